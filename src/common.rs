@@ -1,5 +1,32 @@
+use std::{
+    fmt,
+    future::Future,
+    net::{Ipv4Addr, Ipv6Addr},
+    pin::Pin,
+    rc::Rc,
+    sync::atomic::{AtomicUsize, Ordering},
+};
 use strum_macros::{Display, EnumString};
 
+pub mod animation;
+pub mod announcer;
+pub mod bookmarks;
+pub mod css;
+pub mod find;
+pub mod html;
+pub mod icons;
+pub mod keyboard;
+pub mod menu;
+pub mod qr;
+pub mod share;
+pub mod style;
+pub mod tailwind;
+
+/// Default accessible name of the address bar input.
+pub const ADDRESS_BAR_LABEL: &str = "Website address or search query";
+/// Default accessible name of the refresh button.
+pub const REFRESH_LABEL: &str = "Refresh";
+
 #[derive(PartialEq, Clone)]
 pub enum ButtonType {
     Close,
@@ -8,6 +35,11 @@ pub enum ButtonType {
 }
 
 impl ButtonType {
+    /// Tailwind background and focus ring classes of the control, as
+    /// returned before the style modes existed.
+    #[deprecated(
+        note = "use `tailwind::control_class`, or `css::control_class` for stylesheet mode"
+    )]
     pub fn as_class(&self) -> &'static str {
         match self {
             ButtonType::Close => "bg-red-500 hover:bg-red-600 focus:ring-red-300",
@@ -31,6 +63,106 @@ impl ButtonType {
             ButtonType::Maximize => "Maximize window (Cmd+Ctrl+F)",
         }
     }
+
+    /// Fill and stroke colors of the traffic-light circle.
+    pub fn colors(&self) -> (&'static str, &'static str) {
+        match self {
+            ButtonType::Close => ("#FF5F57", "#E14640"),
+            ButtonType::Minimize => ("#FFBD2E", "#DFA123"),
+            ButtonType::Maximize => ("#28CA42", "#1DAD2C"),
+        }
+    }
+
+    /// Fill color of the traffic-light circle while hovered.
+    pub fn hover_fill(&self) -> &'static str {
+        match self {
+            ButtonType::Close => "#E0443E",
+            ButtonType::Minimize => "#DEA123",
+            ButtonType::Maximize => "#1AAB29",
+        }
+    }
+
+    /// Path of the glyph telling the controls apart: ×, − and ⤢ as revealed
+    /// on hover, with + for maximize in forced colors mode.
+    pub fn glyph(&self, forced_colors: bool) -> &'static str {
+        match self {
+            ButtonType::Close => icons::CLOSE_GLYPH,
+            ButtonType::Minimize => icons::MINIMIZE_GLYPH,
+            ButtonType::Maximize if forced_colors => icons::MAXIMIZE_GLYPH,
+            ButtonType::Maximize => icons::FULLSCREEN_GLYPH,
+        }
+    }
+
+    /// Stroke of the glyph revealed over the traffic-light circle.
+    pub fn glyph_color(&self) -> &'static str {
+        match self {
+            ButtonType::Close => "#4D0000",
+            ButtonType::Minimize => "#995700",
+            ButtonType::Maximize => "#006500",
+        }
+    }
+
+    /// Colors the control is drawn with in `state`.
+    ///
+    /// In forced colors mode the traffic-light colors are replaced by the
+    /// same system color, so the control uses system colors outright and
+    /// always shows its glyph. Controls of an inactive window are grey until
+    /// their glyphs are revealed.
+    pub fn control_colors(&self, state: ControlState) -> ControlColors {
+        if state.forced_colors {
+            let (fill, glyph) = if state.hovered {
+                ("Highlight", "HighlightText")
+            } else {
+                ("ButtonFace", "ButtonText")
+            };
+            return ControlColors {
+                fill,
+                stroke: "ButtonText",
+                glyph: Some(glyph),
+            };
+        }
+        if state.inactive && !state.show_glyph {
+            return ControlColors {
+                fill: "#DCDCDC",
+                stroke: "#C8C8C8",
+                glyph: None,
+            };
+        }
+        let (fill, stroke) = self.colors();
+        ControlColors {
+            fill: if state.hovered {
+                self.hover_fill()
+            } else {
+                fill
+            },
+            stroke,
+            glyph: state.show_glyph.then(|| self.glyph_color()),
+        }
+    }
+}
+
+/// What a window control is showing, from which
+/// [`ButtonType::control_colors`] picks its colors.
+#[derive(PartialEq, Clone, Copy, Default, Debug)]
+pub struct ControlState {
+    /// The pointer is over the control.
+    pub hovered: bool,
+    /// The pointer or focus is inside the group of controls.
+    pub show_glyph: bool,
+    /// The window is not the active one.
+    pub inactive: bool,
+    /// The page is shown in a forced colors mode such as Windows High
+    /// Contrast.
+    pub forced_colors: bool,
+}
+
+/// Fill and stroke of a window control's circle and the stroke of its glyph,
+/// which is hidden when `glyph` is `None`.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct ControlColors {
+    pub fill: &'static str,
+    pub stroke: &'static str,
+    pub glyph: Option<&'static str>,
 }
 
 #[derive(PartialEq, Clone, Default, Debug, EnumString, Display)]
@@ -62,3 +194,1104 @@ pub enum Variant {
     Tabs,
     Ios,
 }
+
+/// Where an address bar suggestion came from.
+#[derive(PartialEq, Clone, Default, Debug, EnumString, Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum SuggestionSource {
+    #[default]
+    Static,
+    History,
+    Remote,
+}
+
+/// A single entry in the address bar autocomplete dropdown.
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct Suggestion {
+    /// The value written into the address bar when the suggestion is picked.
+    pub url: String,
+    /// Optional human readable text shown next to the URL (e.g. a page title).
+    pub title: String,
+    pub source: SuggestionSource,
+}
+
+impl Suggestion {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    pub fn with_source(mut self, source: SuggestionSource) -> Self {
+        self.source = source;
+        self
+    }
+}
+
+pub type SuggestionFuture = Pin<Box<dyn Future<Output = Vec<Suggestion>>>>;
+
+/// Supplies the suggestions shown under the address bar.
+///
+/// `Static` and `History` are filtered locally against the typed text, while
+/// `Async` hands the query to the host application and renders whatever the
+/// returned future resolves to.
+#[derive(Clone, Default)]
+pub enum SuggestionProvider {
+    #[default]
+    None,
+    Static(Vec<Suggestion>),
+    History(Vec<String>),
+    Async(Rc<dyn Fn(String) -> SuggestionFuture>),
+}
+
+impl SuggestionProvider {
+    pub fn from_async<F, Fut>(f: F) -> Self
+    where
+        F: Fn(String) -> Fut + 'static,
+        Fut: Future<Output = Vec<Suggestion>> + 'static,
+    {
+        SuggestionProvider::Async(Rc::new(move |query| Box::pin(f(query))))
+    }
+
+    pub fn is_none(&self) -> bool {
+        matches!(self, SuggestionProvider::None)
+    }
+
+    /// Returns the locally filtered suggestions for `query`, or `None` when the
+    /// provider is asynchronous and has to be polled instead.
+    pub fn filter(&self, query: &str, limit: usize) -> Option<Vec<Suggestion>> {
+        match self {
+            SuggestionProvider::None => Some(Vec::new()),
+            SuggestionProvider::Static(items) => Some(filter_suggestions(items, query, limit)),
+            SuggestionProvider::History(urls) => {
+                let items: Vec<Suggestion> = urls
+                    .iter()
+                    .map(|url| Suggestion::new(url.clone()).with_source(SuggestionSource::History))
+                    .collect();
+                Some(filter_suggestions(&items, query, limit))
+            }
+            SuggestionProvider::Async(_) => None,
+        }
+    }
+
+    pub fn fetch(&self, query: String) -> Option<SuggestionFuture> {
+        match self {
+            SuggestionProvider::Async(f) => Some(f(query)),
+            _ => None,
+        }
+    }
+}
+
+impl PartialEq for SuggestionProvider {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (SuggestionProvider::None, SuggestionProvider::None) => true,
+            (SuggestionProvider::Static(a), SuggestionProvider::Static(b)) => a == b,
+            (SuggestionProvider::History(a), SuggestionProvider::History(b)) => a == b,
+            (SuggestionProvider::Async(a), SuggestionProvider::Async(b)) => Rc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+}
+
+/// Strips the scheme and a leading `www.` so that typing `ope` matches
+/// `https://www.opensass.org`.
+pub fn strip_url_prefix(url: &str) -> &str {
+    let rest = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+        .unwrap_or(url);
+    rest.strip_prefix("www.").unwrap_or(rest)
+}
+
+/// Keeps the suggestions whose URL or title contains `query` (case-insensitive),
+/// ranking prefix matches on the URL first.
+pub fn filter_suggestions(items: &[Suggestion], query: &str, limit: usize) -> Vec<Suggestion> {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return Vec::new();
+    }
+
+    let mut prefixed = Vec::new();
+    let mut contained = Vec::new();
+    for item in items {
+        let url = item.url.to_lowercase();
+        if url.starts_with(&query) || strip_url_prefix(&url).starts_with(&query) {
+            prefixed.push(item.clone());
+        } else if url.contains(&query) || item.title.to_lowercase().contains(&query) {
+            contained.push(item.clone());
+        }
+    }
+
+    prefixed.extend(contained);
+    prefixed.truncate(limit);
+    prefixed
+}
+
+/// Splits `text` into `(segment, is_match)` pairs around every case-insensitive
+/// occurrence of `query`, for rendering highlighted matches.
+pub fn highlight_segments<'a>(text: &'a str, query: &str) -> Vec<(&'a str, bool)> {
+    let mut segments = Vec::new();
+    let mut cursor = 0;
+    for (start, end) in match_offsets(text, query.trim(), char::len_utf8) {
+        if start > cursor {
+            segments.push((&text[cursor..start], false));
+        }
+        segments.push((&text[start..end], true));
+        cursor = end;
+    }
+    if cursor < text.len() || segments.is_empty() {
+        segments.push((&text[cursor..], false));
+    }
+    segments
+}
+
+/// Finds every case-insensitive, non-overlapping occurrence of `query` in
+/// `text`, returning `(start, end)` offsets in UTF-16 code units as used by DOM
+/// ranges.
+pub fn find_matches(text: &str, query: &str) -> Vec<(usize, usize)> {
+    match_offsets(text, query, char::len_utf16)
+}
+
+/// Case-insensitive, non-overlapping occurrences of `query` in `text`, as
+/// `(start, end)` offsets counted with `width` per character. Characters are
+/// compared one by one, so matches always fall on character boundaries.
+fn match_offsets(text: &str, query: &str, width: fn(char) -> usize) -> Vec<(usize, usize)> {
+    fn fold(c: char) -> char {
+        let mut lower = c.to_lowercase();
+        match (lower.next(), lower.next()) {
+            (Some(folded), None) => folded,
+            _ => c,
+        }
+    }
+
+    let needle: Vec<char> = query.chars().map(fold).collect();
+    if needle.is_empty() {
+        return Vec::new();
+    }
+
+    let mut offset = 0;
+    let haystack: Vec<(char, usize)> = text
+        .chars()
+        .map(|c| {
+            let start = offset;
+            offset += width(c);
+            (fold(c), start)
+        })
+        .collect();
+
+    let mut matches = Vec::new();
+    let mut index = 0;
+    while index + needle.len() <= haystack.len() {
+        if haystack[index..index + needle.len()]
+            .iter()
+            .zip(&needle)
+            .all(|((c, _), n)| c == n)
+        {
+            let end = haystack
+                .get(index + needle.len())
+                .map_or(offset, |&(_, start)| start);
+            matches.push((haystack[index].1, end));
+            index += needle.len();
+        } else {
+            index += 1;
+        }
+    }
+    matches
+}
+
+/// Formats the "n of m" counter of the find bar for the match at `current`.
+pub fn find_status(current: Option<usize>, total: usize) -> String {
+    match current {
+        Some(index) if total > 0 => format!("{} of {}", index + 1, total),
+        _ => "No results".to_string(),
+    }
+}
+
+/// Zoom factors the zoom shortcuts step through, as in common desktop browsers.
+pub const ZOOM_LEVELS: [f64; 15] = [
+    0.25, 0.33, 0.5, 0.67, 0.75, 0.8, 0.9, 1.0, 1.1, 1.25, 1.5, 1.75, 2.0, 2.5, 3.0,
+];
+
+/// Returns the next zoom level above (`zoom_in`) or below `level`, staying at
+/// the ends of [`ZOOM_LEVELS`].
+pub fn zoom_step(level: f64, zoom_in: bool) -> f64 {
+    const TOLERANCE: f64 = 0.001;
+    if zoom_in {
+        ZOOM_LEVELS
+            .iter()
+            .copied()
+            .find(|&step| step > level + TOLERANCE)
+            .unwrap_or(ZOOM_LEVELS[ZOOM_LEVELS.len() - 1])
+    } else {
+        ZOOM_LEVELS
+            .iter()
+            .rev()
+            .copied()
+            .find(|&step| step < level - TOLERANCE)
+            .unwrap_or(ZOOM_LEVELS[0])
+    }
+}
+
+/// Whether `level` is the default 100% zoom.
+pub fn is_default_zoom(level: f64) -> bool {
+    (level - 1.0).abs() < 0.001
+}
+
+/// Formats a zoom level as a percentage, e.g. `1.25` as `"125%"`.
+pub fn zoom_label(level: f64) -> String {
+    format!("{}%", (level * 100.0).round())
+}
+
+/// Inline style for the element wrapping zoomed content. The content is laid
+/// out at `100% / level` of the available width and scaled, so a wide page
+/// keeps its layout in a small frame.
+pub fn zoom_style(level: f64) -> String {
+    if is_default_zoom(level) {
+        "display: contents;".to_string()
+    } else {
+        format!(
+            "width: {:.4}%; transform: scale({level}); transform-origin: 0 0;",
+            100.0 / level
+        )
+    }
+}
+
+/// Returns the full text to place in the address bar when `typed` is a prefix
+/// of `suggestion`, so the remainder can be shown as a selected inline completion.
+pub fn inline_completion(typed: &str, suggestion: &str) -> Option<String> {
+    if typed.is_empty() {
+        return None;
+    }
+    let lower = typed.to_lowercase();
+    [suggestion, strip_url_prefix(suggestion)]
+        .into_iter()
+        .find(|candidate| {
+            candidate.len() > typed.len()
+                && candidate.is_char_boundary(typed.len())
+                && candidate[..typed.len()].to_lowercase() == lower
+        })
+        .map(|candidate| format!("{}{}", typed, &candidate[typed.len()..]))
+}
+
+/// Second-level labels that form a public suffix together with their TLD
+/// (e.g. `co.uk`). This is a small heuristic, not the full Public Suffix List.
+const MULTI_PART_SUFFIXES: &[&str] = &[
+    "ac", "co", "com", "edu", "gov", "ltd", "ne", "net", "or", "org", "plc",
+];
+
+/// An address split into the parts a browser emphasizes when the address bar
+/// is not focused: the scheme and `www.` are dropped, the registrable domain
+/// is highlighted and everything after the host is de-emphasized.
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct DisplayUrl {
+    /// Subdomain labels in front of the registrable domain, including the trailing dot.
+    pub subdomain: String,
+    /// The registrable domain, e.g. `opensass.org`.
+    pub domain: String,
+    /// Port, path, query and fragment. A lone `/` is omitted.
+    pub rest: String,
+}
+
+impl DisplayUrl {
+    /// Parses `url` for display, returning `None` for text that does not look
+    /// like an address (such as a search query).
+    pub fn parse(url: &str) -> Option<Self> {
+        let url = url.trim();
+        if url.is_empty() || url.contains(char::is_whitespace) {
+            return None;
+        }
+
+        // Only a scheme in front of the path counts, so `?next=http://a.b`
+        // in the query cannot pose as the real one.
+        let without_scheme = match url.split_once("://") {
+            Some((scheme, rest)) if is_scheme(scheme) => rest,
+            _ => url,
+        };
+        let host_end = without_scheme
+            .find(['/', '?', '#'])
+            .unwrap_or(without_scheme.len());
+        let (authority, rest) = without_scheme.split_at(host_end);
+        let authority = authority.rsplit('@').next().unwrap_or(authority);
+        let (host, port) = match authority.strip_prefix('[') {
+            Some(bracketed) => match bracketed.find(']') {
+                Some(index) => authority.split_at(index + 2),
+                None => return None,
+            },
+            None => match authority.rfind(':') {
+                Some(index) => authority.split_at(index),
+                None => (authority, ""),
+            },
+        };
+        let rest = if rest == "/" { "" } else { rest };
+
+        // IP addresses have no registrable domain and are shown whole.
+        let is_ipv6 = host
+            .strip_prefix('[')
+            .and_then(|host| host.strip_suffix(']'))
+            .is_some_and(|host| host.parse::<Ipv6Addr>().is_ok());
+        if is_ipv6 || host.parse::<Ipv4Addr>().is_ok() {
+            return Some(Self {
+                subdomain: String::new(),
+                domain: host.to_lowercase(),
+                rest: format!("{}{}", port, rest),
+            });
+        }
+        if host.is_empty() || (!host.contains('.') && host != "localhost") {
+            return None;
+        }
+
+        let host = host
+            .split('.')
+            .map(|label| {
+                label
+                    .strip_prefix("xn--")
+                    .and_then(decode_punycode)
+                    .unwrap_or_else(|| label.to_lowercase())
+            })
+            .collect::<Vec<_>>();
+        let host = host
+            .strip_prefix(&["www".to_string()])
+            .filter(|labels| labels.len() > 1)
+            .unwrap_or(&host);
+
+        let domain_labels = match host {
+            [.., second, tld]
+                if host.len() > 2
+                    && tld.chars().count() == 2
+                    && MULTI_PART_SUFFIXES.contains(&second.as_str()) =>
+            {
+                3
+            }
+            _ => 2,
+        }
+        .min(host.len());
+        let split = host.len() - domain_labels;
+        let subdomain = host[..split]
+            .iter()
+            .map(|label| format!("{}.", label))
+            .collect::<String>();
+        let domain = host[split..].join(".");
+
+        Some(Self {
+            subdomain,
+            domain,
+            rest: format!("{}{}", port, rest),
+        })
+    }
+}
+
+/// Whether `scheme` is a URL scheme: a letter followed by letters, digits,
+/// `+`, `-` or `.`.
+fn is_scheme(scheme: &str) -> bool {
+    let mut chars = scheme.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+}
+
+/// Decodes a Punycode label (without its `xn--` prefix) as described in RFC 3492.
+pub fn decode_punycode(input: &str) -> Option<String> {
+    const BASE: u32 = 36;
+    const T_MIN: u32 = 1;
+    const T_MAX: u32 = 26;
+    const SKEW: u32 = 38;
+    const DAMP: u32 = 700;
+
+    fn adapt(delta: u32, num_points: u32, first_time: bool) -> u32 {
+        let mut delta = if first_time { delta / DAMP } else { delta / 2 };
+        delta += delta / num_points;
+        let mut k = 0;
+        while delta > ((BASE - T_MIN) * T_MAX) / 2 {
+            delta /= BASE - T_MIN;
+            k += BASE;
+        }
+        k + (BASE - T_MIN + 1) * delta / (delta + SKEW)
+    }
+
+    let (basic, extended) = match input.rfind('-') {
+        Some(index) => (&input[..index], &input[index + 1..]),
+        None => ("", input),
+    };
+    if !basic.is_ascii() {
+        return None;
+    }
+
+    let mut output: Vec<char> = basic.chars().collect();
+    let mut n: u32 = 128;
+    let mut i: u32 = 0;
+    let mut bias: u32 = 72;
+    let mut digits = extended.bytes().peekable();
+
+    while digits.peek().is_some() {
+        let old_i = i;
+        let mut weight: u32 = 1;
+        let mut k = BASE;
+        loop {
+            let digit = match digits.next()? {
+                byte @ b'a'..=b'z' => (byte - b'a') as u32,
+                byte @ b'A'..=b'Z' => (byte - b'A') as u32,
+                byte @ b'0'..=b'9' => (byte - b'0') as u32 + 26,
+                _ => return None,
+            };
+            i = i.checked_add(digit.checked_mul(weight)?)?;
+            let t = if k <= bias {
+                T_MIN
+            } else if k >= bias + T_MAX {
+                T_MAX
+            } else {
+                k - bias
+            };
+            if digit < t {
+                break;
+            }
+            weight = weight.checked_mul(BASE - t)?;
+            k += BASE;
+        }
+
+        let len = output.len() as u32 + 1;
+        bias = adapt(i - old_i, len, old_i == 0);
+        n = n.checked_add(i / len)?;
+        i %= len;
+        output.insert(i as usize, char::from_u32(n)?);
+        i += 1;
+    }
+
+    Some(output.into_iter().collect())
+}
+
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Returns `id`, or a page-unique id starting with `prefix` when `id` is
+/// empty, so several frames on one page never share element ids.
+///
+/// Generated ids come from a process-wide counter. Pages hydrated from
+/// server-rendered HTML should set the frame `id`, which the ids of its parts
+/// derive from through [`part_id`], as the counters of server and client do
+/// not line up.
+pub fn element_id(id: &str, prefix: &str) -> String {
+    if id.is_empty() {
+        format!("{prefix}-{}", NEXT_ID.fetch_add(1, Ordering::Relaxed))
+    } else {
+        id.to_string()
+    }
+}
+
+/// Id of the `part` of the frame with id `frame_id`. Empty when the frame has
+/// no id, so the part generates its own.
+pub fn part_id(frame_id: &str, part: &str) -> String {
+    if frame_id.is_empty() {
+        String::new()
+    } else {
+        format!("{frame_id}-{part}")
+    }
+}
+
+/// Joins the ids an `aria-describedby` attribute references, skipping empty
+/// ones. Returns `None` when nothing is left.
+pub fn describedby_ids<'a>(ids: impl IntoIterator<Item = &'a str>) -> Option<String> {
+    let ids: Vec<&str> = ids.into_iter().flat_map(str::split_whitespace).collect();
+    (!ids.is_empty()).then(|| ids.join(" "))
+}
+
+/// A DOM operation that failed, reported through `on_error` instead of
+/// panicking.
+#[derive(PartialEq, Clone, Debug)]
+pub enum DomError {
+    /// There is no global `window`, e.g. outside a browser.
+    NoWindow,
+    /// The window has no `document`.
+    NoDocument,
+    /// The document has no `body`.
+    NoBody,
+    /// A DOM call threw; `operation` names the call.
+    Js {
+        operation: &'static str,
+        message: String,
+    },
+}
+
+impl fmt::Display for DomError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DomError::NoWindow => f.write_str("no global window"),
+            DomError::NoDocument => f.write_str("window has no document"),
+            DomError::NoBody => f.write_str("document has no body"),
+            DomError::Js { operation, message } => write!(f, "{operation} failed: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for DomError {}
+
+/// Loading lifecycle of the page shown in the frame, driven by the host app.
+#[derive(PartialEq, Clone, Default, Debug)]
+pub enum LoadState {
+    #[default]
+    Idle,
+    /// Loading with progress in percent (`0..=100`).
+    Loading(u8),
+    Loaded,
+    Failed,
+}
+
+impl LoadState {
+    pub fn is_loading(&self) -> bool {
+        matches!(self, LoadState::Loading(_))
+    }
+
+    /// Width of the progress bar in percent, or `None` when no bar is shown.
+    pub fn progress(&self) -> Option<u8> {
+        match self {
+            LoadState::Idle => None,
+            LoadState::Loading(progress) => Some((*progress).min(100)),
+            LoadState::Loaded | LoadState::Failed => Some(100),
+        }
+    }
+
+    /// Inline style for the progress bar: blue while loading, faded out once
+    /// loaded and red when loading failed.
+    pub fn progress_style(&self) -> String {
+        let color = match self {
+            LoadState::Failed => "#ef4444",
+            _ => "#3b82f6",
+        };
+        let opacity = if *self == LoadState::Loaded { 0 } else { 1 };
+        format!(
+            "width: {}%; background-color: {}; opacity: {};",
+            self.progress().unwrap_or(0),
+            color,
+            opacity
+        )
+    }
+}
+
+/// Where the current page was shared to from the share button.
+#[derive(PartialEq, Clone, Copy, Debug, EnumString, Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum ShareTarget {
+    /// The platform share sheet opened through `navigator.share`.
+    Native,
+    Clipboard,
+    Email,
+    QrCode,
+}
+
+/// Builds a `mailto:` link with the page title as subject and the URL as body.
+pub fn share_mailto(url: &str, title: &str) -> String {
+    let subject = if title.is_empty() { url } else { title };
+    format!(
+        "mailto:?subject={}&body={}",
+        percent_encode(subject),
+        percent_encode(url)
+    )
+}
+
+/// Percent-encodes everything except RFC 3986 unreserved characters.
+fn percent_encode(input: &str) -> String {
+    input
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{byte:02X}"),
+        })
+        .collect()
+}
+
+/// An entry in the "More options" menu.
+///
+/// Items are identified by `id`, which is what the selection callback
+/// receives. A separator only draws a divider and ignores the other fields.
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct MenuItem {
+    pub id: String,
+    pub label: String,
+    /// Image URL shown before the label.
+    pub icon: String,
+    /// Keyboard shortcut hint shown after the label, e.g. `Ctrl+P`.
+    pub shortcut: String,
+    pub disabled: bool,
+    pub separator: bool,
+    /// Renders the item as a `menuitemcheckbox` with this checked state.
+    pub checked: Option<bool>,
+    pub submenu: Vec<MenuItem>,
+}
+
+impl MenuItem {
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn separator() -> Self {
+        Self {
+            separator: true,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = icon.into();
+        self
+    }
+
+    pub fn with_shortcut(mut self, shortcut: impl Into<String>) -> Self {
+        self.shortcut = shortcut.into();
+        self
+    }
+
+    pub fn with_disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    pub fn with_checked(mut self, checked: bool) -> Self {
+        self.checked = Some(checked);
+        self
+    }
+
+    pub fn with_submenu(mut self, submenu: Vec<MenuItem>) -> Self {
+        self.submenu = submenu;
+        self
+    }
+
+    pub fn has_submenu(&self) -> bool {
+        !self.submenu.is_empty()
+    }
+}
+
+/// Index of the next item after `from` (or the first one when `None`) that
+/// keyboard focus can land on, wrapping around. Separators are skipped while
+/// disabled items stay focusable, as in the WAI-ARIA menu pattern.
+pub fn menu_step(items: &[MenuItem], from: Option<usize>, forward: bool) -> Option<usize> {
+    let len = items.len();
+    if len == 0 {
+        return None;
+    }
+    let start = match (from, forward) {
+        (None, true) => len - 1,
+        (None, false) => 0,
+        (Some(index), _) => index.min(len - 1),
+    };
+    (1..=len)
+        .map(|offset| {
+            if forward {
+                (start + offset) % len
+            } else {
+                (start + len - offset) % len
+            }
+        })
+        .find(|&index| !items[index].separator)
+}
+
+/// Index of the first item after `from` whose label starts with `query`
+/// (case-insensitive), for typeahead in menus.
+pub fn menu_typeahead(items: &[MenuItem], from: Option<usize>, query: &str) -> Option<usize> {
+    let len = items.len();
+    if len == 0 || query.is_empty() {
+        return None;
+    }
+    let query = query.to_lowercase();
+    // Repeating a single character cycles through matches, so search starts
+    // after the current item; otherwise the current item may match again.
+    let skip_current = query.chars().count() == 1;
+    let start = from.map_or(0, |index| index + usize::from(skip_current));
+    (0..len)
+        .map(|offset| (start + offset) % len)
+        .find(|&index| {
+            !items[index].separator && items[index].label.to_lowercase().starts_with(&query)
+        })
+}
+
+/// Where a toolbar item is rendered in the header.
+#[derive(PartialEq, Clone, Copy, Default, Debug, EnumString, Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum ToolbarPlacement {
+    /// After the navigation buttons, before the address bar.
+    Left,
+    /// After the address bar, before the share button.
+    #[default]
+    Right,
+    /// Always inside the "More options" menu.
+    Overflow,
+}
+
+/// Indices of toolbar items grouped by where they end up, each group sorted by
+/// the items' `order`.
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct ToolbarLayout {
+    pub left: Vec<usize>,
+    pub right: Vec<usize>,
+    pub overflow: Vec<usize>,
+}
+
+/// Lays out toolbar items given as `(placement, order)` pairs.
+///
+/// When `available_width` is known and the inline items need more room than
+/// it allows at `item_width` pixels each, the right-hand items with the
+/// highest order move into the overflow group first, then the left-hand ones.
+pub fn layout_toolbar(
+    items: &[(ToolbarPlacement, i32)],
+    available_width: Option<f64>,
+    item_width: f64,
+) -> ToolbarLayout {
+    let mut sorted: Vec<usize> = (0..items.len()).collect();
+    sorted.sort_by_key(|&index| items[index].1);
+
+    let mut layout = ToolbarLayout::default();
+    for index in sorted {
+        match items[index].0 {
+            ToolbarPlacement::Left => layout.left.push(index),
+            ToolbarPlacement::Right => layout.right.push(index),
+            ToolbarPlacement::Overflow => layout.overflow.push(index),
+        }
+    }
+
+    if let Some(width) = available_width {
+        let capacity = (width.max(0.0) / item_width.max(1.0)) as usize;
+        let mut collapsed = Vec::new();
+        while layout.left.len() + layout.right.len() > capacity {
+            match layout.right.pop().or_else(|| layout.left.pop()) {
+                Some(index) => collapsed.push(index),
+                None => break,
+            }
+        }
+        collapsed.sort_by_key(|&index| items[index].1);
+        collapsed.append(&mut layout.overflow);
+        layout.overflow = collapsed;
+    }
+    layout
+}
+
+/// A saved link shown in the bookmarks bar. A bookmark with `children` is a
+/// folder and opens a menu instead of navigating.
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct Bookmark {
+    pub title: String,
+    pub url: String,
+    /// Image URL shown before the title; a globe or folder icon is used when empty.
+    pub icon: String,
+    pub children: Vec<Bookmark>,
+}
+
+impl Bookmark {
+    pub fn new(title: impl Into<String>, url: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            url: url.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn folder(title: impl Into<String>, children: Vec<Bookmark>) -> Self {
+        Self {
+            title: title.into(),
+            children,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = icon.into();
+        self
+    }
+
+    pub fn is_folder(&self) -> bool {
+        !self.children.is_empty()
+    }
+
+    /// The menu entry for this bookmark, identified by its URL. Folders become
+    /// submenus.
+    pub fn to_menu_item(&self) -> MenuItem {
+        MenuItem {
+            icon: self.icon.clone(),
+            submenu: self.children.iter().map(Bookmark::to_menu_item).collect(),
+            ..MenuItem::new(self.url.clone(), self.title.clone())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(deprecated)]
+    fn keeps_tailwind_control_classes() {
+        assert_eq!(
+            ButtonType::Close.as_class(),
+            "bg-red-500 hover:bg-red-600 focus:ring-red-300"
+        );
+        assert!(ButtonType::Maximize.as_class().starts_with("bg-green-500"));
+    }
+
+    #[test]
+    fn filters_suggestions() {
+        let items = [
+            Suggestion::new("https://www.opensass.org").with_title("OpenSASS"),
+            Suggestion::new("https://docs.rs/yew").with_title("Yew docs"),
+            Suggestion::new("https://crates.io/search?q=opensass"),
+        ];
+        let urls = |query, limit| {
+            filter_suggestions(&items, query, limit)
+                .into_iter()
+                .map(|item| item.url)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            urls("OPEN", 5),
+            [
+                "https://www.opensass.org",
+                "https://crates.io/search?q=opensass"
+            ]
+        );
+        assert_eq!(urls("yew docs", 5), ["https://docs.rs/yew"]);
+        assert_eq!(urls("open", 1), ["https://www.opensass.org"]);
+        assert!(urls("  ", 5).is_empty());
+    }
+
+    #[test]
+    fn completes_inline() {
+        assert_eq!(
+            inline_completion("ope", "https://www.opensass.org").as_deref(),
+            Some("opensass.org")
+        );
+        assert_eq!(
+            inline_completion("HTTPS://O", "https://opensass.org").as_deref(),
+            Some("HTTPS://Opensass.org")
+        );
+        assert_eq!(
+            inline_completion("mü", "https://münchen.de").as_deref(),
+            Some("münchen.de")
+        );
+        assert_eq!(inline_completion("docs", "https://opensass.org"), None);
+        assert_eq!(inline_completion("", "https://opensass.org"), None);
+    }
+
+    #[test]
+    fn highlights_matches_on_char_boundaries() {
+        assert_eq!(
+            highlight_segments("Open SASS open", "open"),
+            [("Open", true), (" SASS ", false), ("open", true)]
+        );
+        assert_eq!(
+            highlight_segments("Café Müller", "MÜL"),
+            [("Café ", false), ("Mül", true), ("ler", false)]
+        );
+        assert_eq!(
+            highlight_segments("東京タワー", "京タ"),
+            [("東", false), ("京タ", true), ("ワー", false)]
+        );
+        assert_eq!(highlight_segments("opensass", " "), [("opensass", false)]);
+        assert_eq!(find_matches("Café café", "CAFÉ"), [(0, 4), (5, 9)]);
+        assert_eq!(find_matches("😀a", "a"), [(2, 3)]);
+    }
+
+    fn display(url: &str) -> (String, String, String) {
+        let parsed = DisplayUrl::parse(url).expect("address");
+        (parsed.subdomain, parsed.domain, parsed.rest)
+    }
+
+    #[test]
+    fn display_url_emphasizes_registrable_domain() {
+        assert_eq!(
+            display("https://www.docs.opensass.org/"),
+            ("docs.".into(), "opensass.org".into(), "".into())
+        );
+        assert_eq!(
+            display("http://shop.example.co.uk:8080/cart?id=1#top"),
+            (
+                "shop.".into(),
+                "example.co.uk".into(),
+                ":8080/cart?id=1#top".into()
+            )
+        );
+        assert_eq!(DisplayUrl::parse("rust book"), None);
+        assert_eq!(
+            DisplayUrl::parse("localhost:3000").unwrap().domain,
+            "localhost"
+        );
+    }
+
+    #[test]
+    fn display_url_ignores_schemes_after_the_host() {
+        assert_eq!(
+            display("opensass.org/?next=http://a.b/c"),
+            (
+                "".into(),
+                "opensass.org".into(),
+                "/?next=http://a.b/c".into()
+            )
+        );
+        assert_eq!(
+            display("https://evil.example/#https://opensass.org"),
+            (
+                "".into(),
+                "evil.example".into(),
+                "/#https://opensass.org".into()
+            )
+        );
+    }
+
+    #[test]
+    fn display_url_keeps_ip_addresses_whole() {
+        assert_eq!(
+            display("http://192.168.0.1:8080/x"),
+            ("".into(), "192.168.0.1".into(), ":8080/x".into())
+        );
+        assert_eq!(
+            display("http://[::1]:3000/"),
+            ("".into(), "[::1]".into(), ":3000".into())
+        );
+        assert_eq!(
+            display("https://[2001:DB8::1]/a"),
+            ("".into(), "[2001:db8::1]".into(), "/a".into())
+        );
+        assert_eq!(DisplayUrl::parse("http://[::1/"), None);
+    }
+
+    #[test]
+    fn display_url_decodes_punycode() {
+        assert_eq!(
+            display("https://xn--mnchen-3ya.de/"),
+            ("".into(), "münchen.de".into(), "".into())
+        );
+        assert_eq!(
+            display("https://www.xn--bcher-kva.example/"),
+            ("".into(), "bücher.example".into(), "".into())
+        );
+        assert_eq!(
+            decode_punycode("egbpdaj6bu4bxfgehfvwxn").as_deref(),
+            Some("ليهمابتكلموشعربي؟")
+        );
+        assert_eq!(decode_punycode("bcher-kva!"), None);
+    }
+
+    #[test]
+    fn progress_follows_load_state() {
+        assert_eq!(LoadState::Idle.progress(), None);
+        assert_eq!(LoadState::Loading(40).progress(), Some(40));
+        assert_eq!(LoadState::Loading(250).progress(), Some(100));
+        assert_eq!(LoadState::Loaded.progress(), Some(100));
+        assert_eq!(LoadState::Failed.progress(), Some(100));
+
+        assert_eq!(
+            LoadState::Idle.progress_style(),
+            "width: 0%; background-color: #3b82f6; opacity: 1;"
+        );
+        assert_eq!(
+            LoadState::Loading(40).progress_style(),
+            "width: 40%; background-color: #3b82f6; opacity: 1;"
+        );
+        assert_eq!(
+            LoadState::Loaded.progress_style(),
+            "width: 100%; background-color: #3b82f6; opacity: 0;"
+        );
+        assert_eq!(
+            LoadState::Failed.progress_style(),
+            "width: 100%; background-color: #ef4444; opacity: 1;"
+        );
+    }
+
+    #[test]
+    fn lays_out_toolbar_items() {
+        use ToolbarPlacement::{Left, Overflow, Right};
+        let items = [(Right, 2), (Left, 0), (Overflow, 5), (Right, 1), (Left, 3)];
+
+        assert_eq!(
+            layout_toolbar(&items, None, 32.0),
+            ToolbarLayout {
+                left: vec![1, 4],
+                right: vec![3, 0],
+                overflow: vec![2],
+            }
+        );
+        // Room for one item: the right-hand ones collapse first, then the
+        // left-hand item with the highest order, all ahead of pinned overflow.
+        assert_eq!(
+            layout_toolbar(&items, Some(40.0), 32.0),
+            ToolbarLayout {
+                left: vec![1],
+                right: vec![],
+                overflow: vec![3, 0, 4, 2],
+            }
+        );
+        assert_eq!(
+            layout_toolbar(&items, Some(200.0), 32.0),
+            layout_toolbar(&items, None, 32.0)
+        );
+        assert_eq!(layout_toolbar(&items, Some(-10.0), 32.0).overflow.len(), 5);
+    }
+
+    #[test]
+    fn steps_zoom_within_bounds() {
+        assert_eq!(zoom_step(1.0, true), 1.1);
+        assert_eq!(zoom_step(1.0, false), 0.9);
+        assert_eq!(zoom_step(1.2, true), 1.25);
+        assert_eq!(zoom_step(1.2, false), 1.1);
+        assert_eq!(zoom_step(3.0, true), 3.0);
+        assert_eq!(zoom_step(10.0, true), 3.0);
+        assert_eq!(zoom_step(0.25, false), 0.25);
+        assert_eq!(zoom_step(0.1, false), 0.25);
+
+        assert_eq!(zoom_label(1.25), "125%");
+        assert!(is_default_zoom(1.0004));
+        assert_eq!(zoom_style(1.0), "display: contents;");
+        assert_eq!(
+            zoom_style(2.0),
+            "width: 50.0000%; transform: scale(2); transform-origin: 0 0;"
+        );
+    }
+
+    fn menu() -> Vec<MenuItem> {
+        vec![
+            MenuItem::new("new", "New tab"),
+            MenuItem::separator(),
+            MenuItem::new("print", "Print").with_disabled(true),
+            MenuItem::new("paste", "Paste"),
+            MenuItem::new("zoom", "Zoom"),
+        ]
+    }
+
+    #[test]
+    fn steps_through_menu_items() {
+        let items = menu();
+
+        assert_eq!(menu_step(&items, None, true), Some(0));
+        assert_eq!(menu_step(&items, None, false), Some(4));
+        // Separators are skipped, disabled items stay focusable.
+        assert_eq!(menu_step(&items, Some(0), true), Some(2));
+        assert_eq!(menu_step(&items, Some(2), false), Some(0));
+        assert_eq!(menu_step(&items, Some(4), true), Some(0));
+        assert_eq!(menu_step(&items, Some(0), false), Some(4));
+        assert_eq!(menu_step(&items, Some(9), true), Some(0));
+        assert_eq!(menu_step(&[], None, true), None);
+        assert_eq!(menu_step(&[MenuItem::separator()], None, true), None);
+    }
+
+    #[test]
+    fn finds_menu_items_by_typeahead() {
+        let items = menu();
+
+        assert_eq!(menu_typeahead(&items, None, "p"), Some(2));
+        // Repeating one character cycles through the items starting with it.
+        assert_eq!(menu_typeahead(&items, Some(2), "p"), Some(3));
+        assert_eq!(menu_typeahead(&items, Some(3), "p"), Some(2));
+        // Longer queries keep matching the current item.
+        assert_eq!(menu_typeahead(&items, Some(3), "PA"), Some(3));
+        assert_eq!(menu_typeahead(&items, Some(0), "z"), Some(4));
+        assert_eq!(menu_typeahead(&items, Some(0), "x"), None);
+        assert_eq!(menu_typeahead(&items, Some(0), ""), None);
+    }
+}
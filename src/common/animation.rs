@@ -0,0 +1,250 @@
+//! Window transitions of the frame.
+//!
+//! Each binding keeps an [`AnimationState`] and renders [`AnimationState::style`]
+//! on the outer container. The styles are CSS transitions, so the frame moves
+//! to the next state when its `transitionend` event fires.
+//!
+//! A closed frame stays hidden until it is unmounted. A minimized frame stays
+//! hidden until the host restores it through the frame's `minimized` prop.
+
+use strum_macros::{Display, EnumString};
+
+/// Media query matched when the user asked for less motion.
+pub const REDUCED_MOTION_QUERY: &str = "(prefers-reduced-motion: reduce)";
+
+/// How the frame animates when it opens, closes, minimizes or maximizes.
+#[derive(PartialEq, Clone, Copy, Default, Debug, EnumString, Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum Animation {
+    /// No transitions; the frame never hides itself.
+    #[default]
+    None,
+    /// Fades in on open and out on close and minimize.
+    Fade,
+    /// Scales and fades on open and close, collapses towards the bottom on
+    /// minimize, grows back on restore and briefly expands on maximize.
+    Scale,
+}
+
+/// A window transition.
+#[derive(PartialEq, Clone, Copy, Debug, EnumString, Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum AnimationPhase {
+    Open,
+    Close,
+    Minimize,
+    Maximize,
+    /// Shows a minimized frame again.
+    Restore,
+}
+
+impl AnimationPhase {
+    /// The property whose `transitionend` finishes this phase.
+    pub fn end_property(&self) -> &'static str {
+        match self {
+            AnimationPhase::Maximize => "transform",
+            _ => "opacity",
+        }
+    }
+}
+
+impl Animation {
+    /// Whether `phase` has a transition under this setting.
+    pub fn animates(&self, phase: AnimationPhase) -> bool {
+        match self {
+            Animation::None => false,
+            Animation::Fade => phase != AnimationPhase::Maximize,
+            Animation::Scale => true,
+        }
+    }
+}
+
+/// Where the frame is in a window transition.
+#[derive(PartialEq, Clone, Copy, Default, Debug)]
+pub enum AnimationState {
+    #[default]
+    Idle,
+    /// Rendered in the start style of the phase; the binding switches to
+    /// [`AnimationState::Running`] once that style has been applied.
+    Pending(AnimationPhase),
+    /// Transitioning towards the end style of the phase.
+    Running(AnimationPhase),
+    /// The phase has finished.
+    Done(AnimationPhase),
+}
+
+impl AnimationState {
+    /// The state to enter when `phase` starts. Phases without a transition,
+    /// including every phase while the user prefers reduced motion, finish
+    /// right away.
+    pub fn start(animation: Animation, phase: AnimationPhase, reduced_motion: bool) -> Self {
+        if reduced_motion || !animation.animates(phase) {
+            AnimationState::Done(phase)
+        } else if matches!(phase, AnimationPhase::Open | AnimationPhase::Restore) {
+            AnimationState::Pending(phase)
+        } else {
+            AnimationState::Running(phase)
+        }
+    }
+
+    /// The phase that just finished, if the `transitionend` of `property` in
+    /// this state completes it. Phases transitioning several properties end
+    /// on a single one of them, so they finish only once.
+    pub fn finished(&self, property: &str) -> Option<AnimationPhase> {
+        match self {
+            AnimationState::Running(phase) if phase.end_property() == property => Some(*phase),
+            _ => None,
+        }
+    }
+
+    /// Whether the frame is minimizing or minimized.
+    pub fn is_minimized(&self) -> bool {
+        matches!(
+            self,
+            AnimationState::Running(AnimationPhase::Minimize)
+                | AnimationState::Done(AnimationPhase::Minimize)
+        )
+    }
+
+    /// The phase that brings the frame in line with the host's `minimized`
+    /// flag, if it differs from what the frame shows.
+    pub fn follow_minimized(&self, minimized: bool) -> Option<AnimationPhase> {
+        match (minimized, self.is_minimized()) {
+            (true, false) => Some(AnimationPhase::Minimize),
+            (false, true) => Some(AnimationPhase::Restore),
+            _ => None,
+        }
+    }
+
+    /// Inline style of the outer container in this state.
+    pub fn style(&self, animation: Animation) -> &'static str {
+        use AnimationPhase::*;
+        use AnimationState::*;
+
+        match (animation, self) {
+            (Animation::None, _) | (_, Idle) | (_, Done(Open | Restore)) => "",
+            (Animation::Fade, Pending(Open)) => "opacity: 0;",
+            (_, Pending(Open)) => "opacity: 0; transform: scale(0.96);",
+            (Animation::Fade, Running(Open)) => "opacity: 1; transition: opacity 200ms ease-out;",
+            (_, Running(Open)) => {
+                "opacity: 1; transform: none; transition: opacity 200ms ease-out, transform 200ms ease-out;"
+            }
+            (Animation::Fade, Running(Close)) => {
+                "opacity: 0; pointer-events: none; transition: opacity 200ms ease-in;"
+            }
+            (_, Running(Close)) => {
+                "opacity: 0; transform: scale(0.96); pointer-events: none; transition: opacity 200ms ease-in, transform 200ms ease-in;"
+            }
+            (Animation::Fade, Running(Minimize)) => {
+                "opacity: 0; pointer-events: none; transition: opacity 300ms ease-in;"
+            }
+            (_, Running(Minimize)) => {
+                "opacity: 0; transform: translateY(40%) scale(0.3, 0.05); transform-origin: 50% 100%; pointer-events: none; transition: opacity 350ms cubic-bezier(0.4, 0, 1, 1), transform 350ms cubic-bezier(0.4, 0, 1, 1);"
+            }
+            (Animation::Fade, Pending(Restore)) => "opacity: 0;",
+            (_, Pending(Restore)) => {
+                "opacity: 0; transform: translateY(40%) scale(0.3, 0.05); transform-origin: 50% 100%;"
+            }
+            (Animation::Fade, Running(Restore)) => {
+                "opacity: 1; transition: opacity 300ms ease-out;"
+            }
+            (_, Running(Restore)) => {
+                "opacity: 1; transform: none; transform-origin: 50% 100%; transition: opacity 350ms cubic-bezier(0, 0, 0.2, 1), transform 350ms cubic-bezier(0, 0, 0.2, 1);"
+            }
+            (_, Running(Maximize)) => {
+                "transform: scale(1.03); transition: transform 180ms ease-out;"
+            }
+            (_, Done(Maximize)) => "transform: none; transition: transform 180ms ease-in;",
+            (_, Done(Close | Minimize)) => "opacity: 0; visibility: hidden; pointer-events: none;",
+            (_, Pending(_)) => "",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn phases_start_by_setting_and_motion_preference() {
+        use AnimationPhase::*;
+
+        assert_eq!(
+            AnimationState::start(Animation::Scale, Open, false),
+            AnimationState::Pending(Open)
+        );
+        assert_eq!(
+            AnimationState::start(Animation::Scale, Close, false),
+            AnimationState::Running(Close)
+        );
+        assert_eq!(
+            AnimationState::start(Animation::Scale, Minimize, true),
+            AnimationState::Done(Minimize)
+        );
+        assert_eq!(
+            AnimationState::start(Animation::Fade, Maximize, false),
+            AnimationState::Done(Maximize)
+        );
+        assert_eq!(
+            AnimationState::start(Animation::None, Close, false),
+            AnimationState::Done(Close)
+        );
+        assert_eq!(
+            AnimationState::Running(Close).finished("opacity"),
+            Some(Close)
+        );
+        assert_eq!(AnimationState::Running(Close).finished("transform"), None);
+        assert_eq!(
+            AnimationState::Running(Maximize).finished("transform"),
+            Some(Maximize)
+        );
+        assert_eq!(AnimationState::Done(Close).finished("opacity"), None);
+    }
+
+    #[test]
+    fn only_animated_frames_hide() {
+        use AnimationPhase::*;
+
+        assert_eq!(AnimationState::Done(Close).style(Animation::None), "");
+        assert!(
+            AnimationState::Done(Close)
+                .style(Animation::Scale)
+                .contains("visibility: hidden;")
+        );
+        assert!(
+            AnimationState::Running(Minimize)
+                .style(Animation::Scale)
+                .contains("transform-origin: 50% 100%;")
+        );
+        assert_eq!(AnimationState::Done(Open).style(Animation::Fade), "");
+    }
+
+    #[test]
+    fn restores_a_minimized_frame() {
+        use AnimationPhase::*;
+
+        let minimized = AnimationState::Done(Minimize);
+        assert_eq!(minimized.follow_minimized(true), None);
+        assert_eq!(minimized.follow_minimized(false), Some(Restore));
+        assert_eq!(
+            AnimationState::Running(Minimize).follow_minimized(false),
+            Some(Restore)
+        );
+        assert_eq!(AnimationState::Idle.follow_minimized(false), None);
+        assert_eq!(AnimationState::Idle.follow_minimized(true), Some(Minimize));
+
+        let restoring = AnimationState::start(Animation::Scale, Restore, false);
+        assert_eq!(restoring, AnimationState::Pending(Restore));
+        assert!(!restoring.style(Animation::Scale).contains("visibility"));
+        assert_eq!(
+            AnimationState::Running(Restore).finished("opacity"),
+            Some(Restore)
+        );
+        assert_eq!(AnimationState::Done(Restore).style(Animation::Scale), "");
+        assert_eq!(
+            AnimationState::start(Animation::Fade, Restore, true),
+            AnimationState::Done(Restore)
+        );
+        assert_eq!(AnimationState::Done(Restore).follow_minimized(false), None);
+    }
+}
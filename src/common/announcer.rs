@@ -0,0 +1,177 @@
+//! Screen reader announcements.
+//!
+//! The bindings keep one hidden live region per [`Politeness`] on the page
+//! and reuse it for every message, instead of adding a region per message.
+//! What the frame says for each [`Announcement`] comes from
+//! [`AnnouncerMessages`], provided through the framework's context.
+
+use super::LoadState;
+use strum_macros::{Display, EnumString};
+
+/// Id of the live region used for `politeness`.
+pub fn region_id(politeness: Politeness) -> &'static str {
+    match politeness {
+        Politeness::Polite => "browser-rs-announcer-polite",
+        Politeness::Assertive => "browser-rs-announcer-assertive",
+    }
+}
+
+/// How urgently a message interrupts the screen reader, rendered as the
+/// region's `aria-live` value.
+#[derive(PartialEq, Clone, Copy, Default, Debug, EnumString, Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum Politeness {
+    /// Read once the user is idle.
+    #[default]
+    Polite,
+    /// Read right away, interrupting speech.
+    Assertive,
+}
+
+/// Something the frame tells screen reader users about.
+#[derive(PartialEq, Clone, Debug)]
+pub enum Announcement {
+    /// Navigation to the URL started.
+    Navigation(String),
+    /// The page finished loading; carries its title, possibly empty.
+    Loaded(String),
+    /// The page failed to load.
+    LoadFailed,
+    Closed,
+    Minimized,
+    Maximized,
+    /// Free text, e.g. the match count of the find bar.
+    Message(String),
+}
+
+impl Announcement {
+    /// The announcement for a page entering `load_state`, if it just left
+    /// loading. `title` is the page title, possibly empty.
+    pub fn for_load(was_loading: bool, load_state: &LoadState, title: &str) -> Option<Self> {
+        match load_state {
+            LoadState::Loaded if was_loading => Some(Announcement::Loaded(title.to_string())),
+            LoadState::Failed if was_loading => Some(Announcement::LoadFailed),
+            _ => None,
+        }
+    }
+
+    /// The channel the announcement is read on. Failures and closing the
+    /// window interrupt; everything else waits.
+    pub fn politeness(&self) -> Politeness {
+        match self {
+            Announcement::LoadFailed | Announcement::Closed => Politeness::Assertive,
+            _ => Politeness::Polite,
+        }
+    }
+}
+
+/// Message templates of the announcements.
+///
+/// `{url}` and `{title}` are replaced with the URL or title the announcement
+/// carries.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct AnnouncerMessages {
+    pub navigation: &'static str,
+    pub loaded: &'static str,
+    /// Used instead of `loaded` when the page has no title.
+    pub loaded_untitled: &'static str,
+    pub load_failed: &'static str,
+    pub closed: &'static str,
+    pub minimized: &'static str,
+    pub maximized: &'static str,
+}
+
+impl Default for AnnouncerMessages {
+    fn default() -> Self {
+        Self {
+            navigation: "Navigating to {url}",
+            loaded: "{title} loaded",
+            loaded_untitled: "Page loaded",
+            load_failed: "Page failed to load",
+            closed: "Window closed",
+            minimized: "Window minimized",
+            maximized: "Window maximized",
+        }
+    }
+}
+
+impl AnnouncerMessages {
+    pub fn with_navigation(mut self, template: &'static str) -> Self {
+        self.navigation = template;
+        self
+    }
+
+    pub fn with_loaded(mut self, template: &'static str) -> Self {
+        self.loaded = template;
+        self
+    }
+
+    pub fn with_loaded_untitled(mut self, template: &'static str) -> Self {
+        self.loaded_untitled = template;
+        self
+    }
+
+    pub fn with_load_failed(mut self, template: &'static str) -> Self {
+        self.load_failed = template;
+        self
+    }
+
+    pub fn with_closed(mut self, template: &'static str) -> Self {
+        self.closed = template;
+        self
+    }
+
+    pub fn with_minimized(mut self, template: &'static str) -> Self {
+        self.minimized = template;
+        self
+    }
+
+    pub fn with_maximized(mut self, template: &'static str) -> Self {
+        self.maximized = template;
+        self
+    }
+
+    /// The text read for `announcement`.
+    pub fn message(&self, announcement: &Announcement) -> String {
+        match announcement {
+            Announcement::Navigation(url) => self.navigation.replace("{url}", url),
+            Announcement::Loaded(title) if title.is_empty() => self.loaded_untitled.to_string(),
+            Announcement::Loaded(title) => self.loaded.replace("{title}", title),
+            Announcement::LoadFailed => self.load_failed.to_string(),
+            Announcement::Closed => self.closed.to_string(),
+            Announcement::Minimized => self.minimized.to_string(),
+            Announcement::Maximized => self.maximized.to_string(),
+            Announcement::Message(message) => message.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fills_templates() {
+        let messages = AnnouncerMessages::default().with_navigation("Opening {url}");
+
+        assert_eq!(
+            messages.message(&Announcement::Navigation("https://opensass.org".into())),
+            "Opening https://opensass.org"
+        );
+        assert_eq!(
+            messages.message(&Announcement::Loaded("Docs".into())),
+            "Docs loaded"
+        );
+        assert_eq!(
+            messages.message(&Announcement::Loaded(String::new())),
+            "Page loaded"
+        );
+        assert_eq!(Announcement::Closed.politeness(), Politeness::Assertive);
+        assert_eq!(
+            Announcement::for_load(true, &LoadState::Failed, ""),
+            Some(Announcement::LoadFailed)
+        );
+        assert_eq!(Announcement::for_load(false, &LoadState::Loaded, ""), None);
+        assert_eq!(Politeness::Assertive.to_string(), "assertive");
+    }
+}
@@ -0,0 +1,150 @@
+//! Menus of the bookmarks bar: a folder's contents, or the bookmarks that did
+//! not fit in the bar.
+
+use super::{Bookmark, MenuItem, css, css::StyleMode};
+
+/// Label of the overflow button and its menu.
+pub const MORE_BOOKMARKS_LABEL: &str = "More bookmarks";
+
+/// A menu opened from the bookmarks bar.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum BookmarkMenu {
+    /// The folder at this index of the bar.
+    Folder(usize),
+    /// The bookmarks hidden behind the chevron.
+    Overflow,
+}
+
+impl BookmarkMenu {
+    /// Id of the button that opens this menu in the bar with the id `bar_id`.
+    pub fn trigger_id(&self, bar_id: &str) -> String {
+        match self {
+            BookmarkMenu::Folder(index) => format!("{bar_id}-folder-{index}"),
+            BookmarkMenu::Overflow => format!("{bar_id}-overflow"),
+        }
+    }
+
+    pub fn menu_id(&self, bar_id: &str) -> String {
+        match self {
+            BookmarkMenu::Folder(index) => format!("{bar_id}-menu-{index}"),
+            BookmarkMenu::Overflow => format!("{bar_id}-menu-overflow"),
+        }
+    }
+
+    /// Entries and label of the menu, given how many bookmarks are `visible`
+    /// in the bar.
+    pub fn entries(&self, bookmarks: &[Bookmark], visible: usize) -> (Vec<MenuItem>, String) {
+        match self {
+            BookmarkMenu::Folder(index) => bookmarks.get(*index).map_or_else(
+                || (Vec::new(), String::new()),
+                |folder| {
+                    (
+                        folder.children.iter().map(Bookmark::to_menu_item).collect(),
+                        folder.title.clone(),
+                    )
+                },
+            ),
+            BookmarkMenu::Overflow => (
+                bookmarks[visible.min(bookmarks.len())..]
+                    .iter()
+                    .map(Bookmark::to_menu_item)
+                    .collect(),
+                MORE_BOOKMARKS_LABEL.to_string(),
+            ),
+        }
+    }
+
+    /// Stylesheet-mode classes of the element placing the menu.
+    pub fn class(&self) -> &'static str {
+        match self {
+            BookmarkMenu::Folder(_) => css::BOOKMARK_MENU,
+            BookmarkMenu::Overflow => css::BOOKMARK_OVERFLOW_MENU,
+        }
+    }
+
+    /// Style placing the menu below its trigger, whose offset from the left
+    /// edge of the bar is `left` pixels. The overflow menu aligns right. In
+    /// stylesheet mode [`class`](Self::class) places it and only the offset
+    /// is left inline.
+    pub fn position(&self, left: i32, mode: StyleMode) -> String {
+        match (self, mode) {
+            (BookmarkMenu::Folder(_), StyleMode::Inline) => {
+                format!("position: absolute; top: 100%; left: {left}px;")
+            }
+            (BookmarkMenu::Folder(_), StyleMode::Stylesheet) => format!("left: {left}px;"),
+            (BookmarkMenu::Overflow, StyleMode::Inline) => {
+                "position: absolute; top: 100%; right: 8px;".to_string()
+            }
+            (BookmarkMenu::Overflow, StyleMode::Stylesheet) => String::new(),
+        }
+    }
+}
+
+/// Whether `menu` is the one in `open`, which pairs the open menu with the
+/// offset of its trigger.
+pub fn is_open(open: Option<(BookmarkMenu, i32)>, menu: BookmarkMenu) -> bool {
+    matches!(open, Some((open, _)) if open == menu)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lists_folder_and_overflow_entries() {
+        let bookmarks = vec![
+            Bookmark::new("Docs", "https://docs.rs"),
+            Bookmark::folder("Rust", vec![Bookmark::new("Book", "https://rust-lang.org")]),
+            Bookmark::new("Crates", "https://crates.io"),
+        ];
+
+        let (items, label) = BookmarkMenu::Folder(1).entries(&bookmarks, 3);
+        assert_eq!(label, "Rust");
+        assert_eq!(items[0].id, "https://rust-lang.org");
+
+        let (items, label) = BookmarkMenu::Overflow.entries(&bookmarks, 1);
+        assert_eq!(label, MORE_BOOKMARKS_LABEL);
+        assert_eq!(items.len(), 2);
+        assert!(items[0].has_submenu());
+        assert!(BookmarkMenu::Overflow.entries(&bookmarks, 9).0.is_empty());
+        assert!(BookmarkMenu::Folder(7).entries(&bookmarks, 3).0.is_empty());
+    }
+
+    #[test]
+    fn names_and_places_menus() {
+        assert_eq!(BookmarkMenu::Folder(2).trigger_id("bar"), "bar-folder-2");
+        assert_eq!(BookmarkMenu::Overflow.trigger_id("bar"), "bar-overflow");
+        assert_eq!(BookmarkMenu::Folder(2).menu_id("bar"), "bar-menu-2");
+        assert_eq!(BookmarkMenu::Overflow.menu_id("bar"), "bar-menu-overflow");
+        let inline = StyleMode::Inline;
+        assert!(
+            BookmarkMenu::Folder(0)
+                .position(42, inline)
+                .contains("left: 42px;")
+        );
+        assert!(
+            BookmarkMenu::Overflow
+                .position(42, inline)
+                .contains("right: 8px;")
+        );
+        assert_eq!(
+            BookmarkMenu::Folder(0).position(42, StyleMode::Stylesheet),
+            "left: 42px;"
+        );
+        assert!(
+            BookmarkMenu::Overflow
+                .position(42, StyleMode::Stylesheet)
+                .is_empty()
+        );
+        assert!(
+            BookmarkMenu::Overflow
+                .class()
+                .starts_with(BookmarkMenu::Folder(0).class())
+        );
+
+        let open = Some((BookmarkMenu::Folder(1), 10));
+        assert!(is_open(open, BookmarkMenu::Folder(1)));
+        assert!(!is_open(open, BookmarkMenu::Folder(2)));
+        assert!(!is_open(None, BookmarkMenu::Overflow));
+    }
+}
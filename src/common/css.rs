@@ -0,0 +1,269 @@
+//! Stylesheet mode: stable BEM class names and the stylesheet that styles them.
+//!
+//! With [`StyleMode::Stylesheet`] the frame chrome, its menus, bookmarks bar
+//! and find bar drop their inline default styles and carry the classes below
+//! instead. Include [`STYLESHEET`] once on the page and theme it through its
+//! `--browser-*` custom properties.
+
+use super::{ButtonType, Size, Variant, is_default_zoom, zoom_style};
+use strum_macros::{Display, EnumString};
+
+/// The stylesheet for [`StyleMode::Stylesheet`].
+pub const STYLESHEET: &str = include_str!("browser-rs.css");
+
+pub const FRAME: &str = "browser-frame";
+pub const HEADER: &str = "browser-frame__header";
+pub const NAV: &str = "browser-frame__nav";
+pub const ACTIONS: &str = "browser-frame__actions";
+pub const ICON_BUTTON: &str = "browser-frame__icon-button";
+pub const ICON_BUTTON_MUTED: &str = "browser-frame__icon-button browser-frame__icon-button--muted";
+pub const PROGRESS: &str = "browser-frame__progress";
+pub const CONTROLS: &str = "browser-frame__controls";
+pub const CONTROLS_INACTIVE: &str = "browser-frame__controls browser-frame__controls--inactive";
+pub const CONTROL_GLYPH: &str = "browser-frame__control-glyph";
+pub const ADDRESS: &str = "browser-frame__address";
+pub const ADDRESS_WITH_CONTROLS: &str =
+    "browser-frame__address browser-frame__address--with-controls";
+pub const ADDRESS_BAR: &str = "browser-frame__address-bar";
+pub const ADDRESS_BAR_ZOOMED: &str =
+    "browser-frame__address-bar browser-frame__address-bar--zoomed";
+pub const ADDRESS_INPUT: &str = "browser-frame__address-input";
+pub const ADDRESS_INPUT_MASKED: &str =
+    "browser-frame__address-input browser-frame__address-input--masked";
+pub const URL: &str = "browser-frame__url";
+pub const URL_DOMAIN: &str = "browser-frame__url-domain";
+pub const URL_PATH: &str = "browser-frame__url-path";
+pub const ZOOM: &str = "browser-frame__zoom";
+pub const REFRESH: &str = "browser-frame__refresh";
+pub const CONTENT: &str = "browser-frame__content";
+pub const ZOOM_LAYER: &str = "browser-frame__zoom-layer";
+pub const ZOOM_LAYER_ZOOMED: &str = "browser-frame__zoom-layer browser-frame__zoom-layer--zoomed";
+pub const VISUALLY_HIDDEN: &str = "browser-frame__visually-hidden";
+pub const SUGGESTIONS: &str = "browser-frame__suggestions";
+pub const SUGGESTION: &str = "browser-frame__suggestion";
+pub const SUGGESTION_ACTIVE: &str = "browser-frame__suggestion browser-frame__suggestion--active";
+pub const SUGGESTION_MATCH: &str = "browser-frame__suggestion-match";
+pub const SUGGESTION_TITLE: &str = "browser-frame__suggestion-title";
+pub const TITLE_BAR: &str = "browser-frame__title-bar";
+pub const TAB_LIST: &str = "browser-frame__tabs";
+pub const TAB: &str = "browser-frame__tab";
+pub const FAVICON: &str = "browser-frame__favicon";
+pub const TITLE: &str = "browser-frame__title";
+pub const TOOLBAR_BUTTON: &str = "browser-frame__toolbar-button";
+pub const TOOLBAR_BUTTON_TOGGLED: &str =
+    "browser-frame__toolbar-button browser-frame__toolbar-button--toggled";
+pub const TOOLBAR_BADGE: &str = "browser-frame__toolbar-badge";
+pub const POPUP: &str = "browser-frame__popup";
+pub const BACKDROP: &str = "browser-frame__backdrop";
+pub const SHARE: &str = "browser-frame__share";
+pub const SHARE_ITEM: &str = "browser-frame__share-item";
+pub const SHARE_QR: &str = "browser-frame__share-qr";
+pub const MENU: &str = "browser-frame__menu";
+pub const SUBMENU: &str = "browser-frame__menu browser-frame__menu--submenu";
+pub const MENU_ENTRY: &str = "browser-frame__menu-entry";
+pub const MENU_CHECK: &str = "browser-frame__menu-check";
+pub const MENU_ARROW: &str = "browser-frame__menu-arrow";
+pub const MENU_SEPARATOR: &str = "browser-frame__menu-separator";
+pub const MENU_SHORTCUT: &str = "browser-frame__menu-shortcut";
+pub const BOOKMARKS: &str = "browser-frame__bookmarks";
+pub const BOOKMARK_LIST: &str = "browser-frame__bookmark-list";
+pub const BOOKMARK_ENTRY: &str = "browser-frame__bookmark-entry";
+pub const BOOKMARK_ENTRY_HIDDEN: &str =
+    "browser-frame__bookmark-entry browser-frame__bookmark-entry--hidden";
+pub const BOOKMARK: &str = "browser-frame__bookmark";
+pub const BOOKMARK_TITLE: &str = "browser-frame__bookmark-title";
+pub const BOOKMARK_MENU: &str = "browser-frame__bookmark-menu";
+pub const BOOKMARK_OVERFLOW_MENU: &str =
+    "browser-frame__bookmark-menu browser-frame__bookmark-menu--overflow";
+pub const FIND: &str = "browser-frame__find";
+pub const FIND_INPUT: &str = "browser-frame__find-input";
+pub const FIND_STATUS: &str = "browser-frame__find-status";
+pub const FIND_BUTTON: &str = "browser-frame__find-button";
+
+/// How the frame chrome is styled.
+#[derive(PartialEq, Clone, Copy, Default, Debug, EnumString, Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum StyleMode {
+    /// Inline `style` attributes from the `*_style` props. Needs no CSS.
+    #[default]
+    Inline,
+    /// BEM class names styled by [`STYLESHEET`]; the `*_style` props of the
+    /// chrome are ignored.
+    Stylesheet,
+}
+
+impl StyleMode {
+    /// The inline style to render: `style` in inline mode, nothing in
+    /// stylesheet mode.
+    pub fn inline<'a>(&self, style: &'a str) -> &'a str {
+        match self {
+            StyleMode::Inline => style,
+            StyleMode::Stylesheet => "",
+        }
+    }
+
+    /// The stable class `name` in stylesheet mode, nothing in inline mode.
+    pub fn name(&self, name: &'static str) -> &'static str {
+        match self {
+            StyleMode::Inline => "",
+            StyleMode::Stylesheet => name,
+        }
+    }
+
+    /// The class attribute to render: `class` in inline mode, prefixed with
+    /// the stable `name` in stylesheet mode.
+    pub fn class(&self, name: &str, class: &str) -> String {
+        match self {
+            StyleMode::Inline => class.to_string(),
+            StyleMode::Stylesheet if class.is_empty() => name.to_string(),
+            StyleMode::Stylesheet => format!("{name} {class}"),
+        }
+    }
+}
+
+/// Classes of the outer frame, with modifiers for its variant and size.
+pub fn frame_class(variant: &Variant, size: &Size) -> String {
+    format!("{FRAME} {FRAME}--{variant} {FRAME}--{size}")
+}
+
+/// Classes of the layer scaling the content to `level`.
+pub fn zoom_layer_class(level: f64) -> &'static str {
+    if is_default_zoom(level) {
+        ZOOM_LAYER
+    } else {
+        ZOOM_LAYER_ZOOMED
+    }
+}
+
+/// Style of the layer scaling the content to `level`. The scale depends on
+/// the level, so stylesheet mode keeps it inline when zoomed.
+pub fn zoom_layer_style(level: f64, mode: StyleMode) -> String {
+    match mode {
+        StyleMode::Inline => zoom_style(level),
+        StyleMode::Stylesheet if is_default_zoom(level) => String::new(),
+        StyleMode::Stylesheet => {
+            format!("width: {:.4}%; transform: scale({level});", 100.0 / level)
+        }
+    }
+}
+
+/// Classes of a window control button.
+pub fn control_class(button: &ButtonType) -> &'static str {
+    match button {
+        ButtonType::Close => "browser-frame__control browser-frame__control--close",
+        ButtonType::Minimize => "browser-frame__control browser-frame__control--minimize",
+        ButtonType::Maximize => "browser-frame__control browser-frame__control--maximize",
+    }
+}
+
+/// Classes of a menu item, with modifiers for the active and disabled states.
+pub fn menu_item_class(active: bool, disabled: bool) -> String {
+    let mut class = "browser-frame__menu-item".to_string();
+    if active {
+        class.push_str(" browser-frame__menu-item--active");
+    }
+    if disabled {
+        class.push_str(" browser-frame__menu-item--disabled");
+    }
+    class
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classes_only_in_stylesheet_mode() {
+        assert_eq!(StyleMode::Inline.class(HEADER, "custom"), "custom");
+        assert_eq!(StyleMode::Inline.inline("color: red;"), "color: red;");
+        assert_eq!(
+            StyleMode::Stylesheet.class(HEADER, "custom"),
+            "browser-frame__header custom"
+        );
+        assert_eq!(StyleMode::Stylesheet.class(HEADER, ""), HEADER);
+        assert_eq!(StyleMode::Stylesheet.inline("color: red;"), "");
+    }
+
+    #[test]
+    fn keeps_only_the_zoom_scale_inline() {
+        assert_eq!(zoom_layer_class(1.0), ZOOM_LAYER);
+        assert_eq!(
+            zoom_layer_style(1.0, StyleMode::Inline),
+            "display: contents;"
+        );
+        assert_eq!(zoom_layer_style(1.0, StyleMode::Stylesheet), "");
+        assert_eq!(zoom_layer_class(2.0), ZOOM_LAYER_ZOOMED);
+        assert_eq!(
+            zoom_layer_style(2.0, StyleMode::Stylesheet),
+            "width: 50.0000%; transform: scale(2);"
+        );
+    }
+
+    #[test]
+    fn stylesheet_covers_every_class() {
+        assert_eq!(
+            frame_class(&Variant::Tabs, &Size::Small),
+            "browser-frame browser-frame--tabs browser-frame--small"
+        );
+        for class in [
+            HEADER,
+            NAV,
+            ACTIONS,
+            ICON_BUTTON_MUTED,
+            PROGRESS,
+            CONTROLS_INACTIVE,
+            CONTROL_GLYPH,
+            ADDRESS_WITH_CONTROLS,
+            ADDRESS_BAR_ZOOMED,
+            ADDRESS_INPUT_MASKED,
+            URL,
+            URL_DOMAIN,
+            URL_PATH,
+            ZOOM,
+            REFRESH,
+            ZOOM_LAYER_ZOOMED,
+            VISUALLY_HIDDEN,
+            SUGGESTIONS,
+            SUGGESTION_ACTIVE,
+            SUGGESTION_MATCH,
+            SUGGESTION_TITLE,
+            TITLE_BAR,
+            TAB_LIST,
+            TAB,
+            FAVICON,
+            TITLE,
+            TOOLBAR_BUTTON_TOGGLED,
+            TOOLBAR_BADGE,
+            POPUP,
+            BACKDROP,
+            SHARE,
+            SHARE_ITEM,
+            SHARE_QR,
+            SUBMENU,
+            MENU_ENTRY,
+            MENU_CHECK,
+            MENU_ARROW,
+            MENU_SEPARATOR,
+            MENU_SHORTCUT,
+            BOOKMARKS,
+            BOOKMARK_LIST,
+            BOOKMARK_ENTRY_HIDDEN,
+            BOOKMARK,
+            BOOKMARK_TITLE,
+            BOOKMARK_OVERFLOW_MENU,
+            FIND,
+            FIND_INPUT,
+            FIND_STATUS,
+            FIND_BUTTON,
+            &menu_item_class(true, true),
+            control_class(&ButtonType::Close),
+            control_class(&ButtonType::Minimize),
+            control_class(&ButtonType::Maximize),
+            &frame_class(&Variant::Ios, &Size::Full),
+        ] {
+            for name in class.split(' ') {
+                assert!(STYLESHEET.contains(&format!(".{name}")), "{name}");
+            }
+        }
+    }
+}
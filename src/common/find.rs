@@ -0,0 +1,97 @@
+//! State of the find bar and the highlights it registers.
+
+use super::{find_status, keyboard::cycle_index};
+
+/// Name of the CSS custom highlight holding every match of the find bar with
+/// the id `id`. Each bar registers its own highlights, so several frames on a
+/// page never color or clear each other's matches.
+pub fn match_highlight(id: &str) -> String {
+    format!("{id}-match")
+}
+
+/// Name of the CSS custom highlight holding the current match of the find
+/// bar with the id `id`.
+pub fn current_highlight(id: &str) -> String {
+    format!("{id}-current")
+}
+
+/// The query of the find bar and where it is among the matches.
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct FindState {
+    pub query: String,
+    pub current: Option<usize>,
+    pub total: usize,
+}
+
+impl FindState {
+    /// Records the `total` matches of a new `query` and makes the first one
+    /// current, which is returned.
+    pub fn search(&mut self, query: String, total: usize) -> Option<usize> {
+        self.current = (total > 0).then_some(0);
+        self.total = total;
+        self.query = query;
+        self.current
+    }
+
+    /// Moves to the next or previous match, wrapping around, and returns it.
+    pub fn step(&mut self, forward: bool) -> Option<usize> {
+        let next = cycle_index(self.current, self.total, forward)?;
+        self.current = Some(next);
+        Some(next)
+    }
+
+    /// Status such as "2 of 5" shown and announced for the current match.
+    pub fn status(&self) -> String {
+        find_status(self.current, self.total)
+    }
+
+    /// The status shown next to the input, empty until something is typed.
+    pub fn visible_status(&self) -> String {
+        if self.query.is_empty() {
+            String::new()
+        } else {
+            self.status()
+        }
+    }
+}
+
+/// `::highlight()` rules coloring the matches and the current one of the find
+/// bar with the id `id`.
+pub fn highlight_css(id: &str, color: &str, current_color: &str) -> String {
+    format!(
+        "::highlight({}) {{ background-color: {color}; }} ::highlight({}) {{ background-color: {current_color}; }}",
+        match_highlight(id),
+        current_highlight(id)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn searches_and_steps_through_matches() {
+        let mut find = FindState::default();
+        assert_eq!(find.visible_status(), "");
+
+        assert_eq!(find.search("rust".to_string(), 3), Some(0));
+        assert_eq!(find.step(false), Some(2));
+        assert_eq!(find.step(true), Some(0));
+        assert_eq!(find.status(), find_status(Some(0), 3));
+        assert_eq!(find.visible_status(), find.status());
+
+        assert_eq!(find.search("rusty".to_string(), 0), None);
+        assert_eq!(find.step(true), None);
+        assert_eq!(find.visible_status(), find_status(None, 0));
+    }
+
+    #[test]
+    fn colors_both_highlights_of_one_bar() {
+        let css = highlight_css("browser-find-1", "yellow", "orange");
+        assert!(css.contains("::highlight(browser-find-1-match) { background-color: yellow; }"));
+        assert!(css.contains("::highlight(browser-find-1-current) { background-color: orange; }"));
+        assert!(!css.contains("browser-find-2"));
+        assert_ne!(match_highlight("a"), match_highlight("b"));
+        assert_ne!(match_highlight("a"), current_highlight("a"));
+    }
+}
@@ -0,0 +1,352 @@
+//! Framework-free HTML rendering of the browser chrome.
+//!
+//! [`StaticFrame`] mirrors the header of the Yew and Dioxus `BrowserFrame`
+//! with the same inline styles, labels and address display, so static site
+//! generators such as mdBook or Zola can embed matching mockups without
+//! shipping WASM. Nothing runs behind the chrome, so its buttons are disabled
+//! and its address bar is skipped when tabbing through the page.
+
+use super::{
+    ADDRESS_BAR_LABEL, ButtonType, DisplayUrl, REFRESH_LABEL, Size, Variant, icons, style,
+};
+use std::fmt::Write;
+
+/// Configuration of a statically rendered browser frame.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StaticFrame {
+    pub url: String,
+    pub placeholder: String,
+    pub variant: Variant,
+    pub size: Size,
+    pub show_controls: bool,
+    pub show_address_bar: bool,
+    /// HTML placed inside the content area. It is inserted as is, not escaped.
+    pub content: String,
+    pub id: String,
+    pub class: String,
+    /// Default look of the outer container; clear it when styling through
+    /// `class`.
+    pub base_style: String,
+    pub style: String,
+    pub aria_label: String,
+}
+
+impl Default for StaticFrame {
+    fn default() -> Self {
+        Self {
+            url: String::new(),
+            placeholder: String::new(),
+            variant: Variant::default(),
+            size: Size::default(),
+            show_controls: true,
+            show_address_bar: true,
+            content: String::new(),
+            id: String::new(),
+            class: String::new(),
+            base_style: style::FRAME_BASE_STYLE.to_string(),
+            style: String::new(),
+            aria_label: "Browser window".to_string(),
+        }
+    }
+}
+
+impl StaticFrame {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    pub fn with_variant(mut self, variant: Variant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    pub fn with_size(mut self, size: Size) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn with_controls(mut self, show_controls: bool) -> Self {
+        self.show_controls = show_controls;
+        self
+    }
+
+    pub fn with_address_bar(mut self, show_address_bar: bool) -> Self {
+        self.show_address_bar = show_address_bar;
+        self
+    }
+
+    pub fn with_content(mut self, content: impl Into<String>) -> Self {
+        self.content = content.into();
+        self
+    }
+
+    pub fn with_id(mut self, id: impl Into<String>) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    pub fn with_class(mut self, class: impl Into<String>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    pub fn with_base_style(mut self, base_style: impl Into<String>) -> Self {
+        self.base_style = base_style.into();
+        self
+    }
+
+    pub fn with_style(mut self, style: impl Into<String>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    pub fn with_aria_label(mut self, aria_label: impl Into<String>) -> Self {
+        self.aria_label = aria_label.into();
+        self
+    }
+
+    /// Renders the frame: header with window controls, address bar and
+    /// action buttons, followed by the content area.
+    pub fn render(&self) -> String {
+        let mut html = String::new();
+        let _ = write!(
+            html,
+            r#"<article{}{} style="{}" role="application" aria-label="{}" tabindex="-1">"#,
+            attribute("id", &self.id),
+            attribute("class", &self.class),
+            escape(&style::frame_style(
+                &self.base_style,
+                &self.size,
+                &self.style
+            )),
+            escape(&self.aria_label),
+        );
+        self.render_header(&mut html);
+        let _ = write!(
+            html,
+            r#"<main role="main" aria-label="Browser content area" tabindex="-1"><div style="{}">{}</div></main></article>"#,
+            super::zoom_style(1.0),
+            self.content,
+        );
+        html
+    }
+
+    fn render_header(&self, html: &mut String) {
+        let _ = write!(
+            html,
+            r#"<header style="{}" aria-label="Browser window header"><div style="display: flex; align-items: center; gap: 6px;">"#,
+            escape(&style::header_style(
+                style::HEADER_BASE_STYLE,
+                &self.variant,
+                &self.size
+            )),
+        );
+        if self.show_controls {
+            let _ = write!(
+                html,
+                r#"<nav style="{}" role="toolbar" aria-label="Browser window controls">"#,
+                style::CONTROLS_STYLE
+            );
+            for button in [
+                ButtonType::Close,
+                ButtonType::Minimize,
+                ButtonType::Maximize,
+            ] {
+                let (fill, stroke) = button.colors();
+                let _ = write!(
+                    html,
+                    r#"<button type="button" style="{}" aria-label="{}" title="{}" disabled><svg width="12" height="12" viewBox="0 0 12 12" fill="none" xmlns="http://www.w3.org/2000/svg"><path d="{}" fill="{fill}" stroke="{stroke}"></path></svg></button>"#,
+                    style::CONTROL_BUTTON_STYLE,
+                    button.default_aria_label(),
+                    button.default_title(),
+                    icons::CONTROL_BUTTON,
+                );
+            }
+            html.push_str("</nav>");
+            if self.variant != Variant::Ios {
+                icon_button(html, "Sidebar", 20, 15, icons::SIDEBAR, FILL_767676);
+                icon_button(html, "Back", 9, 16, icons::BACK, STROKE_737373);
+                icon_button(html, "Forward", 9, 16, icons::FORWARD, STROKE_BFBFBF);
+            }
+        }
+        html.push_str("</div>");
+
+        if self.show_address_bar {
+            let display_url = DisplayUrl::parse(&self.url);
+            let input_style = if display_url.is_some() {
+                format!("{} color: transparent;", style::ADDRESS_INPUT_STYLE)
+            } else {
+                style::ADDRESS_INPUT_STYLE.to_string()
+            };
+            let _ = write!(
+                html,
+                r#"<div style="{}"><div style="{}"><input type="text" value="{}" placeholder="{}" readonly tabindex="-1" aria-label="{ADDRESS_BAR_LABEL}" style="{}">"#,
+                escape(&style::address_wrapper_style(
+                    style::ADDRESS_WRAPPER_BASE_STYLE,
+                    self.show_controls
+                )),
+                style::ADDRESS_BAR_STYLE,
+                escape(&self.url),
+                escape(&self.placeholder),
+                input_style,
+            );
+            if let Some(display_url) = display_url {
+                let _ = write!(
+                    html,
+                    r#"<div aria-hidden="true" style="{}"><span>{}</span><span style="{}">{}</span><span style="{}">{}</span></div>"#,
+                    style::URL_DISPLAY_STYLE,
+                    escape(&display_url.subdomain),
+                    style::URL_DOMAIN_STYLE,
+                    escape(&display_url.domain),
+                    style::URL_PATH_STYLE,
+                    escape(&display_url.rest),
+                );
+            }
+            let _ = write!(
+                html,
+                r##"<button type="button" style="{}" aria-label="{REFRESH_LABEL}" disabled><svg width="11" height="13" viewBox="0 0 11 13" fill="none" xmlns="http://www.w3.org/2000/svg"><path d="{}" stroke="#767676" stroke-linecap="round" stroke-linejoin="round"></path></svg></button></div></div>"##,
+                style::REFRESH_BUTTON_STYLE,
+                icons::REFRESH,
+            );
+        }
+
+        html.push_str(
+            r#"<div style="display: flex; align-items: center; gap: 6px; margin-left: auto;">"#,
+        );
+        if self.show_controls {
+            icon_button(html, "Share", 15, 19, icons::SHARE, FILL_767676);
+            icon_button(html, "Tabs", 15, 15, icons::TABS, FILL_767676);
+            icon_button(html, "More options", 18, 19, icons::MORE, FILL_767676);
+        }
+        html.push_str("</div></header>");
+    }
+}
+
+const FILL_767676: &str = r##"fill="#767676""##;
+const STROKE_737373: &str =
+    r##"stroke="#737373" stroke-width="1.5" stroke-linecap="round" stroke-linejoin="round""##;
+const STROKE_BFBFBF: &str =
+    r##"stroke="#BFBFBF" stroke-width="1.5" stroke-linecap="round" stroke-linejoin="round""##;
+
+fn icon_button(html: &mut String, label: &str, width: u32, height: u32, path: &str, paint: &str) {
+    let _ = write!(
+        html,
+        r#"<button type="button" style="{}" aria-label="{label}" title="{label}" disabled><svg width="{width}" height="{height}" viewBox="0 0 {width} {height}" fill="none" xmlns="http://www.w3.org/2000/svg"><path d="{path}" {paint}></path></svg></button>"#,
+        style::ICON_BUTTON_STYLE,
+    );
+}
+
+fn attribute(name: &str, value: &str) -> String {
+    if value.is_empty() {
+        String::new()
+    } else {
+        format!(r#" {name}="{}""#, escape(value))
+    }
+}
+
+/// Escapes text for use in HTML content and quoted attribute values.
+pub fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_markup() {
+        assert_eq!(
+            escape(r#"<a href="x">Tom & 'Jerry'</a>"#),
+            "&lt;a href=&quot;x&quot;&gt;Tom &amp; &#39;Jerry&#39;&lt;/a&gt;"
+        );
+    }
+
+    #[test]
+    fn renders_chrome_and_content() {
+        let html = StaticFrame::new("https://opensass.org/?a=1&b=2")
+            .with_id("demo")
+            .with_content("<p>Hello</p>")
+            .render();
+
+        assert!(html.starts_with(r#"<article id="demo""#));
+        assert!(html.ends_with("<p>Hello</p></div></main></article>"));
+        assert!(html.contains(r#"value="https://opensass.org/?a=1&amp;b=2""#));
+        assert!(html.contains(r#"aria-label="Close window""#));
+        assert!(html.contains(r##"fill="#FF5F57""##));
+        assert!(html.contains(r#"aria-label="Back""#));
+        assert!(html.contains(&escape(&style::header_style(
+            style::HEADER_BASE_STYLE,
+            &Variant::Default,
+            &Size::Medium
+        ))));
+        assert!(!html.contains("class="));
+        assert!(html.contains(&format!(r#"aria-label="{ADDRESS_BAR_LABEL}""#)));
+        assert!(html.contains(&format!(r#"aria-label="{REFRESH_LABEL}""#)));
+        assert!(html.contains("background-color: light-dark(#ffffff, #1f2937);"));
+    }
+
+    #[test]
+    fn follows_variant_and_toggles() {
+        let ios = StaticFrame::new("")
+            .with_variant(Variant::Ios)
+            .with_size(Size::Full)
+            .render();
+        assert!(!ios.contains(r#"aria-label="Back""#));
+        assert!(ios.contains("width: 100%;"));
+        assert!(ios.contains("justify-content: space-between;"));
+
+        let bare = StaticFrame::new("")
+            .with_controls(false)
+            .with_address_bar(false)
+            .render();
+        assert!(!bare.contains("<nav"));
+        assert!(!bare.contains("<input"));
+        assert!(!bare.contains(r#"id=""#));
+
+        let classed = StaticFrame::new("").with_class("demo frame").render();
+        assert!(classed.starts_with(r#"<article class="demo frame""#));
+    }
+
+    #[test]
+    fn renders_inert_controls_and_the_domain() {
+        let html = StaticFrame::new("https://docs.opensass.org/browser").render();
+
+        assert!(!html.contains(r#"tabindex="0""#));
+        assert_eq!(
+            html.matches("<button").count(),
+            html.matches(" disabled>").count()
+        );
+        assert!(html.contains(r#"readonly tabindex="-1""#));
+        assert!(html.contains(&format!(
+            r#"<span style="{}">opensass.org</span>"#,
+            style::URL_DOMAIN_STYLE
+        )));
+        assert!(html.contains(&format!(
+            "{} color: transparent;",
+            style::ADDRESS_INPUT_STYLE
+        )));
+
+        let search = StaticFrame::new("rust book").render();
+        assert!(!search.contains(r#"aria-hidden="true""#));
+        assert!(!search.contains(" color: transparent;"));
+    }
+}
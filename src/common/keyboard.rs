@@ -0,0 +1,410 @@
+//! Framework-agnostic keyboard semantics.
+//!
+//! Bindings translate their keyboard events into a [`KeyInput`] and act on the
+//! command returned here, so every framework reacts to the same keys.
+
+use super::{MenuItem, menu_step};
+
+/// A key press, as reported by `KeyboardEvent.key` and its modifier flags.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct KeyInput<'a> {
+    pub key: &'a str,
+    pub ctrl: bool,
+    pub meta: bool,
+    pub alt: bool,
+    pub shift: bool,
+}
+
+impl<'a> KeyInput<'a> {
+    pub fn new(key: &'a str) -> Self {
+        Self {
+            key,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_ctrl(mut self, ctrl: bool) -> Self {
+        self.ctrl = ctrl;
+        self
+    }
+
+    pub fn with_meta(mut self, meta: bool) -> Self {
+        self.meta = meta;
+        self
+    }
+
+    pub fn with_alt(mut self, alt: bool) -> Self {
+        self.alt = alt;
+        self
+    }
+
+    pub fn with_shift(mut self, shift: bool) -> Self {
+        self.shift = shift;
+        self
+    }
+
+    /// Whether `Ctrl` or `Cmd` is held, the modifier of browser shortcuts.
+    pub fn has_command_modifier(&self) -> bool {
+        self.ctrl || self.meta
+    }
+
+    /// The typed character when the key is a single printable character
+    /// without `Ctrl`, `Cmd` or `Alt`.
+    pub fn printable(&self) -> Option<&'a str> {
+        let mut chars = self.key.chars();
+        let single = chars.next().is_some() && chars.next().is_none();
+        (single && !self.ctrl && !self.meta && !self.alt).then_some(self.key)
+    }
+}
+
+/// Moves from `current` to the next (`forward`) or previous of `count`
+/// entries, wrapping around. Starts at the first or last entry when nothing is
+/// selected.
+pub fn cycle_index(current: Option<usize>, count: usize, forward: bool) -> Option<usize> {
+    if count == 0 {
+        return None;
+    }
+    Some(match (current, forward) {
+        (Some(index), true) => (index + 1) % count,
+        (Some(index), false) => (index + count - 1) % count,
+        (None, true) => 0,
+        (None, false) => count - 1,
+    })
+}
+
+/// Keys handled by `use_keyboard` for the whole frame.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NavigationCommand {
+    Escape,
+    Enter,
+    /// Keep focus inside the container, moving backwards with `Shift`.
+    TrapFocus,
+}
+
+/// Maps a key press to a [`NavigationCommand`]. `inside` tells whether the
+/// press started inside the container; `Escape` elsewhere on the page is left
+/// alone, so one press closes a single frame on a page with several.
+pub fn navigation_command(
+    input: &KeyInput,
+    trap_focus: bool,
+    inside: bool,
+) -> Option<NavigationCommand> {
+    match input.key {
+        "Escape" if inside => Some(NavigationCommand::Escape),
+        "Enter" => Some(NavigationCommand::Enter),
+        "Tab" if trap_focus => Some(NavigationCommand::TrapFocus),
+        _ => None,
+    }
+}
+
+/// Browser shortcuts handled while focus is inside the frame.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FrameCommand {
+    Find,
+    ZoomIn,
+    ZoomOut,
+    ZoomReset,
+}
+
+/// Maps `Cmd/Ctrl` + `F`, `+`, `-` and `0` to frame commands. `Find` is only
+/// returned when `find_in_page` is enabled.
+pub fn frame_command(input: &KeyInput, find_in_page: bool) -> Option<FrameCommand> {
+    if !input.has_command_modifier() {
+        return None;
+    }
+    match input.key {
+        "f" | "F" if find_in_page => Some(FrameCommand::Find),
+        "+" | "=" => Some(FrameCommand::ZoomIn),
+        "-" | "_" => Some(FrameCommand::ZoomOut),
+        "0" => Some(FrameCommand::ZoomReset),
+        _ => None,
+    }
+}
+
+/// Keys handled by a `MenuList`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MenuCommand<'a> {
+    /// Move the active item.
+    Highlight(Option<usize>),
+    /// Select the item, or open its submenu.
+    Activate(usize),
+    /// Return focus to the parent menu.
+    Collapse,
+    Close,
+    /// Close the menu and let `Tab` move focus as usual.
+    Leave,
+    /// Extend the typeahead query with the typed character.
+    Typeahead(&'a str),
+}
+
+/// Maps a key press in a menu given its `items`, the `active` item and
+/// whether the menu is a submenu (`nested`).
+pub fn menu_command<'a>(
+    input: &KeyInput<'a>,
+    items: &[MenuItem],
+    active: Option<usize>,
+    nested: bool,
+) -> Option<MenuCommand<'a>> {
+    match input.key {
+        "ArrowDown" => Some(MenuCommand::Highlight(menu_step(items, active, true))),
+        "ArrowUp" => Some(MenuCommand::Highlight(menu_step(items, active, false))),
+        "Home" => Some(MenuCommand::Highlight(menu_step(items, None, true))),
+        "End" => Some(MenuCommand::Highlight(menu_step(items, None, false))),
+        "Enter" | " " => active.map(MenuCommand::Activate),
+        "ArrowRight" => active
+            .filter(|&index| items[index].has_submenu())
+            .map(MenuCommand::Activate),
+        "ArrowLeft" | "Escape" if nested => Some(MenuCommand::Collapse),
+        "Escape" => Some(MenuCommand::Close),
+        "Tab" => Some(MenuCommand::Leave),
+        _ => input.printable().map(MenuCommand::Typeahead),
+    }
+}
+
+/// Keys handled by the find bar input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FindCommand {
+    Next,
+    Previous,
+    Close,
+}
+
+pub fn find_command(input: &KeyInput) -> Option<FindCommand> {
+    match input.key {
+        "Enter" if input.shift => Some(FindCommand::Previous),
+        "Enter" => Some(FindCommand::Next),
+        "Escape" => Some(FindCommand::Close),
+        _ => None,
+    }
+}
+
+/// The toolbar item to focus for a key press on the item at `current` of
+/// `count`, following the roving tabindex of the WAI-ARIA toolbar pattern.
+pub fn toolbar_index(input: &KeyInput, current: usize, count: usize) -> Option<usize> {
+    if count == 0 || input.has_command_modifier() || input.alt {
+        return None;
+    }
+    match input.key {
+        "ArrowRight" => cycle_index(Some(current), count, true),
+        "ArrowLeft" => cycle_index(Some(current), count, false),
+        "Home" => Some(0),
+        "End" => Some(count - 1),
+        _ => None,
+    }
+}
+
+/// Keys handled by the address bar input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AddressBarCommand {
+    /// Highlight the suggestion at the index and preview its URL.
+    Highlight(usize),
+    /// Open the suggestion list.
+    Open,
+    /// Close the list and restore the typed text.
+    Dismiss,
+    /// Navigate to the highlighted suggestion, or to the typed text.
+    Submit(Option<usize>),
+}
+
+/// Maps a key press in the address bar given the highlighted suggestion
+/// `active`, the number of suggestions and whether the list is `expanded`.
+pub fn address_bar_command(
+    input: &KeyInput,
+    active: Option<usize>,
+    count: usize,
+    expanded: bool,
+) -> Option<AddressBarCommand> {
+    match input.key {
+        "ArrowDown" | "ArrowUp" if expanded => {
+            cycle_index(active, count, input.key == "ArrowDown").map(AddressBarCommand::Highlight)
+        }
+        "ArrowDown" if count > 0 => Some(AddressBarCommand::Open),
+        "Escape" if expanded => Some(AddressBarCommand::Dismiss),
+        "Enter" => Some(AddressBarCommand::Submit(active.filter(|_| expanded))),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cycles_through_entries() {
+        assert_eq!(cycle_index(None, 3, true), Some(0));
+        assert_eq!(cycle_index(None, 3, false), Some(2));
+        assert_eq!(cycle_index(Some(2), 3, true), Some(0));
+        assert_eq!(cycle_index(Some(0), 3, false), Some(2));
+        assert_eq!(cycle_index(Some(0), 0, true), None);
+    }
+
+    #[test]
+    fn roves_through_toolbar_items() {
+        assert_eq!(toolbar_index(&KeyInput::new("ArrowRight"), 2, 3), Some(0));
+        assert_eq!(toolbar_index(&KeyInput::new("ArrowLeft"), 0, 3), Some(2));
+        assert_eq!(toolbar_index(&KeyInput::new("Home"), 2, 3), Some(0));
+        assert_eq!(toolbar_index(&KeyInput::new("End"), 0, 3), Some(2));
+        assert_eq!(toolbar_index(&KeyInput::new("ArrowDown"), 0, 3), None);
+        assert_eq!(
+            toolbar_index(&KeyInput::new("ArrowLeft").with_alt(true), 1, 3),
+            None
+        );
+    }
+
+    #[test]
+    fn detects_printable_keys() {
+        assert_eq!(KeyInput::new("a").printable(), Some("a"));
+        assert_eq!(KeyInput::new("é").printable(), Some("é"));
+        assert_eq!(KeyInput::new("ArrowDown").printable(), None);
+        assert_eq!(KeyInput::new("a").with_ctrl(true).printable(), None);
+        assert_eq!(KeyInput::new("").printable(), None);
+    }
+
+    #[test]
+    fn maps_navigation_keys() {
+        assert_eq!(
+            navigation_command(&KeyInput::new("Escape"), false, true),
+            Some(NavigationCommand::Escape)
+        );
+        assert_eq!(navigation_command(&KeyInput::new("Tab"), false, true), None);
+        assert_eq!(
+            navigation_command(&KeyInput::new("Tab"), true, true),
+            Some(NavigationCommand::TrapFocus)
+        );
+    }
+
+    #[test]
+    fn escape_closes_only_the_frame_it_was_pressed_in() {
+        let escape = KeyInput::new("Escape");
+        // Two frames on one page see the same key press; it started inside
+        // the first one only.
+        let frames = [true, false].map(|inside| navigation_command(&escape, false, inside));
+        assert_eq!(frames, [Some(NavigationCommand::Escape), None]);
+        assert_eq!(
+            navigation_command(&KeyInput::new("Tab"), true, false),
+            Some(NavigationCommand::TrapFocus)
+        );
+    }
+
+    #[test]
+    fn maps_frame_shortcuts() {
+        let ctrl = |key| KeyInput::new(key).with_ctrl(true);
+        let meta = |key| KeyInput::new(key).with_meta(true);
+
+        assert_eq!(frame_command(&ctrl("f"), true), Some(FrameCommand::Find));
+        assert_eq!(frame_command(&meta("F"), true), Some(FrameCommand::Find));
+        assert_eq!(frame_command(&ctrl("f"), false), None);
+        assert_eq!(frame_command(&ctrl("="), true), Some(FrameCommand::ZoomIn));
+        assert_eq!(frame_command(&meta("+"), true), Some(FrameCommand::ZoomIn));
+        assert_eq!(frame_command(&ctrl("-"), true), Some(FrameCommand::ZoomOut));
+        assert_eq!(
+            frame_command(&ctrl("0"), true),
+            Some(FrameCommand::ZoomReset)
+        );
+        assert_eq!(frame_command(&KeyInput::new("f"), true), None);
+    }
+
+    #[test]
+    fn maps_menu_keys() {
+        let items = vec![
+            MenuItem::new("a", "Alpha"),
+            MenuItem::separator(),
+            MenuItem::new("b", "Beta").with_submenu(vec![MenuItem::new("c", "Gamma")]),
+        ];
+        let key = |key| KeyInput::new(key);
+
+        assert_eq!(
+            menu_command(&key("ArrowDown"), &items, Some(0), false),
+            Some(MenuCommand::Highlight(Some(2)))
+        );
+        assert_eq!(
+            menu_command(&key("Home"), &items, Some(2), false),
+            Some(MenuCommand::Highlight(Some(0)))
+        );
+        assert_eq!(
+            menu_command(&key(" "), &items, Some(0), false),
+            Some(MenuCommand::Activate(0))
+        );
+        assert_eq!(
+            menu_command(&key("ArrowRight"), &items, Some(0), false),
+            None
+        );
+        assert_eq!(
+            menu_command(&key("ArrowRight"), &items, Some(2), false),
+            Some(MenuCommand::Activate(2))
+        );
+        assert_eq!(
+            menu_command(&key("ArrowLeft"), &items, Some(0), false),
+            None
+        );
+        assert_eq!(
+            menu_command(&key("ArrowLeft"), &items, Some(0), true),
+            Some(MenuCommand::Collapse)
+        );
+        assert_eq!(
+            menu_command(&key("Escape"), &items, Some(0), false),
+            Some(MenuCommand::Close)
+        );
+        assert_eq!(
+            menu_command(&key("Tab"), &items, Some(0), false),
+            Some(MenuCommand::Leave)
+        );
+        assert_eq!(
+            menu_command(&key("b"), &items, Some(0), false),
+            Some(MenuCommand::Typeahead("b"))
+        );
+        assert_eq!(
+            menu_command(&key("b").with_meta(true), &items, Some(0), false),
+            None
+        );
+    }
+
+    #[test]
+    fn maps_find_keys() {
+        assert_eq!(
+            find_command(&KeyInput::new("Enter")),
+            Some(FindCommand::Next)
+        );
+        assert_eq!(
+            find_command(&KeyInput::new("Enter").with_shift(true)),
+            Some(FindCommand::Previous)
+        );
+        assert_eq!(
+            find_command(&KeyInput::new("Escape")),
+            Some(FindCommand::Close)
+        );
+        assert_eq!(find_command(&KeyInput::new("a")), None);
+    }
+
+    #[test]
+    fn maps_address_bar_keys() {
+        let down = KeyInput::new("ArrowDown");
+        let up = KeyInput::new("ArrowUp");
+
+        assert_eq!(
+            address_bar_command(&down, None, 3, false),
+            Some(AddressBarCommand::Open)
+        );
+        assert_eq!(address_bar_command(&down, None, 0, false), None);
+        assert_eq!(
+            address_bar_command(&down, Some(2), 3, true),
+            Some(AddressBarCommand::Highlight(0))
+        );
+        assert_eq!(
+            address_bar_command(&up, None, 3, true),
+            Some(AddressBarCommand::Highlight(2))
+        );
+        assert_eq!(
+            address_bar_command(&KeyInput::new("Escape"), None, 3, false),
+            None
+        );
+        assert_eq!(
+            address_bar_command(&KeyInput::new("Enter"), Some(1), 3, false),
+            Some(AddressBarCommand::Submit(None))
+        );
+        assert_eq!(
+            address_bar_command(&KeyInput::new("Enter"), Some(1), 3, true),
+            Some(AddressBarCommand::Submit(Some(1)))
+        );
+    }
+}
@@ -0,0 +1,215 @@
+//! Keyboard and pointer state of a WAI-ARIA menu.
+//!
+//! The bindings keep a [`MenuState`] per rendered menu list, feed it key
+//! presses, clicks and hovers, and carry out the returned [`MenuAction`].
+
+use super::{
+    MenuItem,
+    keyboard::{KeyInput, MenuCommand, menu_command},
+    menu_step, menu_typeahead,
+};
+
+/// Milliseconds without a key press after which typeahead starts over.
+pub const TYPEAHEAD_TIMEOUT: u32 = 500;
+
+/// The active and expanded items of a menu and its typeahead query.
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct MenuState {
+    /// Item that `aria-activedescendant` points at.
+    pub active: Option<usize>,
+    /// Item whose submenu is open.
+    pub expanded: Option<usize>,
+    typeahead: String,
+}
+
+/// What a binding does after [`MenuState`] handled an event.
+#[derive(PartialEq, Clone, Debug)]
+pub enum MenuAction {
+    /// The active or expanded item changed.
+    Update,
+    /// The typeahead query grew; call [`MenuState::reset_typeahead`] once
+    /// [`TYPEAHEAD_TIMEOUT`] passes without another key.
+    Typeahead,
+    /// The item with this id was chosen; report it and close the menu.
+    Select(String),
+    /// Return focus to the parent menu.
+    Collapse,
+    Close,
+    /// Close the menu and let `Tab` move focus as usual.
+    Leave,
+}
+
+impl MenuAction {
+    /// Whether the key press that led here should not reach the page.
+    pub fn prevents_default(&self) -> bool {
+        *self != MenuAction::Leave
+    }
+}
+
+impl MenuState {
+    /// A freshly opened menu with its first item active.
+    pub fn new(items: &[MenuItem]) -> Self {
+        Self {
+            active: menu_step(items, None, true),
+            ..Default::default()
+        }
+    }
+
+    /// Handles a key press, or returns `None` when the menu ignores it.
+    pub fn key(
+        &mut self,
+        input: &KeyInput,
+        items: &[MenuItem],
+        nested: bool,
+    ) -> Option<MenuAction> {
+        match menu_command(input, items, self.active, nested)? {
+            MenuCommand::Highlight(index) => {
+                self.active = index;
+                Some(MenuAction::Update)
+            }
+            MenuCommand::Activate(index) => self.activate(items, index),
+            MenuCommand::Collapse => Some(MenuAction::Collapse),
+            MenuCommand::Close => Some(MenuAction::Close),
+            MenuCommand::Leave => Some(MenuAction::Leave),
+            MenuCommand::Typeahead(key) => {
+                self.typeahead.push_str(key);
+                if let Some(index) = menu_typeahead(items, self.active, &self.typeahead) {
+                    self.active = Some(index);
+                    self.expanded = None;
+                }
+                Some(MenuAction::Typeahead)
+            }
+        }
+    }
+
+    /// Chooses the item at `index`: opens its submenu or selects it.
+    /// Disabled items and separators do nothing.
+    pub fn activate(&mut self, items: &[MenuItem], index: usize) -> Option<MenuAction> {
+        let item = items
+            .get(index)
+            .filter(|item| !item.disabled && !item.separator)?;
+        if item.has_submenu() {
+            self.expanded = Some(index);
+            Some(MenuAction::Update)
+        } else {
+            Some(MenuAction::Select(item.id.clone()))
+        }
+    }
+
+    /// Moves the pointer onto the item at `index`, opening its submenu.
+    pub fn hover(&mut self, items: &[MenuItem], index: usize) {
+        self.active = Some(index);
+        self.expanded = items
+            .get(index)
+            .is_some_and(|item| item.has_submenu() && !item.disabled)
+            .then_some(index);
+    }
+
+    /// Closes the open submenu.
+    pub fn collapse(&mut self) {
+        self.expanded = None;
+    }
+
+    pub fn reset_typeahead(&mut self) {
+        self.typeahead.clear();
+    }
+}
+
+/// Id of the item at `index` in the menu with the id `menu_id`.
+pub fn menu_item_id(menu_id: &str, index: usize) -> String {
+    format!("{menu_id}-item-{index}")
+}
+
+/// Id of the submenu opened by the item at `index`.
+pub fn submenu_id(menu_id: &str, index: usize) -> String {
+    format!("{menu_id}-{index}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn items() -> Vec<MenuItem> {
+        vec![
+            MenuItem::new("new", "New tab"),
+            MenuItem::separator(),
+            MenuItem::new("print", "Print").with_disabled(true),
+            MenuItem::new("zoom", "Zoom").with_submenu(vec![MenuItem::new("in", "Zoom in")]),
+            MenuItem::new("pin", "Pin tab"),
+        ]
+    }
+
+    #[test]
+    fn moves_selects_and_expands_with_keys() {
+        let items = items();
+        let mut state = MenuState::new(&items);
+        assert_eq!(state.active, Some(0));
+
+        let down = KeyInput::new("ArrowDown");
+        assert_eq!(state.key(&down, &items, false), Some(MenuAction::Update));
+        assert_eq!(state.active, Some(2));
+
+        let enter = KeyInput::new("Enter");
+        assert_eq!(state.key(&enter, &items, false), None);
+        state.key(&down, &items, false);
+        assert_eq!(
+            state.key(&KeyInput::new("ArrowRight"), &items, false),
+            Some(MenuAction::Update)
+        );
+        assert_eq!(state.expanded, Some(3));
+
+        state.key(&down, &items, false);
+        assert_eq!(
+            state.key(&enter, &items, false),
+            Some(MenuAction::Select("pin".to_string()))
+        );
+        assert_eq!(
+            state.key(&KeyInput::new("Escape"), &items, true),
+            Some(MenuAction::Collapse)
+        );
+        assert!(
+            !state
+                .key(&KeyInput::new("Tab"), &items, false)
+                .unwrap()
+                .prevents_default()
+        );
+        assert_eq!(state.key(&KeyInput::new("F5"), &items, false), None);
+    }
+
+    #[test]
+    fn types_ahead_until_reset() {
+        let items = items();
+        let mut state = MenuState::new(&items);
+
+        assert_eq!(
+            state.key(&KeyInput::new("p"), &items, false),
+            Some(MenuAction::Typeahead)
+        );
+        assert_eq!(state.active, Some(2));
+        state.key(&KeyInput::new("i"), &items, false);
+        assert_eq!(state.active, Some(4));
+
+        state.reset_typeahead();
+        state.key(&KeyInput::new("z"), &items, false);
+        assert_eq!(state.active, Some(3));
+    }
+
+    #[test]
+    fn hovers_into_submenus() {
+        let items = items();
+        let mut state = MenuState::new(&items);
+
+        state.hover(&items, 3);
+        assert_eq!((state.active, state.expanded), (Some(3), Some(3)));
+        state.hover(&items, 4);
+        assert_eq!((state.active, state.expanded), (Some(4), None));
+        assert_eq!(state.activate(&items, 2), None);
+        assert_eq!(state.activate(&items, 1), None);
+
+        state.activate(&items, 3);
+        state.collapse();
+        assert_eq!(state.expanded, None);
+        assert_eq!(menu_item_id("more", 3), "more-item-3");
+        assert_eq!(submenu_id("more", 3), "more-3");
+    }
+}
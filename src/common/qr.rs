@@ -0,0 +1,536 @@
+//! A small QR Code encoder used by the share popover.
+//!
+//! Only what is needed to share a link is supported: byte mode data with the
+//! medium (`M`) error correction level, versions 1 through 40, and automatic
+//! mask selection.
+
+const ECC_CODEWORDS_PER_BLOCK: [usize; 41] = [
+    0, 10, 16, 26, 18, 24, 16, 18, 22, 22, 26, 30, 22, 22, 24, 24, 28, 28, 26, 26, 26, 26, 28, 28,
+    28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28,
+];
+
+const NUM_ERROR_CORRECTION_BLOCKS: [usize; 41] = [
+    0, 1, 1, 1, 2, 2, 4, 4, 4, 5, 5, 5, 8, 9, 9, 10, 10, 11, 13, 14, 16, 17, 17, 18, 20, 21, 23,
+    25, 26, 28, 29, 31, 33, 35, 37, 38, 40, 43, 45, 47, 49,
+];
+
+/// Format bits of the `M` error correction level.
+const ECC_LEVEL_BITS: u32 = 0;
+
+/// A QR Code symbol as a square grid of dark and light modules.
+#[derive(PartialEq, Clone, Debug)]
+pub struct QrCode {
+    size: usize,
+    modules: Vec<bool>,
+    is_function: Vec<bool>,
+}
+
+impl QrCode {
+    /// Encodes `data` in byte mode, picking the smallest version that fits.
+    ///
+    /// Returns `None` when the data is longer than a version 40 symbol holds.
+    pub fn encode(data: &[u8]) -> Option<Self> {
+        let version = (1..=40).find(|&version| {
+            let count_bits = if version < 10 { 8 } else { 16 };
+            4 + count_bits + data.len() * 8 <= num_data_codewords(version) * 8
+        })?;
+
+        let capacity = num_data_codewords(version);
+        let mut bits = BitBuffer::default();
+        bits.append(0b0100, 4);
+        bits.append(data.len() as u32, if version < 10 { 8 } else { 16 });
+        for &byte in data {
+            bits.append(byte as u32, 8);
+        }
+        let terminator = (capacity * 8 - bits.len()).min(4);
+        bits.append(0, terminator);
+        bits.append(0, (8 - bits.len() % 8) % 8);
+
+        let mut codewords = bits.into_bytes();
+        for pad in [0xEC, 0x11].into_iter().cycle() {
+            if codewords.len() >= capacity {
+                break;
+            }
+            codewords.push(pad);
+        }
+
+        let size = version * 4 + 17;
+        let mut qr = Self {
+            size,
+            modules: vec![false; size * size],
+            is_function: vec![false; size * size],
+        };
+        qr.draw_function_patterns(version);
+        qr.draw_codewords(&add_ecc_and_interleave(&codewords, version));
+
+        let mut best_mask = 0;
+        let mut min_penalty = u32::MAX;
+        for mask in 0..8 {
+            qr.apply_mask(mask);
+            qr.draw_format_bits(mask);
+            let penalty = qr.penalty_score();
+            if penalty < min_penalty {
+                best_mask = mask;
+                min_penalty = penalty;
+            }
+            qr.apply_mask(mask);
+        }
+        qr.apply_mask(best_mask);
+        qr.draw_format_bits(best_mask);
+        Some(qr)
+    }
+
+    /// Width and height of the symbol in modules, without a quiet zone.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn is_dark(&self, x: usize, y: usize) -> bool {
+        x < self.size && y < self.size && self.modules[y * self.size + x]
+    }
+
+    /// Returns SVG path data drawing every dark module as a unit square,
+    /// offset by a quiet zone of `border` modules.
+    ///
+    /// Use it with a `viewBox` of `0 0 {size + 2 * border} {size + 2 * border}`.
+    pub fn to_svg_path(&self, border: usize) -> String {
+        let mut path = String::new();
+        for y in 0..self.size {
+            for x in 0..self.size {
+                if self.is_dark(x, y) {
+                    if !path.is_empty() {
+                        path.push(' ');
+                    }
+                    path.push_str(&format!("M{},{}h1v1h-1z", x + border, y + border));
+                }
+            }
+        }
+        path
+    }
+
+    fn set_function_module(&mut self, x: usize, y: usize, dark: bool) {
+        let index = y * self.size + x;
+        self.modules[index] = dark;
+        self.is_function[index] = true;
+    }
+
+    fn draw_function_patterns(&mut self, version: usize) {
+        let size = self.size;
+        for i in 0..size {
+            self.set_function_module(6, i, i % 2 == 0);
+            self.set_function_module(i, 6, i % 2 == 0);
+        }
+
+        self.draw_finder_pattern(3, 3);
+        self.draw_finder_pattern(size - 4, 3);
+        self.draw_finder_pattern(3, size - 4);
+
+        let positions = alignment_pattern_positions(version);
+        let count = positions.len();
+        for (i, &x) in positions.iter().enumerate() {
+            for (j, &y) in positions.iter().enumerate() {
+                let overlaps_finder =
+                    (i == 0 && (j == 0 || j == count - 1)) || (i == count - 1 && j == 0);
+                if !overlaps_finder {
+                    self.draw_alignment_pattern(x, y);
+                }
+            }
+        }
+
+        // Reserve the format areas; the real bits are drawn once a mask is chosen.
+        self.draw_format_bits(0);
+        self.draw_version(version);
+    }
+
+    fn draw_finder_pattern(&mut self, x: usize, y: usize) {
+        for dy in -4i32..=4 {
+            for dx in -4i32..=4 {
+                let (xx, yy) = (x as i32 + dx, y as i32 + dy);
+                if (0..self.size as i32).contains(&xx) && (0..self.size as i32).contains(&yy) {
+                    let distance = dx.abs().max(dy.abs());
+                    self.set_function_module(
+                        xx as usize,
+                        yy as usize,
+                        distance != 2 && distance != 4,
+                    );
+                }
+            }
+        }
+    }
+
+    fn draw_alignment_pattern(&mut self, x: usize, y: usize) {
+        for dy in -2i32..=2 {
+            for dx in -2i32..=2 {
+                let distance = dx.abs().max(dy.abs());
+                self.set_function_module(
+                    (x as i32 + dx) as usize,
+                    (y as i32 + dy) as usize,
+                    distance != 1,
+                );
+            }
+        }
+    }
+
+    fn draw_format_bits(&mut self, mask: u32) {
+        let bits = format_bits(mask);
+        let bit = |i: usize| (bits >> i) & 1 != 0;
+
+        for i in 0..=5 {
+            self.set_function_module(8, i, bit(i));
+        }
+        self.set_function_module(8, 7, bit(6));
+        self.set_function_module(8, 8, bit(7));
+        self.set_function_module(7, 8, bit(8));
+        for i in 9..15 {
+            self.set_function_module(14 - i, 8, bit(i));
+        }
+
+        let size = self.size;
+        for i in 0..8 {
+            self.set_function_module(size - 1 - i, 8, bit(i));
+        }
+        for i in 8..15 {
+            self.set_function_module(8, size - 15 + i, bit(i));
+        }
+        self.set_function_module(8, size - 8, true);
+    }
+
+    fn draw_version(&mut self, version: usize) {
+        if version < 7 {
+            return;
+        }
+        let bits = version_bits(version);
+        for i in 0..18 {
+            let dark = (bits >> i) & 1 != 0;
+            let a = self.size - 11 + i % 3;
+            let b = i / 3;
+            self.set_function_module(a, b, dark);
+            self.set_function_module(b, a, dark);
+        }
+    }
+
+    fn draw_codewords(&mut self, data: &[u8]) {
+        let size = self.size as i32;
+        let mut bit_index = 0;
+        let mut right = size - 1;
+        while right >= 1 {
+            if right == 6 {
+                right = 5;
+            }
+            for vertical in 0..size {
+                for j in 0..2 {
+                    let x = (right - j) as usize;
+                    let upward = (right + 1) & 2 == 0;
+                    let y = if upward {
+                        size - 1 - vertical
+                    } else {
+                        vertical
+                    } as usize;
+                    let index = y * self.size + x;
+                    if !self.is_function[index] && bit_index < data.len() * 8 {
+                        self.modules[index] =
+                            (data[bit_index >> 3] >> (7 - (bit_index & 7))) & 1 != 0;
+                        bit_index += 1;
+                    }
+                }
+            }
+            right -= 2;
+        }
+    }
+
+    fn apply_mask(&mut self, mask: u32) {
+        for y in 0..self.size {
+            for x in 0..self.size {
+                let invert = match mask {
+                    0 => (x + y) % 2 == 0,
+                    1 => y % 2 == 0,
+                    2 => x % 3 == 0,
+                    3 => (x + y) % 3 == 0,
+                    4 => (x / 3 + y / 2) % 2 == 0,
+                    5 => x * y % 2 + x * y % 3 == 0,
+                    6 => (x * y % 2 + x * y % 3) % 2 == 0,
+                    _ => ((x + y) % 2 + x * y % 3) % 2 == 0,
+                };
+                let index = y * self.size + x;
+                if invert && !self.is_function[index] {
+                    self.modules[index] = !self.modules[index];
+                }
+            }
+        }
+    }
+
+    /// Scores the symbol with the four penalty rules of ISO/IEC 18004; lower is
+    /// easier to scan.
+    fn penalty_score(&self) -> u32 {
+        const FINDER_LIKE: [[bool; 11]; 2] = [
+            [
+                true, false, true, true, true, false, true, false, false, false, false,
+            ],
+            [
+                false, false, false, false, true, false, true, true, true, false, true,
+            ],
+        ];
+
+        let size = self.size;
+        let lines = (0..size)
+            .map(|y| (0..size).map(|x| self.is_dark(x, y)).collect::<Vec<_>>())
+            .chain((0..size).map(|x| (0..size).map(|y| self.is_dark(x, y)).collect::<Vec<_>>()));
+
+        let mut penalty = 0;
+        for line in lines {
+            let mut run = 1;
+            for i in 1..=size {
+                if i < size && line[i] == line[i - 1] {
+                    run += 1;
+                } else {
+                    if run >= 5 {
+                        penalty += 3 + (run - 5) as u32;
+                    }
+                    run = 1;
+                }
+            }
+            penalty += 40
+                * line
+                    .windows(11)
+                    .filter(|window| FINDER_LIKE.iter().any(|pattern| pattern == *window))
+                    .count() as u32;
+        }
+
+        for y in 0..size - 1 {
+            for x in 0..size - 1 {
+                let color = self.is_dark(x, y);
+                if color == self.is_dark(x + 1, y)
+                    && color == self.is_dark(x, y + 1)
+                    && color == self.is_dark(x + 1, y + 1)
+                {
+                    penalty += 3;
+                }
+            }
+        }
+
+        let dark = self.modules.iter().filter(|&&dark| dark).count();
+        let percent = (dark * 100 / self.modules.len()) as i32;
+        penalty + (percent - 50).unsigned_abs() / 5 * 10
+    }
+}
+
+#[derive(Default)]
+struct BitBuffer(Vec<bool>);
+
+impl BitBuffer {
+    fn append(&mut self, value: u32, len: usize) {
+        self.0.extend((0..len).rev().map(|i| (value >> i) & 1 != 0));
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn into_bytes(self) -> Vec<u8> {
+        self.0
+            .chunks(8)
+            .map(|chunk| chunk.iter().fold(0u8, |byte, &bit| (byte << 1) | bit as u8))
+            .collect()
+    }
+}
+
+/// The 15 format bits of the `M` level with `mask`: BCH protected and
+/// XOR-ed with `0x5412`.
+fn format_bits(mask: u32) -> u32 {
+    let data = (ECC_LEVEL_BITS << 3) | mask;
+    let mut remainder = data;
+    for _ in 0..10 {
+        remainder = (remainder << 1) ^ ((remainder >> 9) * 0x537);
+    }
+    ((data << 10) | remainder) ^ 0x5412
+}
+
+/// The 18 BCH protected version bits drawn from version 7 on.
+fn version_bits(version: usize) -> u32 {
+    let mut remainder = version as u32;
+    for _ in 0..12 {
+        remainder = (remainder << 1) ^ ((remainder >> 11) * 0x1F25);
+    }
+    ((version as u32) << 12) | remainder
+}
+
+fn num_raw_data_modules(version: usize) -> usize {
+    let mut result = (16 * version + 128) * version + 64;
+    if version >= 2 {
+        let num_align = version / 7 + 2;
+        result -= (25 * num_align - 10) * num_align - 55;
+        if version >= 7 {
+            result -= 36;
+        }
+    }
+    result
+}
+
+fn num_data_codewords(version: usize) -> usize {
+    num_raw_data_modules(version) / 8
+        - ECC_CODEWORDS_PER_BLOCK[version] * NUM_ERROR_CORRECTION_BLOCKS[version]
+}
+
+fn alignment_pattern_positions(version: usize) -> Vec<usize> {
+    if version == 1 {
+        return Vec::new();
+    }
+    let size = version * 4 + 17;
+    let num_align = version / 7 + 2;
+    let step = (version * 8 + num_align * 3 + 5) / (num_align * 4 - 4) * 2;
+    let mut result: Vec<usize> = (0..num_align - 1).map(|i| size - 7 - i * step).collect();
+    result.push(6);
+    result.reverse();
+    result
+}
+
+/// Splits the data codewords into blocks, appends Reed-Solomon error
+/// correction to each block and interleaves the result.
+fn add_ecc_and_interleave(data: &[u8], version: usize) -> Vec<u8> {
+    let num_blocks = NUM_ERROR_CORRECTION_BLOCKS[version];
+    let block_ecc_len = ECC_CODEWORDS_PER_BLOCK[version];
+    let raw_codewords = num_raw_data_modules(version) / 8;
+    let num_short_blocks = num_blocks - raw_codewords % num_blocks;
+    let short_block_len = raw_codewords / num_blocks;
+
+    let divisor = reed_solomon_divisor(block_ecc_len);
+    let mut blocks = Vec::with_capacity(num_blocks);
+    let mut offset = 0;
+    for i in 0..num_blocks {
+        let data_len = short_block_len - block_ecc_len + usize::from(i >= num_short_blocks);
+        let mut block = data[offset..offset + data_len].to_vec();
+        offset += data_len;
+        let ecc = reed_solomon_remainder(&block, &divisor);
+        if i < num_short_blocks {
+            block.push(0);
+        }
+        block.extend(ecc);
+        blocks.push(block);
+    }
+
+    let mut result = Vec::with_capacity(raw_codewords);
+    for i in 0..blocks[0].len() {
+        for (j, block) in blocks.iter().enumerate() {
+            if i != short_block_len - block_ecc_len || j >= num_short_blocks {
+                result.push(block[i]);
+            }
+        }
+    }
+    result
+}
+
+fn reed_solomon_divisor(degree: usize) -> Vec<u8> {
+    let mut result = vec![0u8; degree];
+    result[degree - 1] = 1;
+    let mut root = 1u8;
+    for _ in 0..degree {
+        for j in 0..degree {
+            result[j] = gf_multiply(result[j], root);
+            if j + 1 < degree {
+                result[j] ^= result[j + 1];
+            }
+        }
+        root = gf_multiply(root, 0x02);
+    }
+    result
+}
+
+fn reed_solomon_remainder(data: &[u8], divisor: &[u8]) -> Vec<u8> {
+    let mut result = vec![0u8; divisor.len()];
+    for &byte in data {
+        let factor = byte ^ result.remove(0);
+        result.push(0);
+        for (value, &coefficient) in result.iter_mut().zip(divisor) {
+            *value ^= gf_multiply(coefficient, factor);
+        }
+    }
+    result
+}
+
+/// Multiplies two elements of GF(2^8) modulo the QR Code polynomial `0x11D`.
+fn gf_multiply(x: u8, y: u8) -> u8 {
+    let mut z: u32 = 0;
+    for i in (0..8).rev() {
+        z = (z << 1) ^ ((z >> 7) * 0x11D);
+        z ^= ((y as u32 >> i) & 1) * x as u32;
+    }
+    z as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_and_version_bits_match_the_standard() {
+        let expected = [
+            0x5412, 0x5125, 0x5E7C, 0x5B4B, 0x45F9, 0x40CE, 0x4F97, 0x4AA0,
+        ];
+        for (mask, bits) in expected.into_iter().enumerate() {
+            assert_eq!(format_bits(mask as u32), bits, "mask {mask}");
+        }
+        assert_eq!(version_bits(7), 0x07C94);
+        assert_eq!(version_bits(8), 0x085BC);
+        assert_eq!(version_bits(40), 0x28C69);
+    }
+
+    #[test]
+    fn picks_the_smallest_version_up_to_the_capacity() {
+        assert_eq!(QrCode::encode(&[b'a'; 14]).unwrap().size(), 21);
+        assert_eq!(QrCode::encode(&[b'a'; 15]).unwrap().size(), 25);
+        assert_eq!(QrCode::encode(&[b'a'; 2331]).unwrap().size(), 177);
+        assert_eq!(QrCode::encode(&[b'a'; 2332]), None);
+    }
+
+    #[test]
+    fn computes_reed_solomon_codewords() {
+        // "HELLO WORLD" as version 1-M, from the worked example at
+        // https://www.thonky.com/qr-code-tutorial/error-correction-coding
+        let data = [
+            32, 91, 11, 120, 209, 114, 220, 77, 67, 64, 236, 17, 236, 17, 236, 17,
+        ];
+        assert_eq!(
+            reed_solomon_remainder(&data, &reed_solomon_divisor(10)),
+            [196, 35, 39, 119, 235, 215, 231, 226, 93, 23]
+        );
+    }
+
+    #[test]
+    fn draws_function_patterns_and_format_bits() {
+        let qr = QrCode::encode(b"https://opensass.org").unwrap();
+        let size = qr.size();
+        assert_eq!(size, 25);
+
+        // Finder pattern centers, timing patterns and the dark module.
+        for (x, y) in [(3, 3), (size - 4, 3), (3, size - 4)] {
+            assert!(qr.is_dark(x, y) && !qr.is_dark(x - 2, y) && qr.is_dark(x - 3, y));
+        }
+        for i in 8..size - 8 {
+            assert_eq!(qr.is_dark(i, 6), i % 2 == 0);
+            assert_eq!(qr.is_dark(6, i), i % 2 == 0);
+        }
+        assert!(qr.is_dark(8, size - 8));
+
+        // Both copies of the format bits name the `M` level and one mask.
+        let read = |cells: Vec<(usize, usize)>| {
+            cells.into_iter().enumerate().fold(0, |bits, (i, (x, y))| {
+                bits | (u32::from(qr.is_dark(x, y)) << i)
+            })
+        };
+        let first = read(
+            (0..=5)
+                .map(|i| (8, i))
+                .chain([(8, 7), (8, 8), (7, 8)])
+                .chain((9..15).map(|i| (14 - i, 8)))
+                .collect(),
+        );
+        let second = read(
+            (0..8)
+                .map(|i| (size - 1 - i, 8))
+                .chain((8..15).map(|i| (8, size - 15 + i)))
+                .collect(),
+        );
+        assert_eq!(first, second);
+        assert!((0..8).any(|mask| format_bits(mask) == first));
+    }
+}
@@ -0,0 +1,80 @@
+//! State of the share popover shown when the browser has no share sheet.
+
+/// Whether the share popover is open, the link was copied and the QR code is
+/// shown.
+#[derive(PartialEq, Clone, Copy, Default, Debug)]
+pub struct ShareState {
+    pub open: bool,
+    pub copied: bool,
+    pub show_qr: bool,
+}
+
+impl ShareState {
+    /// Opens the popover afresh, after the native share sheet was unavailable.
+    pub fn open_fallback(&mut self) {
+        *self = Self {
+            open: true,
+            ..Self::default()
+        };
+    }
+
+    pub fn close(&mut self) {
+        self.open = false;
+    }
+
+    pub fn mark_copied(&mut self) {
+        self.copied = true;
+    }
+
+    /// Shows or hides the QR code, returning whether it is now shown.
+    pub fn toggle_qr(&mut self) -> bool {
+        self.show_qr = !self.show_qr;
+        self.show_qr
+    }
+
+    pub fn copy_label(&self) -> &'static str {
+        if self.copied {
+            "Link copied"
+        } else {
+            "Copy link"
+        }
+    }
+
+    pub fn qr_label(&self) -> &'static str {
+        if self.show_qr {
+            "Hide QR code"
+        } else {
+            "Show QR code"
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reopens_without_earlier_results() {
+        let mut share = ShareState::default();
+        share.open_fallback();
+        share.mark_copied();
+        assert!(share.toggle_qr());
+        assert_eq!(share.copy_label(), "Link copied");
+        assert_eq!(share.qr_label(), "Hide QR code");
+
+        share.close();
+        assert!(!share.open);
+        share.open_fallback();
+        assert_eq!(
+            share,
+            ShareState {
+                open: true,
+                copied: false,
+                show_qr: false
+            }
+        );
+        assert_eq!(share.copy_label(), "Copy link");
+        assert!(share.toggle_qr());
+        assert!(!share.toggle_qr());
+    }
+}
@@ -0,0 +1,186 @@
+//! Default styles shared by every framework binding.
+//!
+//! Each constant is the default value of the matching component prop, so
+//! overriding one in Yew or Dioxus behaves the same way.
+
+use super::{Size, Variant};
+
+// Frame
+//
+// Colors are given twice: browsers without `light-dark()` drop the second
+// declaration and keep the light one.
+pub const FRAME_BASE_STYLE: &str = "color-scheme: light dark; overflow: hidden; color: #111827; color: light-dark(#111827, #ffffff); background-color: #ffffff; background-color: light-dark(#ffffff, #1f2937); border: 1px solid #e5e7eb; border-color: light-dark(#e5e7eb, #374151); border-radius: 0.5rem; box-shadow: 0 10px 15px -3px rgba(0,0,0,0.1), 0 4px 6px -4px rgba(0,0,0,0.1);";
+pub const HEADER_BASE_STYLE: &str = "display: flex; align-items: center; position: relative;";
+pub const ADDRESS_WRAPPER_BASE_STYLE: &str =
+    "flex: 1; display: flex; justify-content: center; padding-right: 8px;";
+pub const ICON_BUTTON_STYLE: &str =
+    "padding: 4px; cursor: pointer; background: none; border: none; box-shadow: none;";
+pub const PROGRESS_BAR_STYLE: &str = "position: absolute; left: 0; bottom: 0; height: 2px; pointer-events: none; transition: width 0.2s ease, opacity 0.4s ease;";
+
+// Text read by screen readers but not shown
+pub const VISUALLY_HIDDEN: &str = "position: absolute; width: 1px; height: 1px; padding: 0; margin: -1px; overflow: hidden; clip: rect(0, 0, 0, 0); white-space: nowrap; border: 0;";
+
+// Window controls
+pub const CONTROLS_STYLE: &str =
+    "display: flex; align-items: center; background: none; padding-left: 10px;";
+pub const CONTROL_BUTTON_STYLE: &str = "width: 1rem; height: 1rem; display: flex; align-items: center; justify-content: center; transition: all 0.2s ease; cursor: pointer; background: none; border: none; padding: 0; margin-right: 0.5rem;";
+
+// Windows High Contrast and other forced colors modes
+pub const FORCED_COLORS_QUERY: &str = "(forced-colors: active)";
+
+// Title bar and tabs
+pub const TITLE_BAR_STYLE: &str = "display: flex; align-items: center; justify-content: center; gap: 6px; height: 28px; padding: 0 12px; font-size: 0.75rem; color: #4b5563; overflow: hidden;";
+pub const TAB_LIST_STYLE: &str = "display: flex; align-items: flex-end; padding: 6px 8px 0 8px;";
+pub const TAB_STYLE: &str = "display: flex; align-items: center; gap: 6px; max-width: 14rem; height: 28px; padding: 0 12px; font-size: 0.75rem; color: #111827; background-color: #ffffff; border: 1px solid #d1d5db; border-bottom: none; border-radius: 6px 6px 0 0;";
+
+// Address bar
+pub const ADDRESS_BAR_STYLE: &str = "flex: 1; margin-left: 1rem; margin-right: 1rem; border: 1px solid #d1d5db; border-radius: 0.375rem; padding-left: 0.75rem; padding-right: 0.75rem; font-size: 0.875rem; position: relative;";
+pub const ADDRESS_BAR_FOCUS_STYLE: &str =
+    "border-color: #3b82f6; box-shadow: 0 0 0 2px rgba(59,130,246,0.35);";
+pub const ADDRESS_INPUT_STYLE: &str = "background-color: transparent; padding-right: 2rem; border: none; outline: none; box-shadow: none; height: 100%;";
+pub const REFRESH_BUTTON_STYLE: &str = "position: absolute; top: 50%; right: 8px; transform: translateY(-50%); padding: 4px; background: none; border: none; box-shadow: none; cursor: pointer;";
+pub const ZOOM_BUTTON_STYLE: &str = "position: absolute; top: 50%; right: 2rem; transform: translateY(-50%); padding: 1px 6px; font-size: 0.7rem; color: #4b5563; background-color: #e5e7eb; border: none; border-radius: 9999px; cursor: pointer;";
+pub const URL_DISPLAY_STYLE: &str = "position: absolute; top: 0; bottom: 0; left: 0.75rem; right: 2rem; display: flex; align-items: center; overflow: hidden; white-space: nowrap; pointer-events: none;";
+pub const URL_DOMAIN_STYLE: &str = "font-weight: 600;";
+pub const URL_PATH_STYLE: &str = "color: #9ca3af;";
+pub const SUGGESTION_LISTBOX_STYLE: &str = "position: absolute; top: 100%; left: 0; right: 0; z-index: 20; margin: 4px 0 0 0; padding: 4px 0; list-style: none; background-color: #ffffff; border: 1px solid #d1d5db; border-radius: 0.375rem; box-shadow: 0 4px 12px rgba(0,0,0,0.15); max-height: 16rem; overflow-y: auto; text-align: left;";
+pub const SUGGESTION_OPTION_STYLE: &str = "display: flex; gap: 8px; align-items: baseline; padding: 6px 12px; cursor: pointer; color: #111827; white-space: nowrap; overflow: hidden; text-overflow: ellipsis;";
+pub const SUGGESTION_MATCH_STYLE: &str = "background: none; color: inherit; font-weight: 600;";
+pub const ACTIVE_ITEM_STYLE: &str = "background-color: #e5e7eb;";
+
+// Toolbar
+pub const TOOLBAR_TOGGLED_STYLE: &str = "background-color: rgba(0,0,0,0.08); border-radius: 6px;";
+pub const TOOLBAR_BADGE_STYLE: &str = "position: absolute; top: 0; right: 0; min-width: 14px; height: 14px; padding: 0 3px; font-size: 0.625rem; line-height: 14px; color: #ffffff; text-align: center; background-color: #ef4444; border-radius: 7px; pointer-events: none;";
+
+// Share menu
+pub const SHARE_POPOVER_STYLE: &str = "position: absolute; top: calc(100% + 6px); right: 0; z-index: 50; display: flex; flex-direction: column; min-width: 12rem; padding: 4px; background-color: #ffffff; border: 1px solid #d1d5db; border-radius: 8px; box-shadow: 0 8px 24px rgba(0,0,0,0.15);";
+pub const SHARE_ITEM_STYLE: &str = "display: block; width: 100%; padding: 6px 10px; font-size: 0.875rem; color: #111827; text-align: left; text-decoration: none; background: none; border: none; border-radius: 4px; cursor: pointer;";
+pub const SHARE_QR_STYLE: &str = "display: block; width: 160px; height: 160px; margin: 6px auto;";
+
+// Menus
+pub const MENU_STYLE: &str = "position: absolute; top: calc(100% + 6px); right: 0; z-index: 50; min-width: 14rem; margin: 0; padding: 4px; list-style: none; background-color: #ffffff; border: 1px solid #d1d5db; border-radius: 8px; box-shadow: 0 8px 24px rgba(0,0,0,0.15); outline: none;";
+pub const SUBMENU_STYLE: &str = "top: -5px; right: calc(100% + 2px);";
+pub const MENU_ITEM_STYLE: &str = "position: relative; display: flex; align-items: center; gap: 8px; padding: 6px 10px; font-size: 0.875rem; color: #111827; border-radius: 4px; cursor: pointer; white-space: nowrap;";
+pub const MENU_ITEM_DISABLED_STYLE: &str = "opacity: 0.5; cursor: default;";
+pub const MENU_SEPARATOR_STYLE: &str = "height: 1px; margin: 4px 0; background-color: #e5e7eb;";
+pub const MENU_SHORTCUT_STYLE: &str =
+    "margin-left: auto; padding-left: 16px; font-size: 0.75rem; color: #6b7280;";
+
+// Bookmarks bar
+pub const BOOKMARKS_BAR_STYLE: &str = "position: relative; display: flex; align-items: center; gap: 2px; height: 30px; padding: 0 8px; font-size: 0.75rem; border-bottom: 1px solid #e5e7eb;";
+pub const BOOKMARK_ITEM_STYLE: &str = "display: flex; align-items: center; gap: 4px; max-width: 10rem; padding: 3px 8px; font-size: inherit; color: #374151; text-decoration: none; white-space: nowrap; background: none; border: none; border-radius: 4px; cursor: pointer;";
+pub const BOOKMARK_MENU_STYLE: &str = "position: absolute; top: 2px; left: 0; z-index: 50; min-width: 14rem; margin: 0; padding: 4px; list-style: none; background-color: #ffffff; border: 1px solid #d1d5db; border-radius: 8px; box-shadow: 0 8px 24px rgba(0,0,0,0.15); outline: none;";
+pub const BOOKMARK_OVERFLOW_MENU_STYLE: &str = "position: absolute; top: 2px; right: 0; z-index: 50; min-width: 14rem; margin: 0; padding: 4px; list-style: none; background-color: #ffffff; border: 1px solid #d1d5db; border-radius: 8px; box-shadow: 0 8px 24px rgba(0,0,0,0.15); outline: none;";
+
+// Find bar
+pub const FIND_BAR_STYLE: &str = "display: flex; align-items: center; gap: 4px; padding: 4px 8px; font-size: 0.75rem; border-bottom: 1px solid #e5e7eb;";
+pub const FIND_INPUT_STYLE: &str = "flex: 1; max-width: 16rem; padding: 3px 8px; font-size: inherit; border: 1px solid #d1d5db; border-radius: 4px; outline: none;";
+pub const FIND_STATUS_STYLE: &str = "min-width: 5rem; color: #6b7280; white-space: nowrap;";
+pub const FIND_BUTTON_STYLE: &str = "display: flex; align-items: center; justify-content: center; padding: 4px; background: none; border: none; border-radius: 4px; cursor: pointer;";
+pub const FIND_HIGHLIGHT_COLOR: &str = "#fde047";
+pub const FIND_CURRENT_HIGHLIGHT_COLOR: &str = "#f97316";
+
+/// Builds the inline style of the browser header for a variant and size.
+pub fn header_style(base: &str, variant: &Variant, size: &Size) -> String {
+    let padding = match size {
+        Size::Small => "4px 6px",
+        Size::Large => "10px 16px",
+        _ => "6px 12px",
+    };
+    let height = match (variant, size) {
+        (Variant::Tabs, _) => "40px",
+        (Variant::Ios, _) => "56px",
+        (_, Size::Large) => "60px",
+        (_, Size::Small) => "38px",
+        _ => "48px",
+    };
+    let border_radius = match variant {
+        Variant::Tabs => "6px",
+        Variant::Default => "8px 8px 0 0",
+        _ => "0",
+    };
+    let border = if *variant == Variant::Tabs {
+        "1px solid #d1d5db"
+    } else {
+        "none"
+    };
+    let box_shadow = if *variant == Variant::Default {
+        "0 2px 6px rgba(0,0,0,0.1)"
+    } else {
+        "none"
+    };
+    let justify = if *variant == Variant::Ios {
+        "space-between"
+    } else {
+        "flex-start"
+    };
+
+    format!(
+        "{base} justify-content: {justify}; padding: {padding}; height: {height}; border-radius: {border_radius}; border: {border}; box-shadow: {box_shadow};"
+    )
+}
+
+/// Builds the inline style of the address bar wrapper, leaving room for the
+/// window controls when they are shown.
+pub fn address_wrapper_style(base: &str, show_controls: bool) -> String {
+    format!(
+        "{} padding-left: {};",
+        base,
+        if show_controls { "8px" } else { "0" }
+    )
+}
+
+/// Builds the inline style of the outer frame from its base style, size and
+/// user style.
+pub fn frame_style(base: &str, size: &Size, style: &str) -> String {
+    format!("{} {} {}", base, size.to_style(), style)
+}
+
+/// Builds the inline style of the address bar container, ringed while its
+/// input has focus.
+pub fn address_bar_style(base: &str, focused: bool) -> String {
+    if focused {
+        format!("{base} {ADDRESS_BAR_FOCUS_STYLE}")
+    } else {
+        base.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn header_style_follows_variant_and_size() {
+        let style = header_style(HEADER_BASE_STYLE, &Variant::Default, &Size::Medium);
+        assert!(style.starts_with(HEADER_BASE_STYLE));
+        assert!(style.contains("height: 48px;"));
+        assert!(style.contains("border-radius: 8px 8px 0 0;"));
+        assert!(style.contains("box-shadow: 0 2px 6px rgba(0,0,0,0.1);"));
+
+        let tabs = header_style("", &Variant::Tabs, &Size::Large);
+        assert!(tabs.contains("height: 40px;"));
+        assert!(tabs.contains("padding: 10px 16px;"));
+        assert!(tabs.contains("border: 1px solid #d1d5db;"));
+
+        let ios = header_style("", &Variant::Ios, &Size::Small);
+        assert!(ios.contains("justify-content: space-between;"));
+        assert!(ios.contains("height: 56px;"));
+        assert!(ios.contains("border-radius: 0;"));
+    }
+
+    #[test]
+    fn address_wrapper_pads_for_controls() {
+        assert!(address_wrapper_style("a;", true).ends_with("padding-left: 8px;"));
+        assert!(address_wrapper_style("a;", false).ends_with("padding-left: 0;"));
+    }
+
+    #[test]
+    fn frame_style_prepends_size() {
+        assert_eq!(
+            frame_style("overflow: hidden;", &Size::Full, "color: red;"),
+            "overflow: hidden; width: 100%; color: red;"
+        );
+        assert!(frame_style(FRAME_BASE_STYLE, &Size::Medium, "").contains("light-dark("));
+    }
+}
@@ -0,0 +1,35 @@
+//! Optional Tailwind CSS preset.
+//!
+//! The components style themselves without Tailwind. Projects that already
+//! ship Tailwind can pass these classes instead, clearing the matching inline
+//! defaults so the classes take effect:
+//!
+//! ```rust,ignore
+//! <BrowserFrame
+//!     class={tailwind::FRAME_CLASS}
+//!     base_style=""
+//!     input_class={tailwind::ADDRESS_INPUT_CLASS}
+//!     close_class={tailwind::control_class(&ButtonType::Close)}
+//! />
+//! ```
+
+use super::ButtonType;
+
+/// Classes of the outer frame.
+pub const FRAME_CLASS: &str = "rounded-lg border shadow-lg overflow-hidden bg-white dark:bg-gray-800 border-gray-200 dark:border-gray-700";
+
+/// Classes of the address bar input.
+pub const ADDRESS_INPUT_CLASS: &str = "text-black dark:text-white";
+
+/// Classes of a window control button, with hover and focus states.
+pub fn control_class(button: &ButtonType) -> &'static str {
+    match button {
+        ButtonType::Close => "rounded-full hover:brightness-90 focus:ring-2 focus:ring-red-300",
+        ButtonType::Minimize => {
+            "rounded-full hover:brightness-90 focus:ring-2 focus:ring-yellow-300"
+        }
+        ButtonType::Maximize => {
+            "rounded-full hover:brightness-90 focus:ring-2 focus:ring-green-300"
+        }
+    }
+}
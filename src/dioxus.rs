@@ -1,15 +1,39 @@
 #![doc = include_str!("../DIOXUS.md")]
 
-use crate::common::{ButtonType, Size, Variant};
+use crate::common::{
+    ADDRESS_BAR_LABEL, Bookmark, ButtonType, ControlState, DisplayUrl, DomError, LoadState,
+    MenuItem, REFRESH_LABEL, ShareTarget, Size, Suggestion, SuggestionProvider, ToolbarPlacement,
+    Variant,
+    animation::{Animation, AnimationPhase, AnimationState},
+    announcer::{Announcement, AnnouncerMessages, Politeness},
+    bookmarks::{self, BookmarkMenu, MORE_BOOKMARKS_LABEL},
+    css::{self, StyleMode, frame_class},
+    describedby_ids, element_id,
+    find::{FindState, highlight_css},
+    highlight_segments, icons, inline_completion, is_default_zoom,
+    keyboard::{
+        AddressBarCommand, FindCommand, FrameCommand, KeyInput, NavigationCommand,
+        address_bar_command, find_command, frame_command, navigation_command,
+    },
+    layout_toolbar,
+    menu::{MenuAction, MenuState, TYPEAHEAD_TIMEOUT, menu_item_id, submenu_id},
+    part_id,
+    qr::QrCode,
+    share::ShareState,
+    share_mailto,
+    style::{self, address_bar_style, address_wrapper_style, frame_style, header_style},
+    zoom_label, zoom_step,
+};
+use crate::dom::{self, page};
 use dioxus::prelude::*;
-use gloo_timers::callback::Timeout;
-use std::rc::Rc;
+use std::{cell::RefCell, rc::Rc};
 use web_sys::{
-    HtmlInputElement,
+    Range, ResizeObserver,
     wasm_bindgen::{JsCast, prelude::*},
-    window,
 };
 
+mod platform;
+
 #[derive(PartialEq, Props, Clone)]
 pub struct BrowserContentProps {
     #[props(default)]
@@ -20,20 +44,32 @@ pub struct BrowserContentProps {
     pub aria_label: &'static str,
     #[props(default)]
     pub aria_describedby: &'static str,
+    #[props(default)]
+    pub onmounted: EventHandler<MountedEvent>,
+    #[props(default = 1.0)]
+    pub zoom: f64,
+    #[props(default)]
+    pub style_mode: StyleMode,
     children: Element,
 }
 
 #[component]
 pub fn BrowserContent(props: BrowserContentProps) -> Element {
+    let mode = props.style_mode;
     rsx! {
         main {
-            class: "{props.class}",
-            style: "{props.style}",
+            class: "{mode.class(css::CONTENT, props.class)}",
+            style: "{mode.inline(props.style)}",
             role: "main",
             aria_label: "{props.aria_label}",
             aria_describedby: "{props.aria_describedby}",
             tabindex: "-1",
-            {props.children}
+            onmounted: move |cx| props.onmounted.call(cx),
+            div {
+                class: mode.name(css::zoom_layer_class(props.zoom)),
+                style: css::zoom_layer_style(props.zoom, mode),
+                {props.children}
+            }
         }
     }
 }
@@ -50,119 +86,444 @@ pub struct AddressBarProps {
     pub read_only: bool,
     #[props(default)]
     pub class: &'static str,
-    #[props(
-        default = "flex: 1; margin-left: 1rem; margin-right: 1rem; border: 1px solid #d1d5db; border-radius: 0.375rem; padding-left: 0.75rem; padding-right: 0.75rem; font-size: 0.875rem; position: relative;"
-    )]
+    #[props(default = style::ADDRESS_BAR_STYLE)]
     pub style: &'static str,
-    #[props(default = "Website address or search query")]
+    #[props(default = ADDRESS_BAR_LABEL)]
     pub label: &'static str,
+    /// Hint read after the label, rendered in a visually hidden element.
     #[props(default = "Enter a website URL or search term. Press Enter to navigate.")]
+    pub description: &'static str,
+    /// Ids of further elements describing the input, space separated.
+    #[props(default)]
     pub describedby: &'static str,
-    #[props(default = "browser-url-input")]
-    pub input_id: &'static str,
-    #[props(default = "text-black dark:text-white")]
+    /// Id of the input; generated per instance when empty.
+    #[props(default)]
+    pub input_id: String,
+    #[props(default)]
     pub input_class: &'static str,
     #[props(default)]
     pub container_class: &'static str,
-    #[props(
-        default = "position: absolute; top: 50%; right: 8px; transform: translateY(-50%); padding: 4px; background: none; border: none; box-shadow: none; outline: none; cursor: pointer;"
-    )]
+    #[props(default = style::REFRESH_BUTTON_STYLE)]
     pub refresh_button_style: &'static str,
-    #[props(default = "Refresh")]
+    #[props(default = REFRESH_LABEL)]
     pub refresh_button_aria_label: &'static str,
-    #[props(
-        default = "background-color: transparent; padding-right: 2rem; border: none; outline: none; box-shadow: none; height: 100%;"
-    )]
+    #[props(default)]
+    pub load_state: LoadState,
+    #[props(default)]
+    pub on_refresh: Option<EventHandler<()>>,
+    #[props(default)]
+    pub on_stop: EventHandler<()>,
+    #[props(default = "Stop loading")]
+    pub stop_button_aria_label: &'static str,
+    #[props(default = 1.0)]
+    pub zoom: f64,
+    #[props(default)]
+    pub on_zoom_reset: EventHandler<()>,
+    #[props(default = style::ZOOM_BUTTON_STYLE)]
+    pub zoom_button_style: &'static str,
+    #[props(default = style::ADDRESS_INPUT_STYLE)]
     pub input_style: &'static str,
+    #[props(default)]
+    pub suggestions: SuggestionProvider,
+    #[props(default = 8)]
+    pub max_suggestions: usize,
+    #[props(default)]
+    pub on_suggestion_select: EventHandler<Suggestion>,
+    #[props(default)]
+    pub on_submit: EventHandler<String>,
+    #[props(default)]
+    pub listbox_class: &'static str,
+    #[props(default = style::SUGGESTION_LISTBOX_STYLE)]
+    pub listbox_style: &'static str,
+    #[props(default)]
+    pub option_class: &'static str,
+    #[props(default = style::SUGGESTION_OPTION_STYLE)]
+    pub option_style: &'static str,
+    #[props(default = style::ACTIVE_ITEM_STYLE)]
+    pub option_active_style: &'static str,
+    #[props(default = style::SUGGESTION_MATCH_STYLE)]
+    pub match_style: &'static str,
+    #[props(default = true)]
+    pub smart_display: bool,
+    #[props(default = style::URL_DISPLAY_STYLE)]
+    pub display_style: &'static str,
+    #[props(default = style::URL_DOMAIN_STYLE)]
+    pub domain_style: &'static str,
+    #[props(default = style::URL_PATH_STYLE)]
+    pub path_style: &'static str,
+    #[props(default)]
+    pub on_error: EventHandler<DomError>,
+    #[props(default)]
+    pub style_mode: StyleMode,
 }
 
 #[component]
 pub fn AddressBar(props: AddressBarProps) -> Element {
+    let announcer = use_announcer();
+    let input_id = use_element_id(&props.input_id, "browser-url-input");
     let mut input_value = use_signal(|| props.url.clone());
     let mut is_focused = use_signal(|| false);
     let mut input_ref: Signal<Option<Rc<MountedData>>> = use_signal(|| None);
 
+    let mut query = use_signal(String::new);
+    let mut suggestions = use_signal(Vec::<Suggestion>::new);
+    let mut active_index = use_signal(|| None::<usize>);
+    let mut is_open = use_signal(|| false);
+
     use_effect(move || {
         input_value.set(props.url.clone());
     });
 
+    let listbox_id = format!("{input_id}-listbox");
+    let description_id = format!("{input_id}-description");
+    let option_id = move |index: usize| format!("{}-option-{}", input_id.read(), index);
+
+    // Renders a batch of suggestions and, while the user is typing forward,
+    // completes the input inline with the best match selected.
+    let mut show_suggestions = move |items: Vec<Suggestion>, typed: String, grew: bool| {
+        if grew {
+            if let Some(completed) = items
+                .first()
+                .and_then(|first| inline_completion(&typed, &first.url))
+            {
+                platform::complete_input(
+                    &input_ref.peek(),
+                    &input_id.peek(),
+                    &completed,
+                    typed.encode_utf16().count(),
+                    completed.encode_utf16().count(),
+                );
+                input_value.set(completed);
+            }
+        }
+        is_open.set(!items.is_empty());
+        suggestions.set(items);
+    };
+
+    let provider = props.suggestions.clone();
+    let max_suggestions = props.max_suggestions;
     let on_input_change = move |evt: FormEvent| {
-        input_value.set(evt.value());
+        let value = evt.value();
+        let grew = value.len() > query.peek().len();
+        input_value.set(value.clone());
+        query.set(value.clone());
+        active_index.set(None);
         props.on_url_change.call(evt);
+
+        match provider.filter(&value, max_suggestions) {
+            Some(items) => show_suggestions(items, value, grew),
+            None => {
+                if let Some(future) = provider.fetch(value.clone()) {
+                    spawn(async move {
+                        let mut items = future.await;
+                        if *query.peek() == value {
+                            items.truncate(max_suggestions);
+                            show_suggestions(items, value, grew);
+                        }
+                    });
+                }
+            }
+        }
+    };
+
+    let mut select_suggestion = move |suggestion: Suggestion| {
+        let url = suggestion.url.clone();
+        input_value.set(url.clone());
+        query.set(url.clone());
+        is_open.set(false);
+        active_index.set(None);
+        props.on_suggestion_select.call(suggestion);
+        props.on_submit.call(url);
     };
-    let on_key_down = move |evt: Event<KeyboardData>| {
-        if evt.key() == Key::Enter {
-            evt.prevent_default();
 
-            if let Some(node) = &*input_ref.read() {
-                if let Some(input) = node.downcast::<HtmlInputElement>() {
-                    let _ = input.blur();
+    let on_key_down = move |evt: Event<KeyboardData>| {
+        let count = suggestions.peek().len();
+        let expanded = *is_open.peek() && count > 0;
+        let key = evt.key().to_string();
+        let Some(command) =
+            address_bar_command(&KeyInput::new(&key), *active_index.peek(), count, expanded)
+        else {
+            return;
+        };
+        evt.prevent_default();
+        match command {
+            AddressBarCommand::Highlight(next) => {
+                active_index.set(Some(next));
+                let url = suggestions.peek()[next].url.clone();
+                input_value.set(url);
+            }
+            AddressBarCommand::Open => is_open.set(true),
+            AddressBarCommand::Dismiss => {
+                evt.stop_propagation();
+                if let Some(native) = evt.data().downcast::<web_sys::KeyboardEvent>() {
+                    native.stop_propagation();
                 }
+                is_open.set(false);
+                active_index.set(None);
+                let typed = query.peek().clone();
+                input_value.set(typed);
             }
+            AddressBarCommand::Submit(active) => {
+                let selected = active.map(|index| suggestions.peek()[index].clone());
+                let value = match selected {
+                    Some(suggestion) => {
+                        let url = suggestion.url.clone();
+                        select_suggestion(suggestion);
+                        url
+                    }
+                    None => {
+                        is_open.set(false);
+                        let value = input_value.peek().clone();
+                        props.on_submit.call(value.clone());
+                        value
+                    }
+                };
 
-            if let Some(document) = window().and_then(|w| w.document()) {
-                let live_region = document.create_element("div").unwrap();
-                live_region.set_attribute("aria-live", "polite").unwrap();
-                live_region.set_attribute("aria-atomic", "true").unwrap();
-                live_region.set_class_name("sr-only");
-                live_region.set_attribute("style", "position: absolute; width: 1px; height: 1px; padding: 0; margin: -1px; overflow: hidden; clip: rect(0, 0, 0, 0); white-space: nowrap; border: 0;").unwrap();
-                live_region
-                    .set_text_content(Some(&format!("Navigating to {}", input_value.read())));
-                document.body().unwrap().append_child(&live_region).unwrap();
+                if let Some(input) = input_ref.peek().clone() {
+                    spawn(async move {
+                        let _ = input.set_focus(false).await;
+                    });
+                }
 
-                let clone = live_region.clone();
-                Timeout::new(1000, move || {
-                    let _ = document.body().unwrap().remove_child(&clone);
-                })
-                .forget();
+                if let Err(error) = announcer.announce(&Announcement::Navigation(value)) {
+                    props.on_error.call(error);
+                }
             }
         }
     };
 
+    let mut select_on_mouse_up = use_signal(|| false);
+
+    let on_focus = move |_| {
+        is_focused.set(true);
+        platform::select_input(&input_ref.peek(), &input_id.peek());
+        select_on_mouse_up.set(true);
+    };
+
+    // The click that focuses the input would otherwise collapse the selection
+    // made in `on_focus`.
+    let on_mouse_up = move |evt: MouseEvent| {
+        if *select_on_mouse_up.peek() {
+            select_on_mouse_up.set(false);
+            evt.prevent_default();
+        }
+    };
+
+    let display_url = if props.smart_display && !is_focused() {
+        DisplayUrl::parse(&input_value.read())
+    } else {
+        None
+    };
+    let zoomed = !is_default_zoom(props.zoom);
+    let mut input_style = if display_url.is_some() {
+        format!("{} color: transparent;", props.input_style)
+    } else {
+        props.input_style.to_string()
+    };
+    let mut display_style = props.display_style.to_string();
+    if zoomed {
+        input_style.push_str(" padding-right: 5rem;");
+        display_style.push_str(" right: 5rem;");
+    }
+    let mode = props.style_mode;
+    let bar_class = if zoomed {
+        css::ADDRESS_BAR_ZOOMED
+    } else {
+        css::ADDRESS_BAR
+    };
+    let input_class = if display_url.is_some() {
+        css::ADDRESS_INPUT_MASKED
+    } else {
+        css::ADDRESS_INPUT
+    };
+    let container_class = mode.class(
+        bar_class,
+        &format!("{} {}", props.container_class, props.class),
+    );
+
+    let expanded = is_open() && !suggestions.read().is_empty();
+    let active_descendant = active_index()
+        .filter(|_| expanded)
+        .map(option_id)
+        .unwrap_or_default();
+    let typed = query();
+    let active_option_style = format!("{} {}", props.option_style, props.option_active_style);
+    let suggestion_title_style = mode.inline("color: #6b7280; font-size: 0.75rem;");
+
     rsx! {
         div {
-            class: "{props.container_class} {props.class}",
-            style: "{props.style}",
+            class: "{container_class}",
+            style: "{mode.inline(&address_bar_style(props.style, is_focused()))}",
             label {
-                r#for: "{props.input_id}",
-                class: "sr-only",
-                style: "position: absolute; width: 1px; height: 1px; padding: 0; margin: -1px; overflow: hidden; clip: rect(0, 0, 0, 0); white-space: nowrap; border: 0;",
+                r#for: "{input_id}",
+                class: mode.name(css::VISUALLY_HIDDEN),
+                style: mode.inline(style::VISUALLY_HIDDEN),
                 "{props.label}"
             }
+            if !props.description.is_empty() {
+                span {
+                    id: "{description_id}",
+                    class: mode.name(css::VISUALLY_HIDDEN),
+                    style: mode.inline(style::VISUALLY_HIDDEN),
+                    "{props.description}"
+                }
+            }
             input {
-                id: "{props.input_id}",
+                id: "{input_id}",
                 r#type: "text",
-                class: "{props.input_class}",
-                style: "{props.input_style}",
+                role: "combobox",
+                class: "{mode.class(input_class, props.input_class)}",
+                style: "{mode.inline(&input_style)}",
                 value: "{input_value}",
                 oninput: on_input_change,
                 onkeydown: on_key_down,
-                onfocus: move |_| is_focused.set(true),
-                onblur: move |_| is_focused.set(false),
+                onfocus: on_focus,
+                onmouseup: on_mouse_up,
+                onblur: move |_| {
+                    is_focused.set(false);
+                    is_open.set(false);
+                    active_index.set(None);
+                },
                 placeholder: "{props.placeholder}",
                 readonly: props.read_only,
-                aria_describedby: "{props.describedby}",
+                aria_describedby: describedby_ids([
+                    if props.description.is_empty() { "" } else { description_id.as_str() },
+                    props.describedby,
+                ]),
+                aria_autocomplete: if !props.suggestions.is_none() { "both" },
+                aria_expanded: if expanded { "true" } else { "false" },
+                aria_controls: "{listbox_id}",
+                aria_activedescendant: if !active_descendant.is_empty() { "{active_descendant}" },
                 autocomplete: "url",
                 spellcheck: "false",
                 onmounted: move |cx| input_ref.set(Some(cx.data())),
             }
-            button {
-                style: "{props.refresh_button_style}",
-                aria_label: "{props.refresh_button_aria_label}",
-                onclick: move |_| {
-                    let _ = window().unwrap().location().reload();
-                },
-                svg {
-                    width: "11",
-                    height: "13",
-                    view_box: "0 0 11 13",
-                    fill: "none",
-                    xmlns: "http://www.w3.org/2000/svg",
-                    path {
-                        d: "M4.99385 1.00002L7.33006 3.33623L4.99385 5.67244M10 7.61925C10 10.1998 7.9081 12.2917 5.3276 12.2917C2.74709 12.2917 0.655182 10.1998 0.655182 7.61925C0.655182 5.03875 2.74709 2.94684 5.3276 2.94684C5.8737 2.94684 6.4957 2.94684 7.27443 3.33621",
-                        stroke: "#767676",
-                        stroke_linecap: "round",
-                        stroke_linejoin: "round"
+            if let Some(display_url) = display_url {
+                div {
+                    aria_hidden: "true",
+                    class: "{mode.class(css::URL, props.input_class)}",
+                    style: "{mode.inline(&display_style)}",
+                    span { "{display_url.subdomain}" }
+                    span {
+                        class: "{mode.name(css::URL_DOMAIN)}",
+                        style: "{mode.inline(props.domain_style)}",
+                        "{display_url.domain}"
+                    }
+                    span {
+                        class: "{mode.name(css::URL_PATH)}",
+                        style: "{mode.inline(props.path_style)}",
+                        "{display_url.rest}"
+                    }
+                }
+            }
+            if zoomed {
+                button {
+                    r#type: "button",
+                    class: "{mode.name(css::ZOOM)}",
+                    style: "{mode.inline(props.zoom_button_style)}",
+                    title: "Reset zoom",
+                    "aria-label": "Zoom {zoom_label(props.zoom)}, reset to 100%",
+                    onclick: move |_| props.on_zoom_reset.call(()),
+                    "{zoom_label(props.zoom)}"
+                }
+            }
+            if props.load_state.is_loading() {
+                button {
+                    class: "{mode.name(css::REFRESH)}",
+                    style: "{mode.inline(props.refresh_button_style)}",
+                    aria_label: "{props.stop_button_aria_label}",
+                    onclick: move |_| props.on_stop.call(()),
+                    svg {
+                        width: "11",
+                        height: "13",
+                        view_box: "0 0 11 13",
+                        fill: "none",
+                        xmlns: "http://www.w3.org/2000/svg",
+                        path {
+                            d: icons::STOP,
+                            stroke: "#767676",
+                            stroke_linecap: "round",
+                            stroke_linejoin: "round"
+                        }
+                    }
+                }
+            } else {
+                button {
+                    class: "{mode.name(css::REFRESH)}",
+                    style: "{mode.inline(props.refresh_button_style)}",
+                    aria_label: "{props.refresh_button_aria_label}",
+                    onclick: move |_| match props.on_refresh {
+                        Some(on_refresh) => on_refresh.call(()),
+                        None => {
+                            if let Err(error) = platform::reload() {
+                                props.on_error.call(error);
+                            }
+                        }
+                    },
+                    svg {
+                        width: "11",
+                        height: "13",
+                        view_box: "0 0 11 13",
+                        fill: "none",
+                        xmlns: "http://www.w3.org/2000/svg",
+                        path {
+                            d: icons::REFRESH,
+                            stroke: "#767676",
+                            stroke_linecap: "round",
+                            stroke_linejoin: "round"
+                        }
+                    }
+                }
+            }
+            if expanded {
+                ul {
+                    id: "{listbox_id}",
+                    role: "listbox",
+                    aria_label: "{props.label}",
+                    class: "{mode.class(css::SUGGESTIONS, props.listbox_class)}",
+                    style: "{mode.inline(props.listbox_style)}",
+                    for (index, suggestion) in suggestions.read().iter().cloned().enumerate() {
+                        li {
+                            key: "{index}",
+                            id: "{option_id(index)}",
+                            role: "option",
+                            aria_selected: if active_index() == Some(index) { "true" } else { "false" },
+                            class: if active_index() == Some(index) {
+                                "{mode.class(css::SUGGESTION_ACTIVE, props.option_class)}"
+                            } else {
+                                "{mode.class(css::SUGGESTION, props.option_class)}"
+                            },
+                            style: if active_index() == Some(index) {
+                                "{mode.inline(&active_option_style)}"
+                            } else {
+                                "{mode.inline(props.option_style)}"
+                            },
+                            onmousedown: move |evt| evt.prevent_default(),
+                            onclick: {
+                                let suggestion = suggestion.clone();
+                                move |_| select_suggestion(suggestion.clone())
+                            },
+                            span {
+                                for (text, is_match) in highlight_segments(&suggestion.url, &typed) {
+                                    if is_match {
+                                        mark {
+                                            class: "{mode.name(css::SUGGESTION_MATCH)}",
+                                            style: "{mode.inline(props.match_style)}",
+                                            "{text}"
+                                        }
+                                    } else {
+                                        "{text}"
+                                    }
+                                }
+                            }
+                            if !suggestion.title.is_empty() {
+                                span {
+                                    class: "{mode.name(css::SUGGESTION_TITLE)}",
+                                    style: "{suggestion_title_style}",
+                                    "{suggestion.title}"
+                                }
+                            }
+                        }
                     }
                 }
             }
@@ -183,9 +544,7 @@ pub struct ControlButtonProps {
     pub on_focus: EventHandler<FocusEvent>,
     #[props(default)]
     pub on_blur: EventHandler<FocusEvent>,
-    #[props(
-        default = "width: 1rem; height: 1rem; display: flex; align-items: center; justify-content: center; transition: all 0.2s ease; cursor: pointer; background: none; border: none; padding: 0; margin-right: 0.5rem;"
-    )]
+    #[props(default = style::CONTROL_BUTTON_STYLE)]
     pub style: &'static str,
     #[props(default)]
     pub class: &'static str,
@@ -201,15 +560,27 @@ pub struct ControlButtonProps {
     pub title: &'static str,
     #[props(default = "0")]
     pub tabindex: &'static str,
+    /// Reveals the ×, − or ⤢ glyph, as when the pointer enters the controls.
+    #[props(default)]
+    pub show_glyph: bool,
+    /// Draws the control grey, as in an inactive window.
+    #[props(default)]
+    pub inactive: bool,
+    #[props(default)]
+    pub style_mode: StyleMode,
 }
 
 #[component]
 pub fn ControlButton(props: ControlButtonProps) -> Element {
-    let (fill, stroke) = match props.r#type {
-        ButtonType::Close => ("#FF5F57", "#E14640"),
-        ButtonType::Minimize => ("#FFBD2E", "#DFA123"),
-        ButtonType::Maximize => ("#28CA42", "#1DAD2C"),
-    };
+    let mut hovered = use_signal(|| false);
+    let forced_colors = use_forced_colors();
+    let colors = props.r#type.control_colors(ControlState {
+        hovered: hovered(),
+        show_glyph: props.show_glyph,
+        inactive: props.inactive,
+        forced_colors: forced_colors(),
+    });
+    let mode = props.style_mode;
 
     let aria_label = if props.aria_label.is_empty() {
         props.r#type.default_aria_label()
@@ -226,14 +597,20 @@ pub fn ControlButton(props: ControlButtonProps) -> Element {
     rsx! {
         button {
             r#type: "{props.button_type}",
-            class: "{props.class}",
-            style: "{props.style}",
+            class: "{mode.class(css::control_class(&props.r#type), props.class)}",
+            style: "{mode.inline(props.style)}",
             aria_label: "{aria_label}",
             title: "{title}",
             tabindex: "{props.tabindex}",
             onclick: move |_| props.on_click.call(()),
-            onmouseover: move |_| props.on_mouse_over.call(()),
-            onmouseout: move |_| props.on_mouse_out.call(()),
+            onmouseover: move |_| {
+                hovered.set(true);
+                props.on_mouse_over.call(());
+            },
+            onmouseout: move |_| {
+                hovered.set(false);
+                props.on_mouse_out.call(());
+            },
             onfocus: props.on_focus,
             onblur: props.on_blur,
 
@@ -246,9 +623,18 @@ pub fn ControlButton(props: ControlButtonProps) -> Element {
                 xmlns: "http://www.w3.org/2000/svg",
                 path {
                     class: "{props.path_class}",
-                    d: "M6 0.5C9.03757 0.5 11.5 2.96243 11.5 6C11.5 9.03757 9.03757 11.5 6 11.5C2.96243 11.5 0.5 9.03757 0.5 6C0.5 2.96243 2.96243 0.5 6 0.5Z",
-                    fill: "{fill}",
-                    stroke: "{stroke}"
+                    d: icons::CONTROL_BUTTON,
+                    fill: "{colors.fill}",
+                    stroke: "{colors.stroke}"
+                }
+                if let Some(glyph) = colors.glyph {
+                    path {
+                        class: mode.name(css::CONTROL_GLYPH),
+                        d: props.r#type.glyph(forced_colors()),
+                        stroke: glyph,
+                        stroke_width: "1.25",
+                        stroke_linecap: "round",
+                    }
                 }
             }
         }
@@ -260,8 +646,10 @@ pub struct BrowserControlsProps {
     #[props(default)]
     pub show_controls: bool,
     #[props(default)]
+    pub on_error: EventHandler<DomError>,
+    #[props(default)]
     pub class: &'static str,
-    #[props(default = "display: flex; align-items: center; background: none; padding-left: 10px;")]
+    #[props(default = style::CONTROLS_STYLE)]
     pub style: &'static str,
 
     #[props(default)]
@@ -338,20 +726,48 @@ pub struct BrowserControlsProps {
     pub maximize_title: &'static str,
     #[props(default = "0")]
     pub maximize_tabindex: &'static str,
+    /// Draws the controls grey until the pointer or focus enters them, as in
+    /// an inactive window.
+    #[props(default)]
+    pub inactive: bool,
+    #[props(default)]
+    pub style_mode: StyleMode,
 }
 
 #[component]
 pub fn BrowserControls(props: BrowserControlsProps) -> Element {
+    let roving = use_roving_tabindex(props.on_error);
+    let mut hovered = use_signal(|| false);
+    let mut focused = use_signal(|| false);
+
     if !props.show_controls {
         return rsx! {};
     }
 
+    let show_glyph = hovered() || focused();
+    let class = if props.inactive {
+        css::CONTROLS_INACTIVE
+    } else {
+        css::CONTROLS
+    };
+
     rsx! {
         nav {
-            class: "{props.class}",
-            style: "{props.style}",
+            class: "{props.style_mode.class(class, props.class)}",
+            style: "{props.style_mode.inline(props.style)}",
             role: "toolbar",
             aria_label: "Browser window controls",
+            onmounted: move |e| roving.mounted(e),
+            onkeydown: move |e| roving.keydown(e),
+            onfocusin: move |e| {
+                focused.set(true);
+                roving.focusin(e);
+            },
+            // Moving focus between the buttons fires `focusout` right before
+            // `focusin`, so the glyphs stay while focus remains in the group.
+            onfocusout: move |_| focused.set(false),
+            onmouseenter: move |_| hovered.set(true),
+            onmouseleave: move |_| hovered.set(false),
             ControlButton {
                 r#type: ButtonType::Close,
                 on_click: props.on_close,
@@ -362,10 +778,13 @@ pub fn BrowserControls(props: BrowserControlsProps) -> Element {
                 class: props.close_class,
                 svg_class: props.close_svg_class,
                 path_class: props.close_path_class,
+                style_mode: props.style_mode,
                 button_type: props.close_button_type,
                 aria_label: props.close_aria_label,
                 title: props.close_title,
                 tabindex: props.close_tabindex,
+                show_glyph,
+                inactive: props.inactive,
             }
             ControlButton {
                 r#type: ButtonType::Minimize,
@@ -377,10 +796,13 @@ pub fn BrowserControls(props: BrowserControlsProps) -> Element {
                 class: props.minimize_class,
                 svg_class: props.minimize_svg_class,
                 path_class: props.minimize_path_class,
+                style_mode: props.style_mode,
                 button_type: props.minimize_button_type,
                 aria_label: props.minimize_aria_label,
                 title: props.minimize_title,
                 tabindex: props.minimize_tabindex,
+                show_glyph,
+                inactive: props.inactive,
             }
             ControlButton {
                 r#type: ButtonType::Maximize,
@@ -392,158 +814,994 @@ pub fn BrowserControls(props: BrowserControlsProps) -> Element {
                 class: props.maximize_class,
                 svg_class: props.maximize_svg_class,
                 path_class: props.maximize_path_class,
+                style_mode: props.style_mode,
                 button_type: props.maximize_button_type,
                 aria_label: props.maximize_aria_label,
                 title: props.maximize_title,
                 tabindex: props.maximize_tabindex,
+                show_glyph,
+                inactive: props.inactive,
             }
         }
     }
 }
 
 #[derive(PartialEq, Props, Clone)]
-pub struct BrowserHeaderProps {
-    #[props(default)]
-    pub url: String,
+pub struct TitleBarProps {
     #[props(default)]
-    pub placeholder: &'static str,
+    pub title: String,
     #[props(default)]
-    pub on_url_change: Option<EventHandler<FormEvent>>,
-    #[props(default = true)]
-    pub show_controls: bool,
-    #[props(default = true)]
-    pub show_address_bar: bool,
-    #[props(default = false)]
-    pub read_only: bool,
+    pub favicon: String,
     #[props(default)]
     pub variant: Variant,
-    #[props(default)]
-    pub size: Size,
-    #[props(default)]
-    pub custom_buttons: Vec<Element>,
+    #[props(default = "New Tab")]
+    pub fallback_title: &'static str,
     #[props(default)]
     pub class: &'static str,
-
-    #[props(default)]
-    pub container_class: &'static str,
-    #[props(default = "text-black dark:text-white")]
-    pub input_class: &'static str,
+    #[props(default = style::TITLE_BAR_STYLE)]
+    pub style: &'static str,
+    #[props(default = style::TAB_LIST_STYLE)]
+    pub tablist_style: &'static str,
+    #[props(default = style::TAB_STYLE)]
+    pub tab_style: &'static str,
     #[props(default)]
-    pub refresh_button_style: &'static str,
-    #[props(default = "Refresh")]
-    pub refresh_button_aria_label: &'static str,
+    pub style_mode: StyleMode,
+}
 
-    #[props(
-        default = "padding: 4px; cursor: pointer; background: none; border: none; box-shadow: none; outline: none;"
-    )]
-    pub icon_button_style: &'static str,
+#[component]
+pub fn TitleBar(props: TitleBarProps) -> Element {
+    let mut favicon_failed = use_signal(|| false);
 
-    #[props(default = "flex: 1; display: flex; justify-content: center; padding-right: 8px;")]
-    pub address_wrapper_base_style: &'static str,
+    use_effect(use_reactive((&props.favicon,), move |_| {
+        favicon_failed.set(false);
+    }));
 
-    #[props(default = "display: flex; align-items: center; position: relative;")]
-    pub header_base_style: &'static str,
+    let title = if props.title.is_empty() {
+        props.fallback_title.to_string()
+    } else {
+        props.title.clone()
+    };
 
-    #[props(default)]
-    pub on_close: EventHandler<()>,
-    #[props(default)]
-    pub on_close_mouse_over: EventHandler<()>,
-    #[props(default)]
-    pub on_close_mouse_out: EventHandler<()>,
-    #[props(default)]
-    pub on_close_focus: EventHandler<FocusEvent>,
-    #[props(default)]
-    pub on_close_blur: EventHandler<FocusEvent>,
-    #[props(default)]
-    pub close_class: &'static str,
-    #[props(default)]
-    pub close_svg_class: &'static str,
-    #[props(default)]
-    pub close_path_class: &'static str,
-    #[props(default = "button")]
-    pub close_button_type: &'static str,
-    #[props(default)]
-    pub close_aria_label: &'static str,
-    #[props(default)]
-    pub close_title: &'static str,
-    #[props(default = "0")]
-    pub close_tabindex: &'static str,
+    let mode = props.style_mode;
+    let icon = if props.favicon.is_empty() || favicon_failed() {
+        rsx! {
+            svg {
+                width: "16",
+                height: "16",
+                view_box: "0 0 16 16",
+                fill: "none",
+                xmlns: "http://www.w3.org/2000/svg",
+                "aria-hidden": "true",
+                class: mode.name(css::FAVICON),
+                style: mode.inline("flex-shrink: 0;"),
+                circle { cx: "8", cy: "8", r: "6.5", stroke: "#767676" }
+                path {
+                    d: "M1.5 8H14.5M8 1.5C9.7 3.3 10.5 5.5 10.5 8C10.5 10.5 9.7 12.7 8 14.5C6.3 12.7 5.5 10.5 5.5 8C5.5 5.5 6.3 3.3 8 1.5Z",
+                    stroke: "#767676",
+                }
+            }
+        }
+    } else {
+        rsx! {
+            img {
+                src: "{props.favicon}",
+                alt: "",
+                width: "16",
+                height: "16",
+                class: mode.name(css::FAVICON),
+                style: mode.inline("flex-shrink: 0;"),
+                onerror: move |_| favicon_failed.set(true),
+            }
+        }
+    };
 
-    #[props(default)]
-    pub on_minimize: EventHandler<()>,
-    #[props(default)]
-    pub on_minimize_mouse_over: EventHandler<()>,
-    #[props(default)]
-    pub on_minimize_mouse_out: EventHandler<()>,
-    #[props(default)]
-    pub on_minimize_focus: EventHandler<FocusEvent>,
-    #[props(default)]
-    pub on_minimize_blur: EventHandler<FocusEvent>,
-    #[props(default)]
-    pub minimize_class: &'static str,
-    #[props(default)]
-    pub minimize_svg_class: &'static str,
-    #[props(default)]
-    pub minimize_path_class: &'static str,
-    #[props(default = "button")]
-    pub minimize_button_type: &'static str,
-    #[props(default)]
-    pub minimize_aria_label: &'static str,
-    #[props(default)]
-    pub minimize_title: &'static str,
-    #[props(default = "0")]
-    pub minimize_tabindex: &'static str,
+    let label = rsx! {
+        span {
+            class: mode.name(css::TITLE),
+            style: mode.inline("overflow: hidden; text-overflow: ellipsis; white-space: nowrap;"),
+            "{title}"
+        }
+    };
 
-    #[props(default)]
-    pub on_maximize: EventHandler<()>,
-    #[props(default)]
-    pub on_maximize_mouse_over: EventHandler<()>,
-    #[props(default)]
-    pub on_maximize_mouse_out: EventHandler<()>,
-    #[props(default)]
-    pub on_maximize_focus: EventHandler<FocusEvent>,
-    #[props(default)]
-    pub on_maximize_blur: EventHandler<FocusEvent>,
-    #[props(default)]
-    pub maximize_class: &'static str,
-    #[props(default)]
-    pub maximize_svg_class: &'static str,
-    #[props(default)]
-    pub maximize_path_class: &'static str,
-    #[props(default = "button")]
-    pub maximize_button_type: &'static str,
-    #[props(default)]
-    pub maximize_aria_label: &'static str,
-    #[props(default)]
-    pub maximize_title: &'static str,
-    #[props(default = "0")]
-    pub maximize_tabindex: &'static str,
+    match props.variant {
+        Variant::Tabs => rsx! {
+            div {
+                class: "{mode.class(css::TAB_LIST, props.class)}",
+                style: "{mode.inline(props.tablist_style)}",
+                role: "tablist",
+                aria_label: "Browser tabs",
+                div {
+                    class: "{mode.name(css::TAB)}",
+                    style: "{mode.inline(props.tab_style)}",
+                    role: "tab",
+                    aria_selected: "true",
+                    title: "{title}",
+                    {icon}
+                    {label}
+                }
+            }
+        },
+        Variant::Default => rsx! {
+            div {
+                class: "{mode.class(css::TITLE_BAR, props.class)}",
+                style: "{mode.inline(props.style)}",
+                title: "{title}",
+                {icon}
+                {label}
+            }
+        },
+        Variant::Ios => rsx! {},
+    }
+}
 
+#[derive(PartialEq, Props, Clone)]
+pub struct ShareMenuProps {
     #[props(default)]
-    pub share_button_style: &'static str,
+    pub url: String,
     #[props(default)]
-    pub share_onclick: EventHandler<()>,
+    pub title: String,
+    #[props(default = true)]
+    pub enabled: bool,
     #[props(default)]
-    pub share_onmouseover: EventHandler<()>,
+    pub on_share: EventHandler<ShareTarget>,
     #[props(default)]
-    pub share_onmouseout: EventHandler<()>,
+    pub onclick: EventHandler<()>,
     #[props(default)]
-    pub share_onfocus: EventHandler<FocusEvent>,
+    pub onmouseover: EventHandler<()>,
     #[props(default)]
-    pub share_onblur: EventHandler<FocusEvent>,
+    pub onmouseout: EventHandler<()>,
     #[props(default)]
-    pub share_tabindex: &'static str,
-
+    pub onfocus: EventHandler<FocusEvent>,
     #[props(default)]
-    pub tabs_button_style: &'static str,
+    pub onblur: EventHandler<FocusEvent>,
     #[props(default)]
-    pub tabs_onclick: EventHandler<()>,
+    pub tabindex: &'static str,
     #[props(default)]
-    pub tabs_onmouseover: EventHandler<()>,
+    pub button_style: &'static str,
     #[props(default)]
-    pub tabs_onmouseout: EventHandler<()>,
+    pub button_class: &'static str,
+    #[props(default = style::SHARE_POPOVER_STYLE)]
+    pub popover_style: &'static str,
+    #[props(default = style::SHARE_ITEM_STYLE)]
+    pub item_style: &'static str,
+    #[props(default = style::SHARE_QR_STYLE)]
+    pub qr_style: &'static str,
     #[props(default)]
-    pub tabs_onfocus: EventHandler<FocusEvent>,
+    pub style_mode: StyleMode,
+}
+
+#[component]
+pub fn ShareMenu(props: ShareMenuProps) -> Element {
+    let mut share = use_signal(ShareState::default);
+    let mut button_ref = use_signal(|| None::<Rc<MountedData>>);
+
+    let mut close = move || {
+        share.write().close();
+        if let Some(button) = button_ref() {
+            spawn(async move {
+                let _ = button.set_focus(true).await;
+            });
+        }
+    };
+
+    let onclick = {
+        let props = props.clone();
+        move |_| {
+            props.onclick.call(());
+            if !props.enabled {
+                return;
+            }
+            if share.read().open {
+                share.write().close();
+                return;
+            }
+
+            let on_share = props.on_share;
+            let (url, title) = (props.url.clone(), props.title.clone());
+            spawn(async move {
+                match platform::share(url, title).await {
+                    Some(true) => on_share.call(ShareTarget::Native),
+                    Some(false) | None => share.write().open_fallback(),
+                }
+            });
+        }
+    };
+
+    let on_copy = {
+        let url = props.url.clone();
+        move |_| {
+            let url = url.clone();
+            spawn(async move {
+                if platform::copy_text(url).await {
+                    share.write().mark_copied();
+                    props.on_share.call(ShareTarget::Clipboard);
+                }
+            });
+        }
+    };
+
+    let on_toggle_qr = move |_| {
+        if share.write().toggle_qr() {
+            props.on_share.call(ShareTarget::QrCode);
+        }
+    };
+
+    let state = share();
+    let mode = props.style_mode;
+    let qr_code = if state.show_qr {
+        QrCode::encode(props.url.as_bytes()).map(|qr| {
+            let extent = qr.size() + 8;
+            let path = qr.to_svg_path(4);
+            rsx! {
+                svg {
+                    class: "{mode.name(css::SHARE_QR)}",
+                    style: "{mode.inline(props.qr_style)}",
+                    view_box: "0 0 {extent} {extent}",
+                    role: "img",
+                    "aria-label": "QR code for {props.url}",
+                    "shape-rendering": "crispEdges",
+                    xmlns: "http://www.w3.org/2000/svg",
+                    rect { width: "100%", height: "100%", fill: "#ffffff" }
+                    path { d: "{path}", fill: "#000000" }
+                }
+            }
+        })
+    } else {
+        None
+    };
+
+    rsx! {
+        div {
+            class: mode.name(css::POPUP),
+            style: mode.inline("position: relative; display: flex;"),
+            // Handled on the wrapper so Escape on the trigger closes the
+            // popover instead of reaching the frame's window-level shortcuts.
+            onkeydown: move |e: KeyboardEvent| {
+                if share.read().open && e.key() == Key::Escape {
+                    e.prevent_default();
+                    e.stop_propagation();
+                    close();
+                }
+            },
+            button {
+                class: "{props.button_class}",
+                style: "{props.button_style}",
+                onmounted: move |cx| button_ref.set(Some(cx.data())),
+                onclick: onclick,
+                onmouseover: move |_| props.onmouseover.call(()),
+                onmouseout: move |_| props.onmouseout.call(()),
+                onfocus: props.onfocus,
+                onblur: props.onblur,
+                "aria-label": "Share",
+                "aria-haspopup": if props.enabled { "dialog" },
+                "aria-expanded": if props.enabled { "{state.open}" },
+                title: "Share",
+                tabindex: "{props.tabindex}",
+                svg {
+                    width: "15",
+                    height: "19",
+                    view_box: "0 0 15 19",
+                    fill: "none",
+                    xmlns: "http://www.w3.org/2000/svg",
+                    path {
+                        d: icons::SHARE,
+                        fill: "#767676",
+                    }
+                }
+            }
+            if state.open {
+                div {
+                    class: mode.name(css::BACKDROP),
+                    style: mode.inline("position: fixed; inset: 0; z-index: 40;"),
+                    onclick: move |_| share.write().close(),
+                }
+                div {
+                    class: "{mode.name(css::SHARE)}",
+                    style: "{mode.inline(props.popover_style)}",
+                    role: "dialog",
+                    "aria-label": "Share",
+                    button {
+                        class: "{mode.name(css::SHARE_ITEM)}",
+                        style: "{mode.inline(props.item_style)}",
+                        onmounted: move |cx| async move {
+                            let _ = cx.data().set_focus(true).await;
+                        },
+                        onclick: on_copy,
+                        "aria-live": "polite",
+                        {state.copy_label()}
+                    }
+                    a {
+                        class: "{mode.name(css::SHARE_ITEM)}",
+                        style: "{mode.inline(props.item_style)}",
+                        href: share_mailto(&props.url, &props.title),
+                        onclick: move |_| {
+                            props.on_share.call(ShareTarget::Email);
+                            close();
+                        },
+                        "Email"
+                    }
+                    button {
+                        class: "{mode.name(css::SHARE_ITEM)}",
+                        style: "{mode.inline(props.item_style)}",
+                        onclick: on_toggle_qr,
+                        "aria-expanded": "{state.show_qr}",
+                        {state.qr_label()}
+                    }
+                    {qr_code}
+                }
+            }
+        }
+    }
+}
+
+#[derive(PartialEq, Props, Clone)]
+pub struct MenuListProps {
+    pub items: Vec<MenuItem>,
+    pub id: String,
+    #[props(default)]
+    pub label: String,
+    #[props(default)]
+    pub on_select: EventHandler<String>,
+    #[props(default)]
+    pub on_close: EventHandler<()>,
+    /// Set on submenus: returns focus to the parent menu on `ArrowLeft`/`Escape`.
+    #[props(default)]
+    pub on_collapse: Option<EventHandler<()>>,
+    #[props(default = style::MENU_STYLE)]
+    pub menu_style: &'static str,
+    #[props(default = style::SUBMENU_STYLE)]
+    pub submenu_style: &'static str,
+    #[props(default = style::MENU_ITEM_STYLE)]
+    pub item_style: &'static str,
+    #[props(default = style::ACTIVE_ITEM_STYLE)]
+    pub item_active_style: &'static str,
+    #[props(default = style::MENU_ITEM_DISABLED_STYLE)]
+    pub item_disabled_style: &'static str,
+    #[props(default = style::MENU_SEPARATOR_STYLE)]
+    pub separator_style: &'static str,
+    #[props(default = style::MENU_SHORTCUT_STYLE)]
+    pub shortcut_style: &'static str,
+    #[props(default)]
+    pub style_mode: StyleMode,
+}
+
+#[component]
+pub fn MenuList(props: MenuListProps) -> Element {
+    let mut menu = use_signal(|| MenuState::new(&props.items));
+    let mut typeahead_reset = use_signal(|| None::<Task>);
+    let mut list_ref = use_signal(|| None::<Rc<MountedData>>);
+
+    // Carries out what the menu state asks for after an event.
+    let apply = move |action: MenuAction| match action {
+        MenuAction::Update => {}
+        MenuAction::Typeahead => {
+            if let Some(reset) = typeahead_reset.take() {
+                reset.cancel();
+            }
+            typeahead_reset.set(Some(spawn(async move {
+                platform::sleep(TYPEAHEAD_TIMEOUT).await;
+                menu.write().reset_typeahead();
+            })));
+        }
+        MenuAction::Select(id) => {
+            props.on_select.call(id);
+            props.on_close.call(());
+        }
+        MenuAction::Collapse => {
+            if let Some(on_collapse) = props.on_collapse {
+                on_collapse.call(());
+            }
+        }
+        MenuAction::Close | MenuAction::Leave => props.on_close.call(()),
+    };
+
+    let collapse_submenu = move |_| {
+        menu.write().collapse();
+        if let Some(list) = list_ref() {
+            spawn(async move {
+                let _ = list.set_focus(true).await;
+            });
+        }
+    };
+
+    let onkeydown = {
+        let props = props.clone();
+        let mut apply = apply;
+        move |e: KeyboardEvent| {
+            let key = e.key().to_string();
+            let modifiers = e.modifiers();
+            let input = KeyInput::new(&key)
+                .with_ctrl(modifiers.ctrl())
+                .with_meta(modifiers.meta())
+                .with_alt(modifiers.alt());
+            let nested = props.on_collapse.is_some();
+            let Some(action) = menu.write().key(&input, &props.items, nested) else {
+                return;
+            };
+            if action.prevents_default() {
+                e.prevent_default();
+                e.stop_propagation();
+            }
+            apply(action);
+        }
+    };
+
+    let mode = props.style_mode;
+    let (class, style) = if props.on_collapse.is_some() {
+        (
+            css::SUBMENU,
+            format!("{} {}", props.menu_style, props.submenu_style),
+        )
+    } else {
+        (css::MENU, props.menu_style.to_string())
+    };
+    let MenuState {
+        active, expanded, ..
+    } = menu();
+    let active_descendant = active.map(|index| menu_item_id(&props.id, index));
+
+    rsx! {
+        ul {
+            id: "{props.id}",
+            role: "menu",
+            tabindex: "-1",
+            "aria-label": if !props.label.is_empty() { "{props.label}" },
+            "aria-orientation": "vertical",
+            "aria-activedescendant": active_descendant,
+            class: "{mode.name(class)}",
+            style: "{mode.inline(&style)}",
+            onmounted: move |cx| {
+                let list = cx.data();
+                list_ref.set(Some(list.clone()));
+                spawn(async move {
+                    let _ = list.set_focus(true).await;
+                });
+            },
+            onkeydown: onkeydown,
+            for (index, item) in props.items.iter().cloned().enumerate() {
+                if item.separator {
+                    li {
+                        role: "separator",
+                        class: "{mode.name(css::MENU_SEPARATOR)}",
+                        style: "{mode.inline(props.separator_style)}",
+                    }
+                } else {
+                    {
+                        let is_active = active == Some(index);
+                        let is_expanded = expanded == Some(index);
+                        let mut item_style = props.item_style.to_string();
+                        if is_active {
+                            item_style = format!("{} {}", item_style, props.item_active_style);
+                        }
+                        if item.disabled {
+                            item_style = format!("{} {}", item_style, props.item_disabled_style);
+                        }
+                        let item_class = mode.class(&css::menu_item_class(is_active, item.disabled), "");
+                        let mut apply = apply;
+                        let items = props.items.clone();
+
+                        rsx! {
+                            // The children of a menu item are presentational, so a
+                            // submenu sits next to its trigger instead of inside it.
+                            li {
+                                role: "none",
+                                class: mode.name(css::MENU_ENTRY),
+                                style: mode.inline("position: relative;"),
+                                div {
+                                    id: menu_item_id(&props.id, index),
+                                    role: if item.checked.is_some() { "menuitemcheckbox" } else { "menuitem" },
+                                    "aria-checked": item.checked.map(|checked| checked.to_string()),
+                                    "aria-disabled": if item.disabled { "true" },
+                                    "aria-haspopup": if item.has_submenu() { "menu" },
+                                    "aria-expanded": if item.has_submenu() { "{is_expanded}" },
+                                    "aria-keyshortcuts": if !item.shortcut.is_empty() { "{item.shortcut}" },
+                                    class: "{item_class}",
+                                    style: "{mode.inline(&item_style)}",
+                                    onclick: {
+                                        let items = items.clone();
+                                        move |e: MouseEvent| {
+                                            e.stop_propagation();
+                                            let action = menu.write().activate(&items, index);
+                                            if let Some(action) = action {
+                                                apply(action);
+                                            }
+                                        }
+                                    },
+                                    onmouseenter: move |_| menu.write().hover(&items, index),
+                                    if let Some(checked) = item.checked {
+                                        span {
+                                            class: mode.name(css::MENU_CHECK),
+                                            style: mode.inline("width: 16px; text-align: center;"),
+                                            "aria-hidden": "true",
+                                            if checked { "✓" }
+                                        }
+                                    }
+                                    if !item.icon.is_empty() {
+                                        img { src: "{item.icon}", alt: "", width: "16", height: "16" }
+                                    }
+                                    span { "{item.label}" }
+                                    if !item.shortcut.is_empty() {
+                                        span {
+                                            class: "{mode.name(css::MENU_SHORTCUT)}",
+                                            style: "{mode.inline(props.shortcut_style)}",
+                                            "aria-hidden": "true",
+                                            "{item.shortcut}"
+                                        }
+                                    }
+                                    if item.has_submenu() {
+                                        span {
+                                            class: mode.name(css::MENU_ARROW),
+                                            style: mode.inline("margin-left: auto; padding-left: 16px;"),
+                                            "aria-hidden": "true",
+                                            "›"
+                                        }
+                                    }
+                                }
+                                if is_expanded {
+                                    MenuList {
+                                        items: item.submenu.clone(),
+                                        id: submenu_id(&props.id, index),
+                                        label: item.label.clone(),
+                                        on_select: props.on_select,
+                                        on_close: props.on_close,
+                                        on_collapse: Some(EventHandler::new(collapse_submenu)),
+                                        menu_style: props.menu_style,
+                                        submenu_style: props.submenu_style,
+                                        item_style: props.item_style,
+                                        item_active_style: props.item_active_style,
+                                        item_disabled_style: props.item_disabled_style,
+                                        separator_style: props.separator_style,
+                                        shortcut_style: props.shortcut_style,
+                                        style_mode: mode,
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[derive(PartialEq, Props, Clone)]
+pub struct MoreMenuProps {
+    #[props(default)]
+    pub items: Vec<MenuItem>,
+    /// Id of the menu; generated per instance when empty.
+    #[props(default)]
+    pub id: String,
+    #[props(default)]
+    pub on_select: EventHandler<String>,
+    #[props(default)]
+    pub onclick: EventHandler<()>,
+    #[props(default)]
+    pub onmouseover: EventHandler<()>,
+    #[props(default)]
+    pub onmouseout: EventHandler<()>,
+    #[props(default)]
+    pub onfocus: EventHandler<FocusEvent>,
+    #[props(default)]
+    pub onblur: EventHandler<FocusEvent>,
+    #[props(default)]
+    pub tabindex: &'static str,
+    #[props(default)]
+    pub button_style: &'static str,
+    #[props(default)]
+    pub button_class: &'static str,
+    #[props(default)]
+    pub style_mode: StyleMode,
+}
+
+#[component]
+pub fn MoreMenu(props: MoreMenuProps) -> Element {
+    let id = use_element_id(&props.id, "browser-more-menu");
+    let mut open = use_signal(|| false);
+    let mut button_ref = use_signal(|| None::<Rc<MountedData>>);
+    let has_menu = !props.items.is_empty();
+
+    let close = move |_| {
+        open.set(false);
+        if let Some(button) = button_ref() {
+            spawn(async move {
+                let _ = button.set_focus(true).await;
+            });
+        }
+    };
+
+    rsx! {
+        div {
+            class: props.style_mode.name(css::POPUP),
+            style: props.style_mode.inline("position: relative; display: flex;"),
+            button {
+                class: "{props.button_class}",
+                style: "{props.button_style}",
+                onmounted: move |cx| button_ref.set(Some(cx.data())),
+                onclick: move |_| {
+                    props.onclick.call(());
+                    if has_menu {
+                        open.set(!open());
+                    }
+                },
+                onkeydown: move |e: KeyboardEvent| {
+                    if has_menu && e.key() == Key::ArrowDown {
+                        e.prevent_default();
+                        open.set(true);
+                    }
+                },
+                onmouseover: move |_| props.onmouseover.call(()),
+                onmouseout: move |_| props.onmouseout.call(()),
+                onfocus: props.onfocus,
+                onblur: props.onblur,
+                "aria-label": "More options",
+                "aria-haspopup": if has_menu { "menu" },
+                "aria-expanded": if has_menu { "{open}" },
+                "aria-controls": if has_menu && open() { "{id}" },
+                title: "More options",
+                tabindex: "{props.tabindex}",
+                svg {
+                    width: "18",
+                    height: "19",
+                    view_box: "0 0 18 19",
+                    fill: "none",
+                    xmlns: "http://www.w3.org/2000/svg",
+                    path {
+                        d: icons::MORE,
+                        fill: "#767676",
+                    }
+                }
+            }
+            if open() {
+                div {
+                    class: props.style_mode.name(css::BACKDROP),
+                    style: props.style_mode.inline("position: fixed; inset: 0; z-index: 40;"),
+                    onclick: move |_| open.set(false),
+                }
+                MenuList {
+                    items: props.items.clone(),
+                    id: id(),
+                    label: "More options",
+                    on_select: props.on_select,
+                    on_close: close,
+                    style_mode: props.style_mode,
+                }
+            }
+        }
+    }
+}
+
+/// A button rendered in the header toolbar.
+///
+/// Build one with [`ToolbarItem::new`] and the `with_*` methods. Within a
+/// placement, items are shown by ascending `order`; when the header is too
+/// narrow the last ones move into the "More options" menu.
+#[derive(PartialEq, Clone)]
+pub struct ToolbarItem {
+    pub id: String,
+    pub icon: Element,
+    pub label: String,
+    /// Tooltip shown on hover; falls back to `label`.
+    pub tooltip: String,
+    /// Short text drawn over the icon, such as an unread count.
+    pub badge: Option<String>,
+    /// Makes the item a toggle button in the given state.
+    pub toggled: Option<bool>,
+    pub placement: ToolbarPlacement,
+    pub order: i32,
+    pub on_click: EventHandler<()>,
+}
+
+impl ToolbarItem {
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            icon: VNode::empty(),
+            label: label.into(),
+            tooltip: String::new(),
+            badge: None,
+            toggled: None,
+            placement: ToolbarPlacement::default(),
+            order: 0,
+            on_click: EventHandler::default(),
+        }
+    }
+
+    pub fn with_icon(mut self, icon: Element) -> Self {
+        self.icon = icon;
+        self
+    }
+
+    pub fn with_tooltip(mut self, tooltip: impl Into<String>) -> Self {
+        self.tooltip = tooltip.into();
+        self
+    }
+
+    pub fn with_badge(mut self, badge: impl Into<String>) -> Self {
+        self.badge = Some(badge.into());
+        self
+    }
+
+    pub fn with_toggled(mut self, toggled: bool) -> Self {
+        self.toggled = Some(toggled);
+        self
+    }
+
+    pub fn with_placement(mut self, placement: ToolbarPlacement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    pub fn with_order(mut self, order: i32) -> Self {
+        self.order = order;
+        self
+    }
+
+    pub fn with_on_click(mut self, on_click: EventHandler<()>) -> Self {
+        self.on_click = on_click;
+        self
+    }
+
+    /// The entry representing this item once it overflows into the menu.
+    pub fn to_menu_item(&self) -> MenuItem {
+        let label = match &self.badge {
+            Some(badge) => format!("{} ({})", self.label, badge),
+            None => self.label.clone(),
+        };
+        MenuItem {
+            checked: self.toggled,
+            ..MenuItem::new(self.id.clone(), label)
+        }
+    }
+}
+
+#[derive(PartialEq, Props, Clone)]
+pub struct ToolbarButtonProps {
+    pub item: ToolbarItem,
+    #[props(default)]
+    pub button_style: &'static str,
+    #[props(default)]
+    pub button_class: &'static str,
+    #[props(default = style::TOOLBAR_TOGGLED_STYLE)]
+    pub toggled_style: &'static str,
+    #[props(default = style::TOOLBAR_BADGE_STYLE)]
+    pub badge_style: &'static str,
+    #[props(default)]
+    pub style_mode: StyleMode,
+}
+
+#[component]
+pub fn ToolbarButton(props: ToolbarButtonProps) -> Element {
+    let item = props.item.clone();
+    let mode = props.style_mode;
+    let toggled = item.toggled == Some(true);
+    let style = if toggled {
+        format!(
+            "{} position: relative; {}",
+            props.button_style, props.toggled_style
+        )
+    } else {
+        format!("{} position: relative;", props.button_style)
+    };
+    let class = if toggled {
+        mode.class(css::TOOLBAR_BUTTON_TOGGLED, props.button_class)
+    } else {
+        mode.class(css::TOOLBAR_BUTTON, props.button_class)
+    };
+    let aria_label = match &item.badge {
+        Some(badge) => format!("{}, {}", item.label, badge),
+        None => item.label.clone(),
+    };
+    let title = if item.tooltip.is_empty() {
+        item.label.clone()
+    } else {
+        item.tooltip.clone()
+    };
+
+    rsx! {
+        button {
+            r#type: "button",
+            class: "{class}",
+            style: mode.inline(&style),
+            onclick: move |_| item.on_click.call(()),
+            "aria-label": "{aria_label}",
+            "aria-pressed": item.toggled.map(|toggled| toggled.to_string()),
+            title: "{title}",
+            {props.item.icon}
+            if let Some(badge) = &props.item.badge {
+                span {
+                    class: "{mode.name(css::TOOLBAR_BADGE)}",
+                    style: "{mode.inline(props.badge_style)}",
+                    "aria-hidden": "true",
+                    "{badge}"
+                }
+            }
+        }
+    }
+}
+
+#[derive(PartialEq, Props, Clone)]
+pub struct BrowserHeaderProps {
+    /// Prefix of the ids of the address bar and the "More options" menu;
+    /// each generates its own when empty.
+    #[props(default)]
+    pub id_prefix: String,
+    #[props(default)]
+    pub url: String,
+    #[props(default)]
+    pub placeholder: &'static str,
+    #[props(default)]
+    pub on_url_change: Option<EventHandler<FormEvent>>,
+    #[props(default)]
+    pub suggestions: SuggestionProvider,
+    #[props(default)]
+    pub on_suggestion_select: EventHandler<Suggestion>,
+    #[props(default = true)]
+    pub smart_display: bool,
+    #[props(default)]
+    pub title: String,
+    #[props(default)]
+    pub favicon: String,
+    #[props(default = true)]
+    pub show_controls: bool,
+    #[props(default)]
+    pub inactive: bool,
+    #[props(default = true)]
+    pub show_address_bar: bool,
+    #[props(default = false)]
+    pub read_only: bool,
+    #[props(default)]
+    pub variant: Variant,
+    #[props(default)]
+    pub size: Size,
+    #[props(default)]
+    pub style_mode: StyleMode,
+    #[props(default)]
+    pub toolbar_items: Vec<ToolbarItem>,
+    #[props(default = 32.0)]
+    pub toolbar_item_width: f64,
+    #[props(default = 420.0)]
+    pub toolbar_reserved_width: f64,
+    #[props(default)]
+    pub class: &'static str,
+
+    #[props(default)]
+    pub container_class: &'static str,
+    #[props(default)]
+    pub input_class: &'static str,
+    #[props(default)]
+    pub refresh_button_style: &'static str,
+    #[props(default = REFRESH_LABEL)]
+    pub refresh_button_aria_label: &'static str,
+    #[props(default)]
+    pub load_state: LoadState,
+    #[props(default)]
+    pub on_refresh: Option<EventHandler<()>>,
+    #[props(default)]
+    pub on_stop: EventHandler<()>,
+    #[props(default = 1.0)]
+    pub zoom: f64,
+    #[props(default)]
+    pub on_zoom_reset: EventHandler<()>,
+    #[props(default)]
+    pub on_submit: EventHandler<String>,
+    #[props(default)]
+    pub on_error: EventHandler<DomError>,
+    #[props(default = style::PROGRESS_BAR_STYLE)]
+    pub progress_bar_style: &'static str,
+
+    #[props(default = style::ICON_BUTTON_STYLE)]
+    pub icon_button_style: &'static str,
+
+    #[props(default = style::ADDRESS_WRAPPER_BASE_STYLE)]
+    pub address_wrapper_base_style: &'static str,
+
+    #[props(default = style::HEADER_BASE_STYLE)]
+    pub header_base_style: &'static str,
+
+    #[props(default)]
+    pub on_close: EventHandler<()>,
+    #[props(default)]
+    pub on_close_mouse_over: EventHandler<()>,
+    #[props(default)]
+    pub on_close_mouse_out: EventHandler<()>,
+    #[props(default)]
+    pub on_close_focus: EventHandler<FocusEvent>,
+    #[props(default)]
+    pub on_close_blur: EventHandler<FocusEvent>,
+    #[props(default)]
+    pub close_class: &'static str,
+    #[props(default)]
+    pub close_svg_class: &'static str,
+    #[props(default)]
+    pub close_path_class: &'static str,
+    #[props(default = "button")]
+    pub close_button_type: &'static str,
+    #[props(default)]
+    pub close_aria_label: &'static str,
+    #[props(default)]
+    pub close_title: &'static str,
+    #[props(default = "0")]
+    pub close_tabindex: &'static str,
+
+    #[props(default)]
+    pub on_minimize: EventHandler<()>,
+    #[props(default)]
+    pub on_minimize_mouse_over: EventHandler<()>,
+    #[props(default)]
+    pub on_minimize_mouse_out: EventHandler<()>,
+    #[props(default)]
+    pub on_minimize_focus: EventHandler<FocusEvent>,
+    #[props(default)]
+    pub on_minimize_blur: EventHandler<FocusEvent>,
+    #[props(default)]
+    pub minimize_class: &'static str,
+    #[props(default)]
+    pub minimize_svg_class: &'static str,
+    #[props(default)]
+    pub minimize_path_class: &'static str,
+    #[props(default = "button")]
+    pub minimize_button_type: &'static str,
+    #[props(default)]
+    pub minimize_aria_label: &'static str,
+    #[props(default)]
+    pub minimize_title: &'static str,
+    #[props(default = "0")]
+    pub minimize_tabindex: &'static str,
+
+    #[props(default)]
+    pub on_maximize: EventHandler<()>,
+    #[props(default)]
+    pub on_maximize_mouse_over: EventHandler<()>,
+    #[props(default)]
+    pub on_maximize_mouse_out: EventHandler<()>,
+    #[props(default)]
+    pub on_maximize_focus: EventHandler<FocusEvent>,
+    #[props(default)]
+    pub on_maximize_blur: EventHandler<FocusEvent>,
+    #[props(default)]
+    pub maximize_class: &'static str,
+    #[props(default)]
+    pub maximize_svg_class: &'static str,
+    #[props(default)]
+    pub maximize_path_class: &'static str,
+    #[props(default = "button")]
+    pub maximize_button_type: &'static str,
+    #[props(default)]
+    pub maximize_aria_label: &'static str,
+    #[props(default)]
+    pub maximize_title: &'static str,
+    #[props(default = "0")]
+    pub maximize_tabindex: &'static str,
+
+    #[props(default)]
+    pub share_button_style: &'static str,
+    #[props(default = true)]
+    pub share_menu: bool,
+    #[props(default)]
+    pub on_share: EventHandler<ShareTarget>,
+    #[props(default)]
+    pub share_onclick: EventHandler<()>,
+    #[props(default)]
+    pub share_onmouseover: EventHandler<()>,
+    #[props(default)]
+    pub share_onmouseout: EventHandler<()>,
+    #[props(default)]
+    pub share_onfocus: EventHandler<FocusEvent>,
+    #[props(default)]
+    pub share_onblur: EventHandler<FocusEvent>,
+    #[props(default)]
+    pub share_tabindex: &'static str,
+
+    #[props(default)]
+    pub tabs_button_style: &'static str,
+    #[props(default)]
+    pub tabs_onclick: EventHandler<()>,
+    #[props(default)]
+    pub tabs_onmouseover: EventHandler<()>,
+    #[props(default)]
+    pub tabs_onmouseout: EventHandler<()>,
+    #[props(default)]
+    pub tabs_onfocus: EventHandler<FocusEvent>,
     #[props(default)]
     pub tabs_onblur: EventHandler<FocusEvent>,
     #[props(default)]
@@ -552,6 +1810,10 @@ pub struct BrowserHeaderProps {
     #[props(default)]
     pub more_button_style: &'static str,
     #[props(default)]
+    pub menu_items: Vec<MenuItem>,
+    #[props(default)]
+    pub on_menu_select: EventHandler<String>,
+    #[props(default)]
     pub more_onclick: EventHandler<()>,
     #[props(default)]
     pub more_onmouseover: EventHandler<()>,
@@ -567,84 +1829,93 @@ pub struct BrowserHeaderProps {
 
 #[component]
 pub fn BrowserHeader(props: BrowserHeaderProps) -> Element {
+    let actions = use_roving_tabindex(props.on_error);
     let is_ios = props.variant == Variant::Ios;
-    let is_tabs = props.variant == Variant::Tabs;
-
-    let base_style = {
-        let padding = match props.size {
-            Size::Small => "4px 6px",
-            Size::Large => "10px 16px",
-            _ => "6px 12px",
-        };
-        let height = match (props.variant.clone(), props.size.clone()) {
-            (Variant::Tabs, _) => "40px",
-            (Variant::Ios, _) => "56px",
-            (_, Size::Large) => "60px",
-            (_, Size::Small) => "38px",
-            _ => "48px",
-        };
-        let border_radius = if is_tabs {
-            "6px"
-        } else if props.variant == Variant::Default {
-            "8px 8px 0 0"
-        } else {
-            "0"
-        };
-        let border = if is_tabs { "1px solid #d1d5db" } else { "none" };
-        let box_shadow = if props.variant == Variant::Default {
-            "0 2px 6px rgba(0,0,0,0.1)"
-        } else {
-            "none"
-        };
-
-        format!(
-            "{} justify-content: {}; padding: {}; height: {}; border-radius: {}; border: {}; box-shadow: {};",
-            props.header_base_style,
-            if is_ios {
-                "space-between"
-            } else {
-                "flex-start"
-            },
-            padding,
-            height,
-            border_radius,
-            border,
-            box_shadow
-        )
-    };
 
-    let address_wrapper_style = format!(
-        "{} padding-left: {};",
-        props.address_wrapper_base_style,
-        if props.show_controls { "8px" } else { "0" }
-    );
-
-    let share_onclick = move |_| props.share_onclick.call(());
-    let share_onmouseover = move |_| props.share_onmouseover.call(());
-    let share_onmouseout = move |_| props.share_onmouseout.call(());
+    let base_style = header_style(props.header_base_style, &props.variant, &props.size);
+    let address_wrapper_style =
+        address_wrapper_style(props.address_wrapper_base_style, props.show_controls);
 
     let tabs_onclick = move |_| props.tabs_onclick.call(());
     let tabs_onmouseover = move |_| props.tabs_onmouseover.call(());
     let tabs_onmouseout = move |_| props.tabs_onmouseout.call(());
 
-    let more_onclick = move |_| props.more_onclick.call(());
-    let more_onmouseover = move |_| props.more_onmouseover.call(());
-    let more_onmouseout = move |_| props.more_onmouseout.call(());
+    let mut header_ref = use_signal(|| None::<Rc<MountedData>>);
+    let header_width = use_element_width(header_ref, !props.toolbar_items.is_empty());
+
+    let toolbar = layout_toolbar(
+        &props
+            .toolbar_items
+            .iter()
+            .map(|item| (item.placement, item.order))
+            .collect::<Vec<_>>(),
+        header_width().map(|width| width - props.toolbar_reserved_width),
+        props.toolbar_item_width,
+    );
+    let left_items: Vec<ToolbarItem> = toolbar
+        .left
+        .iter()
+        .map(|&index| props.toolbar_items[index].clone())
+        .collect();
+    let right_items: Vec<ToolbarItem> = toolbar
+        .right
+        .iter()
+        .map(|&index| props.toolbar_items[index].clone())
+        .collect();
+
+    let mut menu_items: Vec<MenuItem> = toolbar
+        .overflow
+        .iter()
+        .map(|&index| props.toolbar_items[index].to_menu_item())
+        .collect();
+    if !menu_items.is_empty() && !props.menu_items.is_empty() {
+        menu_items.push(MenuItem::separator());
+    }
+    menu_items.extend(props.menu_items.iter().cloned());
+
+    let on_menu_select = {
+        let toolbar_items = props.toolbar_items.clone();
+        move |id: String| match toolbar_items.iter().find(|item| item.id == id) {
+            Some(item) => item.on_click.call(()),
+            None => props.on_menu_select.call(id),
+        }
+    };
+
+    let show_title_bar = !is_ios && (!props.title.is_empty() || !props.favicon.is_empty());
+    let mode = props.style_mode;
+    let icon_button_style = mode.inline(props.icon_button_style);
+    let icon_button_class = mode.name(css::ICON_BUTTON);
+    let address_class = mode.name(if props.show_controls {
+        css::ADDRESS_WITH_CONTROLS
+    } else {
+        css::ADDRESS
+    });
 
     rsx! {
+        if show_title_bar {
+            TitleBar {
+                title: props.title.clone(),
+                favicon: props.favicon.clone(),
+                variant: props.variant.clone(),
+                style_mode: mode,
+            }
+        }
         header {
-            style: "{base_style}",
-            class: "{props.class}",
+            style: "{mode.inline(&base_style)}",
+            class: "{mode.class(css::HEADER, props.class)}",
             "aria-label": "Browser window header",
+            onmounted: move |cx| header_ref.set(Some(cx.data())),
 
             div {
-                style: "display: flex; align-items: center; gap: 6px;",
+                class: "{mode.name(css::NAV)}",
+                style: mode.inline("display: flex; align-items: center; gap: 6px;"),
                 if props.show_controls {
                     BrowserControls {
                         on_close: props.on_close,
                         on_minimize: props.on_minimize,
                         on_maximize: props.on_maximize,
                         show_controls: props.show_controls,
+                        inactive: props.inactive,
                         on_close_mouse_over: props.on_close_mouse_over,
                         on_close_mouse_out: props.on_close_mouse_out,
                         on_close_focus: props.on_close_focus,
@@ -678,10 +1949,13 @@ pub fn BrowserHeader(props: BrowserHeaderProps) -> Element {
                         maximize_aria_label: props.maximize_aria_label,
                         maximize_title: props.maximize_title,
                         maximize_tabindex: props.maximize_tabindex,
+                        style_mode: mode,
+                        on_error: props.on_error,
                     }
                     if !is_ios {
                         button {
-                            style: "{props.icon_button_style}",
+                            class: "{icon_button_class}",
+                            style: "{icon_button_style}",
                             "aria-label": "Sidebar",
                             svg {
                                 width: "20",
@@ -690,13 +1964,14 @@ pub fn BrowserHeader(props: BrowserHeaderProps) -> Element {
                                 fill: "none",
                                 xmlns: "http://www.w3.org/2000/svg",
                                 path {
-                                    d: "M2.62346 15H16.4609C18.2202 15 19.0844 14.1358 19.0844 12.4074V2.59259C19.0844 0.864204 18.2202 0 16.4609 0H2.62346C0.874483 0 0 0.864204 0 2.59259V12.4074C0 14.1358 0.874483 15 2.62346 15ZM2.64404 13.5082C1.90329 13.5082 1.48149 13.1173 1.48149 12.3354V2.66461C1.48149 1.89301 1.90329 1.49177 2.64404 1.49177H6.22427V13.5082H2.64404ZM16.4403 1.49177C17.1811 1.49177 17.6029 1.89301 17.6029 2.66461V12.3354C17.6029 13.1173 17.1811 13.5082 16.4403 13.5082H7.67489V1.49177H16.4403ZM4.67078 4.47532C4.94857 4.47532 5.18518 4.2284 5.18518 3.9609C5.18518 3.69341 4.94857 3.46708 4.67078 3.46708H3.05556C2.78806 3.46708 2.55144 3.69341 2.55144 3.9609C2.55144 4.2284 2.78806 4.47532 3.05556 4.47532H4.67078ZM4.67078 6.53293C4.94857 6.53293 5.18518 6.29629 5.18518 6.01853C5.18518 5.75102 4.94857 5.52469 4.67078 5.52469H3.05556C2.78806 5.52469 2.55144 5.75102 2.55144 6.01853C2.55144 6.29629 2.78806 6.53293 3.05556 6.53293H4.67078ZM4.67078 8.59054C4.94857 8.59054 5.18518 8.35392 5.18518 8.08642C5.18518 7.81893 4.94857 7.5926 4.67078 7.5926H3.05556C2.78806 7.5926 2.55144 7.81893 2.55144 8.08642C2.55144 8.35392 2.78806 8.59054 3.05556 8.59054H4.67078Z",
+                                    d: icons::SIDEBAR,
                                     fill: "#767676",
                                 }
                             }
                         }
                         button {
-                            style: "{props.icon_button_style}",
+                            class: "{icon_button_class}",
+                            style: "{icon_button_style}",
                             "aria-label": "Back",
                             svg {
                                 width: "9",
@@ -705,7 +1980,7 @@ pub fn BrowserHeader(props: BrowserHeaderProps) -> Element {
                                 fill: "none",
                                 xmlns: "http://www.w3.org/2000/svg",
                                 path {
-                                    d: "M7.5 1.5L1 8L7.5 14.5",
+                                    d: icons::BACK,
                                     stroke: "#737373",
                                     stroke_width: "1.5",
                                     stroke_linecap: "round",
@@ -714,7 +1989,8 @@ pub fn BrowserHeader(props: BrowserHeaderProps) -> Element {
                             }
                         }
                         button {
-                            style: "{props.icon_button_style}",
+                            class: "{mode.name(css::ICON_BUTTON_MUTED)}",
+                            style: "{icon_button_style}",
                             "aria-label": "Forward",
                             svg {
                                 width: "9",
@@ -723,7 +1999,7 @@ pub fn BrowserHeader(props: BrowserHeaderProps) -> Element {
                                 fill: "none",
                                 xmlns: "http://www.w3.org/2000/svg",
                                 path {
-                                    d: "M1 14.5L7.5 8L1 1.5",
+                                    d: icons::FORWARD,
                                     stroke: "#BFBFBF",
                                     stroke_width: "1.5",
                                     stroke_linecap: "round",
@@ -733,95 +2009,672 @@ pub fn BrowserHeader(props: BrowserHeaderProps) -> Element {
                         }
                     }
                 }
+                for item in left_items {
+                    ToolbarButton {
+                        key: "{item.id}",
+                        item: item,
+                        button_style: icon_button_style,
+                        button_class: icon_button_class,
+                        style_mode: mode,
+                    }
+                }
             }
 
             if props.show_address_bar {
                 div {
-                    style: "{address_wrapper_style}",
+                    class: "{address_class}",
+                    style: "{mode.inline(&address_wrapper_style)}",
                     AddressBar {
-                        url: props.url,
+                        input_id: part_id(&props.id_prefix, "address"),
+                        url: props.url.clone(),
                         placeholder: props.placeholder,
                         on_url_change: props.on_url_change.unwrap_or_default(),
+                        suggestions: props.suggestions,
+                        on_suggestion_select: props.on_suggestion_select,
+                        smart_display: props.smart_display,
                         read_only: props.read_only,
                         input_class: props.input_class,
                         container_class: props.container_class,
                         refresh_button_style: props.refresh_button_style,
                         refresh_button_aria_label: props.refresh_button_aria_label,
+                        load_state: props.load_state.clone(),
+                        on_refresh: props.on_refresh,
+                        on_stop: props.on_stop,
+                        zoom: props.zoom,
+                        on_zoom_reset: props.on_zoom_reset,
+                        on_submit: props.on_submit,
+                        on_error: props.on_error,
+                        style_mode: mode,
+                    }
+                }
+            }
+
+            div {
+                class: "{mode.name(css::ACTIONS)}",
+                style: mode.inline("display: flex; align-items: center; gap: 6px; margin-left: auto;"),
+                role: "toolbar",
+                aria_label: "Page actions",
+                onmounted: move |e| actions.mounted(e),
+                onkeydown: move |e| actions.keydown(e),
+                onfocusin: move |e| actions.focusin(e),
+                for item in right_items {
+                    ToolbarButton {
+                        key: "{item.id}",
+                        item: item,
+                        button_style: icon_button_style,
+                        button_class: icon_button_class,
+                        style_mode: mode,
+                    }
+                }
+                if props.show_controls {
+                    ShareMenu {
+                        url: props.url.clone(),
+                        title: props.title.clone(),
+                        enabled: props.share_menu,
+                        on_share: props.on_share,
+                        onclick: props.share_onclick,
+                        onmouseover: props.share_onmouseover,
+                        onmouseout: props.share_onmouseout,
+                        onfocus: props.share_onfocus,
+                        onblur: props.share_onblur,
+                        tabindex: props.share_tabindex,
+                        button_style: icon_button_style,
+                        button_class: icon_button_class,
+                        style_mode: mode,
+                    }
+                    button {
+                        class: "{icon_button_class}",
+                        style: "{icon_button_style}",
+                        onclick: tabs_onclick,
+                        onmouseover: tabs_onmouseover,
+                        onmouseout: tabs_onmouseout,
+                        onfocus: props.tabs_onfocus,
+                        onblur: props.tabs_onblur,
+                        "aria-label": "Tabs",
+                        title: "Tabs",
+                        tabindex: "{props.tabs_tabindex}",
+                        svg {
+                            width: "15",
+                            height: "15",
+                            view_box: "0 0 15 15",
+                            fill: "none",
+                            xmlns: "http://www.w3.org/2000/svg",
+                            path {
+                                d: icons::TABS,
+                                fill: "#767676",
+                            }
+                        }
+                    }
+                }
+                if props.show_controls || !toolbar.overflow.is_empty() {
+                    MoreMenu {
+                        id: part_id(&props.id_prefix, "more-menu"),
+                        items: menu_items,
+                        on_select: on_menu_select,
+                        onclick: props.more_onclick,
+                        onmouseover: props.more_onmouseover,
+                        onmouseout: props.more_onmouseout,
+                        onfocus: props.more_onfocus,
+                        onblur: props.more_onblur,
+                        tabindex: props.more_tabindex,
+                        button_style: icon_button_style,
+                        button_class: icon_button_class,
+                        style_mode: mode,
+                    }
+                }
+            }
+
+            if let Some(progress) = props.load_state.progress() {
+                div {
+                    role: "progressbar",
+                    aria_label: "Page load progress",
+                    aria_valuemin: "0",
+                    aria_valuemax: "100",
+                    aria_valuenow: "{progress}",
+                    aria_hidden: if !props.load_state.is_loading() { "true" },
+                    class: "{mode.name(css::PROGRESS)}",
+                    style: "{mode.inline(props.progress_bar_style)} {props.load_state.progress_style()}",
+                }
+            }
+        }
+    }
+}
+
+#[derive(PartialEq, Props, Clone)]
+pub struct BookmarksBarProps {
+    #[props(default)]
+    pub bookmarks: Vec<Bookmark>,
+    /// Called with the URL of the bookmark that was clicked.
+    #[props(default)]
+    pub on_select: EventHandler<String>,
+    /// Prefix of the bar's element ids; generated per instance when empty.
+    #[props(default)]
+    pub id: String,
+    #[props(default)]
+    pub class: &'static str,
+    #[props(default = style::BOOKMARKS_BAR_STYLE)]
+    pub style: &'static str,
+    #[props(default = style::BOOKMARK_ITEM_STYLE)]
+    pub item_style: &'static str,
+    #[props(default = style::BOOKMARK_MENU_STYLE)]
+    pub menu_style: &'static str,
+    #[props(default = style::BOOKMARK_OVERFLOW_MENU_STYLE)]
+    pub overflow_menu_style: &'static str,
+    #[props(default)]
+    pub style_mode: StyleMode,
+}
+
+#[component]
+pub fn BookmarksBar(props: BookmarksBarProps) -> Element {
+    let mode = props.style_mode;
+    let mut list_ref = use_signal(|| None::<Rc<MountedData>>);
+    let list_width = use_element_width(list_ref, true);
+    let mut visible_count = use_signal(|| props.bookmarks.len());
+    let mut open_menu = use_signal(|| None::<(BookmarkMenu, i32)>);
+    let id = use_element_id(&props.id, "browser-bookmarks");
+
+    use_effect(use_reactive((&props.bookmarks,), move |(bookmarks,)| {
+        // Every entry stays rendered so it can be measured; the ones that do
+        // not fit are hidden and listed behind the chevron.
+        let _ = list_width();
+        let list_id = format!("{}-list", id.peek());
+        spawn(async move {
+            if let Some(fitting) = platform::fitting_children(&list_id).await {
+                visible_count.set(fitting.min(bookmarks.len()));
+            }
+        });
+    }));
+
+    let close = move |_| {
+        if let Some((menu, _)) = open_menu.take() {
+            platform::focus_by_id(&menu.trigger_id(&id()));
+        }
+    };
+
+    let mut toggle_menu = move |menu: BookmarkMenu| {
+        if bookmarks::is_open(open_menu(), menu) {
+            open_menu.set(None);
+            return;
+        }
+        let trigger = menu.trigger_id(&id());
+        spawn(async move {
+            let left = platform::offset_left(&trigger).await;
+            open_menu.set(Some((menu, left)));
+        });
+    };
+
+    let menu = open_menu().map(|(menu, left)| {
+        let (items, label) = menu.entries(&props.bookmarks, visible_count());
+        let menu_style = match menu {
+            BookmarkMenu::Folder(_) => props.menu_style,
+            BookmarkMenu::Overflow => props.overflow_menu_style,
+        };
+        (
+            items,
+            label,
+            menu.menu_id(&id()),
+            menu.class(),
+            menu.position(left, mode),
+            menu_style,
+        )
+    });
+
+    let overflowing = visible_count() < props.bookmarks.len();
+    let overflow_expanded = bookmarks::is_open(open_menu(), BookmarkMenu::Overflow);
+
+    rsx! {
+        nav {
+            class: "{mode.class(css::BOOKMARKS, props.class)}",
+            style: "{mode.inline(props.style)}",
+            "aria-label": "Bookmarks",
+            ul {
+                id: "{id}-list",
+                class: mode.name(css::BOOKMARK_LIST),
+                style: mode.inline("display: flex; flex: 1; min-width: 0; gap: 2px; margin: 0; padding: 0; list-style: none; overflow: hidden;"),
+                onmounted: move |cx| list_ref.set(Some(cx.data())),
+                for (index, bookmark) in props.bookmarks.iter().cloned().enumerate() {
+                    li {
+                        class: mode.name(if index >= visible_count() { css::BOOKMARK_ENTRY_HIDDEN } else { css::BOOKMARK_ENTRY }),
+                        style: mode.inline(if index >= visible_count() { "flex-shrink: 0; visibility: hidden;" } else { "flex-shrink: 0;" }),
+                        if bookmark.is_folder() {
+                            button {
+                                id: BookmarkMenu::Folder(index).trigger_id(&id()),
+                                r#type: "button",
+                                class: "{mode.name(css::BOOKMARK)}",
+                                style: "{mode.inline(props.item_style)}",
+                                "aria-haspopup": "menu",
+                                "aria-expanded": "{bookmarks::is_open(open_menu(), BookmarkMenu::Folder(index))}",
+                                onclick: move |_| toggle_menu(BookmarkMenu::Folder(index)),
+                                if !bookmark.icon.is_empty() {
+                                    img { src: "{bookmark.icon}", alt: "", width: "14", height: "14" }
+                                } else {
+                                    svg {
+                                        width: "14",
+                                        height: "14",
+                                        view_box: "0 0 16 16",
+                                        fill: "none",
+                                        "aria-hidden": "true",
+                                        path {
+                                            d: "M1.5 3.5C1.5 2.95 1.95 2.5 2.5 2.5H6L7.5 4H13.5C14.05 4 14.5 4.45 14.5 5V12.5C14.5 13.05 14.05 13.5 13.5 13.5H2.5C1.95 13.5 1.5 13.05 1.5 12.5V3.5Z",
+                                            stroke: "#767676",
+                                        }
+                                    }
+                                }
+                                span {
+                                    class: mode.name(css::BOOKMARK_TITLE),
+                                    style: mode.inline("overflow: hidden; text-overflow: ellipsis;"),
+                                    "{bookmark.title}"
+                                }
+                            }
+                        } else {
+                            a {
+                                href: "{bookmark.url}",
+                                class: "{mode.name(css::BOOKMARK)}",
+                                style: "{mode.inline(props.item_style)}",
+                                title: "{bookmark.url}",
+                                onclick: {
+                                    let url = bookmark.url.clone();
+                                    move |e: MouseEvent| {
+                                        e.prevent_default();
+                                        props.on_select.call(url.clone());
+                                    }
+                                },
+                                if !bookmark.icon.is_empty() {
+                                    img { src: "{bookmark.icon}", alt: "", width: "14", height: "14" }
+                                } else {
+                                    svg {
+                                        width: "14",
+                                        height: "14",
+                                        view_box: "0 0 16 16",
+                                        fill: "none",
+                                        "aria-hidden": "true",
+                                        circle { cx: "8", cy: "8", r: "6.5", stroke: "#767676" }
+                                        path {
+                                            d: "M1.5 8H14.5M8 1.5C9.7 3.3 10.5 5.5 10.5 8C10.5 10.5 9.7 12.7 8 14.5C6.3 12.7 5.5 10.5 5.5 8C5.5 5.5 6.3 3.3 8 1.5Z",
+                                            stroke: "#767676",
+                                        }
+                                    }
+                                }
+                                span {
+                                    class: mode.name(css::BOOKMARK_TITLE),
+                                    style: mode.inline("overflow: hidden; text-overflow: ellipsis;"),
+                                    "{bookmark.title}"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            if overflowing {
+                button {
+                    id: BookmarkMenu::Overflow.trigger_id(&id()),
+                    r#type: "button",
+                    class: "{mode.name(css::BOOKMARK)}",
+                    style: "{mode.inline(props.item_style)}",
+                    "aria-label": MORE_BOOKMARKS_LABEL,
+                    "aria-haspopup": "menu",
+                    "aria-expanded": "{overflow_expanded}",
+                    onclick: move |_| toggle_menu(BookmarkMenu::Overflow),
+                    svg {
+                        width: "12",
+                        height: "12",
+                        view_box: "0 0 12 12",
+                        fill: "none",
+                        "aria-hidden": "true",
+                        path {
+                            d: "M2.5 2.5L6 6L2.5 9.5M6.5 2.5L10 6L6.5 9.5",
+                            stroke: "#767676",
+                            stroke_width: "1.5",
+                            stroke_linecap: "round",
+                            stroke_linejoin: "round",
+                        }
+                    }
+                }
+            }
+            if let Some((items, label, menu_id, menu_class, position, menu_style)) = menu {
+                div {
+                    class: mode.name(css::BACKDROP),
+                    style: mode.inline("position: fixed; inset: 0; z-index: 40;"),
+                    onclick: move |_| open_menu.set(None),
+                }
+                div {
+                    class: "{mode.name(menu_class)}",
+                    style: "{position}",
+                    MenuList {
+                        items: items,
+                        id: menu_id,
+                        label: label,
+                        on_select: props.on_select,
+                        on_close: close,
+                        menu_style: menu_style,
+                        style_mode: mode,
+                    }
+                }
+            }
+        }
+    }
+}
+
+type SizeObserver = RefCell<Option<(ResizeObserver, Closure<dyn FnMut()>)>>;
+
+fn disconnect_size_observer(observer: &SizeObserver) {
+    if let Some((observer, _closure)) = observer.borrow_mut().take() {
+        observer.disconnect();
+    }
+}
+
+/// Returns `id`, or an id starting with `prefix` that is generated once for
+/// the component instance when `id` is empty.
+pub fn use_element_id(id: &str, prefix: &'static str) -> Memo<String> {
+    let id = id.to_string();
+    use_memo(use_reactive((&id,), move |(id,)| element_id(&id, prefix)))
+}
+
+/// Whether the page is shown in a forced colors mode such as Windows High
+/// Contrast, updated while the component is mounted.
+pub fn use_forced_colors() -> Signal<bool> {
+    let mut forced_colors = use_signal(|| false);
+
+    use_effect(move || {
+        spawn(platform::watch_media(
+            style::FORCED_COLORS_QUERY,
+            move |matches| forced_colors.set(matches),
+        ));
+    });
+
+    forced_colors
+}
+
+/// Tracks the width in pixels of the mounted `element` with a
+/// `ResizeObserver` while `enabled` is true. The desktop and mobile renderers
+/// measure it again whenever the window is resized.
+pub fn use_element_width(
+    element: Signal<Option<Rc<MountedData>>>,
+    enabled: bool,
+) -> Signal<Option<f64>> {
+    let mut width = use_signal(|| None::<f64>);
+    let observer: Rc<SizeObserver> = use_hook(|| Rc::new(RefCell::new(None)));
+    let mut measuring = use_signal(|| None::<Task>);
+    let resize_listener_name = use_hook(platform::listener_name);
+
+    {
+        let observer = observer.clone();
+        let resize_listener_name = resize_listener_name.clone();
+        use_effect(use_reactive((&enabled,), move |(enabled,)| {
+            disconnect_size_observer(&observer);
+            if let Some(task) = measuring.take() {
+                task.cancel();
+            }
+            if !platform::WEB {
+                platform::stop_listening(&resize_listener_name);
+                let Some(target) = element.read().clone() else {
+                    return;
+                };
+                if enabled {
+                    let mut resizes = platform::watch_resize(&resize_listener_name);
+                    measuring.set(Some(spawn(async move {
+                        loop {
+                            if let Ok(rect) = target.get_client_rect().await {
+                                width.set(Some(rect.width()));
+                            }
+                            if resizes.recv::<bool>().await.is_err() {
+                                break;
+                            }
+                        }
+                    })));
+                }
+                return;
+            }
+            let Some(target) = element
+                .read()
+                .as_ref()
+                .and_then(|r| r.downcast::<web_sys::Element>().cloned())
+            else {
+                return;
+            };
+            if !enabled {
+                return;
+            }
+
+            let observed = target.clone();
+            let closure = Closure::<dyn FnMut()>::new(move || {
+                width.set(Some(observed.client_width() as f64));
+            });
+            if let Ok(resize_observer) = ResizeObserver::new(closure.as_ref().unchecked_ref()) {
+                resize_observer.observe(&target);
+                *observer.borrow_mut() = Some((resize_observer, closure));
+            }
+        }));
+    }
+
+    use_drop(move || {
+        if platform::WEB {
+            disconnect_size_observer(&observer);
+        } else {
+            platform::stop_listening(&resize_listener_name);
+        }
+    });
+
+    width
+}
+
+type LoadListener = RefCell<Option<(web_sys::Element, Closure<dyn FnMut(web_sys::Event)>)>>;
+
+fn remove_load_listener(listener: &LoadListener) {
+    if let Some((container, closure)) = listener.borrow_mut().take() {
+        let _ = container.remove_event_listener_with_callback_and_bool(
+            "load",
+            closure.as_ref().unchecked_ref(),
+            true,
+        );
+    }
+}
+
+#[derive(PartialEq, Props, Clone)]
+pub struct FindBarProps {
+    /// Element whose rendered text is searched.
+    pub target: Signal<Option<Rc<MountedData>>>,
+    #[props(default)]
+    pub on_close: EventHandler<()>,
+    #[props(default)]
+    pub on_error: EventHandler<DomError>,
+    /// Id of the bar; generated per instance when empty.
+    #[props(default)]
+    pub id: String,
+    #[props(default)]
+    pub class: &'static str,
+    #[props(default = style::FIND_BAR_STYLE)]
+    pub style: &'static str,
+    #[props(default = style::FIND_INPUT_STYLE)]
+    pub input_style: &'static str,
+    #[props(default = style::FIND_STATUS_STYLE)]
+    pub status_style: &'static str,
+    #[props(default = style::FIND_BUTTON_STYLE)]
+    pub button_style: &'static str,
+    #[props(default = style::FIND_HIGHLIGHT_COLOR)]
+    pub highlight_color: &'static str,
+    #[props(default = style::FIND_CURRENT_HIGHLIGHT_COLOR)]
+    pub current_highlight_color: &'static str,
+    #[props(default)]
+    pub style_mode: StyleMode,
+}
+
+/// Searches the rendered text of `target` and highlights the matches.
+///
+/// Needs Dioxus web: elsewhere the target is not a `web_sys` element, so
+/// nothing is found.
+#[component]
+pub fn FindBar(props: FindBarProps) -> Element {
+    let announcer = use_announcer();
+    let id = use_element_id(&props.id, "browser-find");
+    let mut find = use_signal(FindState::default);
+    let ranges: Rc<RefCell<Vec<Range>>> = use_hook(|| Rc::new(RefCell::new(Vec::new())));
+    let target = props.target;
+    let on_error = props.on_error;
+
+    {
+        // Only clean up highlights this bar registered, so dropping it never
+        // touches browser APIs when nothing was searched, e.g. during SSR.
+        let ranges = ranges.clone();
+        use_drop(move || {
+            if !ranges.borrow().is_empty() {
+                page::clear_find_highlights(&id.peek());
+            }
+        });
+    }
+
+    let on_input = {
+        let ranges = ranges.clone();
+        move |e: FormEvent| {
+            let value = e.value();
+            let root = target
+                .read()
+                .as_ref()
+                .and_then(|r| r.downcast::<web_sys::Element>().cloned());
+            let found = match &root {
+                Some(root) if !value.is_empty() => page::find_ranges(root, &value),
+                _ => Vec::new(),
+            };
+            let mut find = find.write();
+            let first = find.search(value, found.len());
+            // Highlighting needs the web renderer. Elsewhere the target is not
+            // a `web_sys` element, so nothing is found or highlighted.
+            if root.is_some() {
+                page::show_find_matches(&id.read(), &found, first);
+            }
+            if !find.query.is_empty() {
+                if let Err(error) = announcer.say(&find.status(), Politeness::Polite) {
+                    on_error.call(error);
+                }
+            }
+            *ranges.borrow_mut() = found;
+        }
+    };
+
+    let step = use_callback(move |forward: bool| {
+        let mut find = find.write();
+        let Some(next) = find.step(forward) else {
+            return;
+        };
+        page::show_find_matches(&id.read(), &ranges.borrow(), Some(next));
+        if let Err(error) = announcer.say(&find.status(), Politeness::Polite) {
+            on_error.call(error);
+        }
+    });
+
+    let on_close = props.on_close;
+    let on_key_down = move |e: KeyboardEvent| {
+        let key = e.key().to_string();
+        let input = KeyInput::new(&key).with_shift(e.modifiers().shift());
+        match find_command(&input) {
+            Some(FindCommand::Next) => step(true),
+            Some(FindCommand::Previous) => step(false),
+            Some(FindCommand::Close) => {
+                e.stop_propagation();
+                on_close.call(());
+            }
+            None => return,
+        }
+        e.prevent_default();
+    };
+
+    let highlight_css = highlight_css(&id(), props.highlight_color, props.current_highlight_color);
+    let status = find.read().visible_status();
+    let FindState { query, total, .. } = find();
+    let mode = props.style_mode;
+    let button_class = mode.name(css::FIND_BUTTON);
+    let button_style = mode.inline(props.button_style);
+
+    rsx! {
+        div {
+            id: "{id}",
+            class: "{mode.class(css::FIND, props.class)}",
+            style: "{mode.inline(props.style)}",
+            role: "search",
+            "aria-label": "Find in page",
+            style { "{highlight_css}" }
+            input {
+                id: "{id}-input",
+                r#type: "search",
+                class: "{mode.name(css::FIND_INPUT)}",
+                style: "{mode.inline(props.input_style)}",
+                placeholder: "Find in page",
+                "aria-label": "Find in page",
+                "aria-describedby": "{id}-status",
+                value: "{query}",
+                oninput: on_input,
+                onkeydown: on_key_down,
+                onmounted: move |cx| async move {
+                    let _ = cx.data().set_focus(true).await;
+                },
+            }
+            span {
+                id: "{id}-status",
+                class: "{mode.name(css::FIND_STATUS)}",
+                style: "{mode.inline(props.status_style)}",
+                "{status}"
+            }
+            button {
+                r#type: "button",
+                class: "{button_class}",
+                style: "{button_style}",
+                "aria-label": "Previous match",
+                disabled: total == 0,
+                onclick: move |_| step(false),
+                svg {
+                    width: "14",
+                    height: "14",
+                    view_box: "0 0 16 16",
+                    fill: "none",
+                    "aria-hidden": "true",
+                    path {
+                        d: "M4 10L8 6L12 10",
+                        stroke: "#767676",
+                        stroke_width: "1.5",
+                        stroke_linecap: "round",
+                        stroke_linejoin: "round",
                     }
                 }
             }
-
-            div {
-                style: "display: flex; align-items: center; gap: 6px; margin-left: auto;",
-                if props.show_controls {
-                    for btn in &props.custom_buttons {
-                        {btn}
-                    }
-                    button {
-                        style: "{props.icon_button_style}",
-                        onclick: share_onclick,
-                        onmouseover: share_onmouseover,
-                        onmouseout: share_onmouseout,
-                        onfocus: props.share_onfocus,
-                        onblur: props.share_onblur,
-                        "aria-label": "Share",
-                        title: "Share",
-                        tabindex: "{props.share_tabindex}",
-                        svg {
-                            width: "15",
-                            height: "19",
-                            view_box: "0 0 15 19",
-                            fill: "none",
-                            xmlns: "http://www.w3.org/2000/svg",
-                            path {
-                                d: "M7.49467 12.3969C7.91045 12.3969 8.26225 12.056 8.26225 11.6513V3.34416L8.1983 2.06613L8.64605 2.55604L9.81876 3.82343C9.95736 3.97254 10.1493 4.04709 10.3305 4.04709C10.7356 4.04709 11.0341 3.77017 11.0341 3.38676C11.0341 3.17377 10.9488 3.02467 10.7996 2.88621L8.04905 0.255589C7.85715 0.0638861 7.69722 0 7.49467 0C7.30277 0 7.14286 0.0638861 6.94029 0.255589L4.18977 2.88621C4.05117 3.02467 3.96589 3.17377 3.96589 3.38676C3.96589 3.77017 4.25372 4.04709 4.65885 4.04709C4.84009 4.04709 5.04264 3.97254 5.18124 3.82343L6.35395 2.55604L6.80171 2.06613L6.73774 3.34416V11.6513C6.73774 12.056 7.08955 12.3969 7.49467 12.3969ZM2.71855 19H12.2814C14.1045 19 15 18.1054 15 16.3161V8.12611C15 6.33688 14.1045 5.44225 12.2814 5.44225H9.98934V6.98654H12.2601C13.0171 6.98654 13.4648 7.4019 13.4648 8.20066V16.2416C13.4648 17.051 13.0171 17.4557 12.2601 17.4557H2.73988C1.97228 17.4557 1.53519 17.051 1.53519 16.2416V8.20066C1.53519 7.4019 1.97228 6.98654 2.73988 6.98654H5.01065V5.44225H2.71855C0.906181 5.44225 0 6.33688 0 8.12611V16.3161C0 18.1054 0.906181 19 2.71855 19Z",
-                                fill: "#767676",
-                            }
-                        }
-                    }
-                    button {
-                        style: "{props.icon_button_style}",
-                        onclick: tabs_onclick,
-                        onmouseover: tabs_onmouseover,
-                        onmouseout: tabs_onmouseout,
-                        onfocus: props.tabs_onfocus,
-                        onblur: props.tabs_onblur,
-                        "aria-label": "Tabs",
-                        title: "Tabs",
-                        tabindex: "{props.tabs_tabindex}",
-                        svg {
-                            width: "15",
-                            height: "15",
-                            view_box: "0 0 15 15",
-                            fill: "none",
-                            xmlns: "http://www.w3.org/2000/svg",
-                            path {
-                                d: "M7.01662 14.6401C7.4887 14.6401 7.87493 14.2646 7.87493 13.7925V8.3745H13.1642C13.6255 8.3745 14.0225 7.97755 14.0225 7.50547C14.0225 7.03341 13.6255 6.63643 13.1642 6.63643H7.87493V1.20768C7.87493 0.735619 7.4887 0.360107 7.01662 0.360107C6.54456 0.360107 6.14758 0.735619 6.14758 1.20768V6.63643H0.869031C0.396973 6.63643 0 7.03341 0 7.50547C0 7.97755 0.396973 8.3745 0.869031 8.3745H6.14758V13.7925C6.14758 14.2646 6.54456 14.6401 7.01662 14.6401Z",
-                                fill: "#767676",
-                            }
-                        }
+            button {
+                r#type: "button",
+                class: "{button_class}",
+                style: "{button_style}",
+                "aria-label": "Next match",
+                disabled: total == 0,
+                onclick: move |_| step(true),
+                svg {
+                    width: "14",
+                    height: "14",
+                    view_box: "0 0 16 16",
+                    fill: "none",
+                    "aria-hidden": "true",
+                    path {
+                        d: "M4 6L8 10L12 6",
+                        stroke: "#767676",
+                        stroke_width: "1.5",
+                        stroke_linecap: "round",
+                        stroke_linejoin: "round",
                     }
-                    button {
-                        style: "{props.icon_button_style}",
-                        onclick: more_onclick,
-                        onmouseover: more_onmouseover,
-                        onmouseout: more_onmouseout,
-                        onfocus: props.more_onfocus,
-                        onblur: props.more_onblur,
-                        "aria-label": "More options",
-                        title: "More options",
-                        tabindex: "{props.more_tabindex}",
-                        svg {
-                            width: "18",
-                            height: "19",
-                            view_box: "0 0 18 19",
-                            fill: "none",
-                            xmlns: "http://www.w3.org/2000/svg",
-                            path {
-                                d: "M2.67776 14.2898H3.97934V15.5914C3.97934 17.3407 4.85401 18.205 6.63458 18.205H14.8189C16.5891 18.205 17.4742 17.3407 17.4742 15.5914V7.32373C17.4742 5.5744 16.5891 4.71016 14.8189 4.71016H13.5174V3.40857C13.5174 1.65923 12.6323 0.794983 10.8621 0.794983H2.67776C0.897191 0.794983 0.022522 1.65923 0.022522 3.40857V11.6762C0.022522 13.4256 0.897191 14.2898 2.67776 14.2898ZM2.69859 12.7904C1.94886 12.7904 1.52195 12.3843 1.52195 11.5929V3.49187C1.52195 2.70051 1.94886 2.29442 2.69859 2.29442H10.8413C11.591 2.29442 12.0179 2.70051 12.0179 3.49187V4.71016H6.63458C4.85401 4.71016 3.97934 5.5744 3.97934 7.32373V12.7904H2.69859ZM6.65539 16.7056C5.90568 16.7056 5.47878 16.2995 5.47878 15.5081V7.40704C5.47878 6.61567 5.90568 6.20957 6.65539 6.20957H14.7981C15.5478 6.20957 15.9747 6.61567 15.9747 7.40704V15.5081C15.9747 16.2995 15.5478 16.7056 14.7981 16.7056H6.65539Z",
-                                fill: "#767676",
-                            }
-                        }
+                }
+            }
+            button {
+                r#type: "button",
+                class: "{button_class}",
+                style: "{button_style}",
+                "aria-label": "Close find bar",
+                onclick: move |_| on_close.call(()),
+                svg {
+                    width: "14",
+                    height: "14",
+                    view_box: "0 0 16 16",
+                    fill: "none",
+                    "aria-hidden": "true",
+                    path {
+                        d: "M4 4L12 12M12 4L4 12",
+                        stroke: "#767676",
+                        stroke_width: "1.5",
+                        stroke_linecap: "round",
                     }
                 }
             }
@@ -829,103 +2682,223 @@ pub fn BrowserHeader(props: BrowserHeaderProps) -> Element {
     }
 }
 
+/// A WAI-ARIA toolbar with a roving tabindex, returned by
+/// [`use_roving_tabindex`]. Forward the toolbar element's `onmounted`,
+/// `onkeydown` and `onfocusin` events to it.
+///
+/// The arrow keys, `Home` and `End` move between the toolbar's buttons on
+/// Dioxus web. The desktop and mobile renderers keep one tab stop per button.
+#[derive(Clone, Copy)]
+pub struct RovingTabindex {
+    toolbar: Signal<Option<Rc<MountedData>>>,
+    on_error: EventHandler<DomError>,
+}
+
+impl RovingTabindex {
+    fn element(&self) -> Option<web_sys::Element> {
+        self.toolbar
+            .peek()
+            .as_ref()?
+            .downcast::<web_sys::Element>()
+            .cloned()
+    }
+
+    fn sync(&self, active: Option<&web_sys::EventTarget>) {
+        if let Some(toolbar) = self.element() {
+            if let Err(error) = dom::sync_roving_tabindex(&toolbar, active) {
+                self.on_error.call(error);
+            }
+        }
+    }
+
+    pub fn mounted(mut self, e: MountedEvent) {
+        self.toolbar.set(Some(e.data()));
+        self.sync(None);
+    }
+
+    pub fn keydown(self, e: KeyboardEvent) {
+        let data = e.data();
+        let (Some(toolbar), Some(event)) =
+            (self.element(), data.downcast::<web_sys::KeyboardEvent>())
+        else {
+            return;
+        };
+        if let Err(error) = dom::rove_toolbar(event, &toolbar) {
+            self.on_error.call(error);
+        }
+    }
+
+    pub fn focusin(self, e: FocusEvent) {
+        let target = e
+            .data()
+            .downcast::<web_sys::FocusEvent>()
+            .and_then(|event| event.target());
+        self.sync(target.as_ref());
+    }
+}
+
+pub fn use_roving_tabindex(on_error: EventHandler<DomError>) -> RovingTabindex {
+    let toolbar = use_signal(|| None);
+    RovingTabindex { toolbar, on_error }
+}
+
+/// Reads messages to screen readers through live regions shared by every
+/// frame on the page.
+///
+/// Get one with [`use_announcer`]. The texts of [`Announcement`]s come from
+/// an `AnnouncerMessages` context when one is provided, e.g. with
+/// `use_context_provider(|| messages)`.
+#[derive(Clone, Copy, PartialEq, Default)]
+pub struct Announcer {
+    messages: AnnouncerMessages,
+}
+
+impl Announcer {
+    /// Reads `message` on the `politeness` channel.
+    pub fn say(&self, message: &str, politeness: Politeness) -> Result<(), DomError> {
+        platform::announce(message, politeness)
+    }
+
+    /// Reads the message for `announcement`.
+    pub fn announce(&self, announcement: &Announcement) -> Result<(), DomError> {
+        self.say(
+            &self.messages.message(announcement),
+            announcement.politeness(),
+        )
+    }
+}
+
+pub fn use_announcer() -> Announcer {
+    let messages = try_use_context::<AnnouncerMessages>().unwrap_or_default();
+    Announcer { messages }
+}
+
+/// Keys handled by [`use_keyboard`]. On the web `Escape` only counts when it
+/// is pressed inside the container the returned ref is put on. The desktop
+/// and mobile renderers forward every `Escape` on the page; `on_enter` and
+/// `trap_focus` need Dioxus web.
 #[derive(Clone, PartialEq, Props)]
 pub struct KeyboardNavigationOptions {
     pub on_escape: Option<EventHandler<()>>,
     pub on_enter: Option<EventHandler<()>>,
     pub trap_focus: bool,
+    pub on_error: Option<EventHandler<DomError>>,
+}
+
+fn report_error(on_error: Option<EventHandler<DomError>>, error: DomError) {
+    if let Some(on_error) = on_error {
+        on_error.call(error);
+    }
+}
+
+type KeyListener = RefCell<Option<(web_sys::Window, Closure<dyn Fn(web_sys::KeyboardEvent)>)>>;
+
+fn remove_key_listener(listener: &KeyListener, on_error: Option<EventHandler<DomError>>) {
+    if let Some((window, closure)) = listener.borrow_mut().take() {
+        if let Err(error) =
+            dom::remove_event_listener(&window, "keydown", closure.as_ref().unchecked_ref())
+        {
+            report_error(on_error, error);
+        }
+    }
 }
 
 pub fn use_keyboard(options: KeyboardNavigationOptions) -> Signal<Option<Rc<MountedData>>> {
     let container_ref: Signal<Option<Rc<MountedData>>> = use_signal(|| None);
+    let key_listener: Rc<KeyListener> = use_hook(|| Rc::new(RefCell::new(None)));
+    let listener_name = use_hook(platform::listener_name);
+    let on_error = options.on_error;
 
     {
         let options = options.clone();
+        let key_listener = key_listener.clone();
+        let listener_name = listener_name.clone();
 
         use_effect(move || {
+            if !platform::WEB {
+                // Desktop and mobile webviews only forward `Escape`; `Enter`
+                // on the container and focus trapping need the web renderer.
+                let Some(on_escape) = options.on_escape else {
+                    return;
+                };
+                platform::stop_listening(&listener_name);
+                let mut keys = platform::listen_for_escape(&listener_name);
+                spawn(async move {
+                    while keys.recv::<String>().await.is_ok() {
+                        on_escape.call(());
+                    }
+                });
+                return;
+            }
+
             let closure = Closure::<dyn Fn(web_sys::KeyboardEvent)>::wrap(Box::new(
                 move |event: web_sys::KeyboardEvent| {
                     let key = event.key();
-                    let target = event.target();
-
-                    match key.as_str() {
-                        "Escape" => {
+                    let target = dom::origin(&event);
+                    let inside = container_ref
+                        .read()
+                        .as_ref()
+                        .and_then(|r| r.downcast::<web_sys::Element>())
+                        .is_none_or(|container| dom::started_inside(&event, container));
+
+                    match navigation_command(&KeyInput::new(&key), options.trap_focus, inside) {
+                        Some(NavigationCommand::Escape) => {
                             if let Some(callback) = &options.on_escape {
                                 event.prevent_default();
                                 callback.call(());
                             }
                         }
-                        "Enter" => {
+                        Some(NavigationCommand::Enter) => {
                             if let Some(callback) = &options.on_enter {
-                                if let Some(_target_elem) = target
-                                    .clone()
-                                    .and_then(|t| t.dyn_into::<web_sys::Element>().ok())
-                                {
-                                    if let Some(container) = container_ref
-                                        .read()
-                                        .as_ref()
-                                        .and_then(|r| r.downcast::<web_sys::Element>())
-                                    {
-                                        if target.unwrap() == ***container {
-                                            event.prevent_default();
-                                            callback.call(());
-                                        }
-                                    }
+                                let on_container = container_ref
+                                    .read()
+                                    .as_ref()
+                                    .and_then(|r| r.downcast::<web_sys::Element>().cloned())
+                                    .is_some_and(|container| {
+                                        target.as_ref() == Some(container.as_ref())
+                                    });
+                                if on_container {
+                                    event.prevent_default();
+                                    callback.call(());
                                 }
                             }
                         }
-                        "Tab" if options.trap_focus => {
+                        Some(NavigationCommand::TrapFocus) => {
                             if let Some(container) = container_ref
                                 .read()
                                 .as_ref()
                                 .and_then(|r| r.downcast::<web_sys::Element>())
                             {
-                                let selector = "button, [href], input, select, textarea, [tabindex]:not([tabindex=\"-1\"])";
-                                let focusables = container.query_selector_all(selector).unwrap();
-
-                                let length = focusables.length();
-                                if length == 0 {
-                                    return;
-                                }
-
-                                let first = focusables
-                                    .item(0)
-                                    .and_then(|e| e.dyn_into::<web_sys::HtmlElement>().ok());
-                                let last = focusables
-                                    .item(length - 1)
-                                    .and_then(|e| e.dyn_into::<web_sys::HtmlElement>().ok());
-
-                                let document = web_sys::window().unwrap().document().unwrap();
-                                let active = document.active_element();
-
-                                if event.shift_key() {
-                                    if active == first.as_ref().map(|e| e.clone().into()) {
-                                        event.prevent_default();
-                                        if let Some(elem) = last {
-                                            elem.focus().ok();
-                                        }
-                                    }
-                                } else if active == last.as_ref().map(|e| e.clone().into()) {
-                                    event.prevent_default();
-                                    if let Some(elem) = first {
-                                        elem.focus().ok();
-                                    }
+                                if let Err(error) = dom::trap_focus(&event, container) {
+                                    report_error(options.on_error, error);
                                 }
                             }
                         }
-                        _ => {}
+                        None => {}
                     }
                 },
             )
                 as Box<dyn Fn(web_sys::KeyboardEvent)>);
 
-            web_sys::window()
-                .unwrap()
-                .add_event_listener_with_callback("keydown", closure.as_ref().unchecked_ref())
-                .unwrap();
+            remove_key_listener(&key_listener, options.on_error);
+            match dom::window().and_then(|window| {
+                dom::add_event_listener(&window, "keydown", closure.as_ref().unchecked_ref())
+                    .map(|_| window)
+            }) {
+                Ok(window) => *key_listener.borrow_mut() = Some((window, closure)),
+                Err(error) => report_error(options.on_error, error),
+            }
         });
     }
 
+    use_drop(move || {
+        if platform::WEB {
+            remove_key_listener(&key_listener, on_error);
+        } else {
+            platform::stop_listening(&listener_name);
+        }
+    });
+
     container_ref
 }
 /// Properties for the `BrowserFrame` component.
@@ -950,6 +2923,80 @@ pub struct BrowserFrameProps {
     #[props(default)]
     pub on_url_change: Option<EventHandler<FormEvent>>,
 
+    /// Source of the autocomplete suggestions shown under the address bar.
+    ///
+    /// Defaults to `SuggestionProvider::None`, which disables the dropdown.
+    #[props(default)]
+    pub suggestions: SuggestionProvider,
+
+    /// Event handler triggered when a suggestion is picked from the dropdown.
+    #[props(default)]
+    pub on_suggestion_select: EventHandler<Suggestion>,
+
+    /// Whether the unfocused address bar shows a simplified URL (no scheme or
+    /// `www.`, emphasized domain, greyed-out path).
+    ///
+    /// Defaults to `true`.
+    #[props(default = true)]
+    pub smart_display: bool,
+
+    /// Title of the displayed page, shown in the title bar (`Variant::Default`)
+    /// or in the active tab (`Variant::Tabs`).
+    ///
+    /// When the content contains a same-origin `iframe`, the title of the loaded
+    /// document replaces this value.
+    #[props(default)]
+    pub title: String,
+
+    /// URL of the page icon shown next to the title. A globe icon is shown when
+    /// empty or when the image fails to load.
+    #[props(default)]
+    pub favicon: String,
+
+    /// Saved links shown in a bookmarks bar under the header. The bar is hidden
+    /// when empty.
+    #[props(default)]
+    pub bookmarks: Vec<Bookmark>,
+
+    /// Event handler invoked with the URL the frame navigates to from a bookmark.
+    ///
+    /// The address bar shows the new URL and an `iframe` inside the content is
+    /// pointed at it; other content should react through this handler.
+    #[props(default)]
+    pub on_navigate: EventHandler<String>,
+
+    /// Event handler invoked with the text submitted from the address bar with
+    /// `Enter` or by picking a suggestion.
+    ///
+    /// The frame does not navigate on its own, as the text may be a search
+    /// term; update `url` to go to the address it stands for.
+    #[props(default)]
+    pub on_submit: EventHandler<String>,
+
+    /// Enables the find-in-page bar, opened with `Cmd/Ctrl+F` while focus is
+    /// inside the frame. It searches the rendered text of the content.
+    ///
+    /// Only Dioxus web offers the bar; the desktop and mobile renderers ignore
+    /// the shortcut.
+    #[props(default = true)]
+    pub find_in_page: bool,
+
+    /// Zoom factor of the content, e.g. `1.25` for 125%. The content is scaled
+    /// with its layout preserved, and the level is shown in the address bar when
+    /// it is not 100%.
+    #[props(default = 1.0)]
+    pub zoom: f64,
+
+    /// Event handler invoked with the new zoom factor when it changes through
+    /// the `Cmd/Ctrl` `+`, `-` and `0` shortcuts or the address bar badge.
+    #[props(default)]
+    pub on_zoom_change: EventHandler<f64>,
+
+    /// Event handler invoked when a DOM operation fails, e.g. when announcing
+    /// to screen readers or reloading the page. Failures never panic.
+    #[props(default)]
+    pub on_error: EventHandler<DomError>,
+
     /// Event handler triggered when the close button is clicked.
     #[props(default)]
     pub on_close: EventHandler<()>,
@@ -968,6 +3015,13 @@ pub struct BrowserFrameProps {
     #[props(default = true)]
     pub show_controls: bool,
 
+    /// Whether the window is inactive, which draws the window controls grey
+    /// until the pointer or focus enters them.
+    ///
+    /// Defaults to `false`.
+    #[props(default)]
+    pub inactive: bool,
+
     /// Whether to show the address bar.
     ///
     /// Defaults to `true`.
@@ -988,19 +3042,57 @@ pub struct BrowserFrameProps {
     #[props(default)]
     pub variant: Variant,
 
-    /// Optional list of custom buttons displayed in the top bar.
+    /// How the frame chrome is styled. `StyleMode::Stylesheet` replaces the
+    /// inline default styles with stable `browser-frame__*` classes styled by
+    /// `browser_rs::common::css::STYLESHEET`.
     #[props(default)]
-    pub custom_buttons: Vec<Element>,
+    pub style_mode: StyleMode,
 
-    /// CSS class applied to the outermost container.
+    /// Transitions played when the frame opens, closes, minimizes or
+    /// maximizes. Skipped while the user prefers reduced motion.
+    #[props(default)]
+    pub animation: Animation,
+
+    /// Callback when a window transition finishes, or right away when the
+    /// phase doesn't animate. Unmount the frame here after
+    /// `AnimationPhase::Close`.
+    #[props(default)]
+    pub on_animation_end: EventHandler<AnimationPhase>,
+
+    /// Whether the host shows the frame minimized. Setting it plays the
+    /// minimize transition, and clearing it restores a minimized frame with
+    /// `AnimationPhase::Restore`.
+    #[props(default)]
+    pub minimized: bool,
+
+    /// Buttons added to the header toolbar, placed left or right of the
+    /// address bar or inside the "More options" menu.
     ///
-    /// Defaults to:
-    /// `"rounded-lg border shadow-lg overflow-hidden bg-white dark:bg-gray-800 border-gray-200 dark:border-gray-700"`
-    #[props(
-        default = "rounded-lg border shadow-lg overflow-hidden bg-white dark:bg-gray-800 border-gray-200 dark:border-gray-700"
-    )]
+    /// Inline items that do not fit in the header collapse into the menu.
+    #[props(default)]
+    pub toolbar_items: Vec<ToolbarItem>,
+
+    /// Width in pixels taken by each inline toolbar item when deciding how
+    /// many fit in the header.
+    #[props(default = 32.0)]
+    pub toolbar_item_width: f64,
+
+    /// Header width in pixels kept free for the window controls, address bar
+    /// and built-in buttons before toolbar items start to collapse.
+    #[props(default = 420.0)]
+    pub toolbar_reserved_width: f64,
+
+    /// CSS class applied to the outermost container.
+    /// `browser_rs::common::tailwind::FRAME_CLASS` restores the Tailwind look.
+    #[props(default)]
     pub class: &'static str,
 
+    /// Default look of the outermost container: background, border, radius
+    /// and shadow, following the system color scheme. Set to `""` when
+    /// styling the container through `class`.
+    #[props(default = style::FRAME_BASE_STYLE)]
+    pub base_style: &'static str,
+
     /// CSS class applied to the browser frame.
     #[props(default)]
     pub frame_class: &'static str,
@@ -1009,7 +3101,8 @@ pub struct BrowserFrameProps {
     #[props(default)]
     pub style: &'static str,
 
-    /// HTML id attribute for the browser container.
+    /// HTML id attribute for the browser container; generated per instance
+    /// when empty.
     #[props(default)]
     pub id: &'static str,
 
@@ -1028,35 +3121,44 @@ pub struct BrowserFrameProps {
     pub container_class: &'static str,
 
     /// Additional CSS class for the input element in the address bar.
-    ///
-    /// Defaults to `"text-black dark:text-white"`.
-    #[props(default = "text-black dark:text-white")]
+    #[props(default)]
     pub input_class: &'static str,
 
     /// Inline style for the refresh button inside the address bar.
-    #[props(
-        default = "position: absolute; top: 50%; right: 8px; transform: translateY(-50%); padding: 4px; background: none; border: none; box-shadow: none; outline: none; cursor: pointer;"
-    )]
+    #[props(default = style::REFRESH_BUTTON_STYLE)]
     pub refresh_button_style: &'static str,
 
     /// ARIA label for the refresh button.
     ///
     /// Defaults to `"Refresh"`.
-    #[props(default = "Refresh")]
+    #[props(default = REFRESH_LABEL)]
     pub refresh_button_aria_label: &'static str,
 
+    /// Loading state of the page, shown as a progress bar along the bottom of
+    /// the header. While loading, the refresh button turns into a stop button.
+    #[props(default)]
+    pub load_state: LoadState,
+
+    /// Event handler triggered when the refresh button is clicked.
+    ///
+    /// When `None`, the refresh button reloads the whole window.
+    #[props(default)]
+    pub on_refresh: Option<EventHandler<()>>,
+
+    /// Event handler triggered when the stop button is clicked while loading.
+    #[props(default)]
+    pub on_stop: EventHandler<()>,
+
     /// Shared inline style for all icon buttons (close, minimize, maximize).
-    #[props(
-        default = "padding: 4px; cursor: pointer; background: none; border: none; box-shadow: none; outline: none;"
-    )]
+    #[props(default = style::ICON_BUTTON_STYLE)]
     pub icon_button_style: &'static str,
 
     /// Inline style for the wrapper around the address bar.
-    #[props(default = "flex: 1; display: flex; justify-content: center; padding-right: 8px;")]
+    #[props(default = style::ADDRESS_WRAPPER_BASE_STYLE)]
     pub address_wrapper_base_style: &'static str,
 
     /// Inline style for the header container (holds address bar and controls).
-    #[props(default = "display: flex; align-items: center; position: relative;")]
+    #[props(default = style::HEADER_BASE_STYLE)]
     pub header_base_style: &'static str,
 
     // Close button props
@@ -1134,6 +3236,17 @@ pub struct BrowserFrameProps {
     // Share button props
     #[props(default)]
     pub share_button_style: &'static str,
+
+    /// Opens the built-in share menu: the native share sheet through
+    /// `navigator.share` when available, otherwise a popover with "Copy link",
+    /// "Email" and a QR code for the current URL.
+    #[props(default = true)]
+    pub share_menu: bool,
+
+    /// Event handler invoked with the target the page was shared to.
+    #[props(default)]
+    pub on_share: EventHandler<ShareTarget>,
+
     #[props(default)]
     pub share_onclick: EventHandler<()>,
     #[props(default)]
@@ -1166,6 +3279,16 @@ pub struct BrowserFrameProps {
     // More button props
     #[props(default)]
     pub more_button_style: &'static str,
+
+    /// Items of the menu opened by the "More options" button. The button only
+    /// emits `more_onclick` when this is empty.
+    #[props(default)]
+    pub menu_items: Vec<MenuItem>,
+
+    /// Event handler invoked with the `id` of the selected menu item.
+    #[props(default)]
+    pub on_menu_select: EventHandler<String>,
+
     #[props(default)]
     pub more_onclick: EventHandler<()>,
     #[props(default)]
@@ -1215,20 +3338,20 @@ pub struct BrowserFrameProps {
 /// }
 /// ```
 ///
-/// ## With Custom Buttons
+/// ## With Toolbar Items
 /// ```rust
 /// use dioxus::prelude::*;
-/// use browser_rs::dioxus::BrowserFrame;
+/// use browser_rs::dioxus::{BrowserFrame, ToolbarItem};
 ///
 /// fn app() -> Element {
-///     let custom_button = rsx! {
-///         button { "Custom Button" }
-///     };
+///     let bookmark = ToolbarItem::new("bookmark", "Bookmark")
+///         .with_icon(rsx! { span { "★" } })
+///         .with_on_click(EventHandler::new(|_| log::info!("Bookmarked")));
 ///
 ///     rsx! {
 ///         BrowserFrame {
 ///             url: "https://opensass.org",
-///             custom_buttons: vec![custom_button],
+///             toolbar_items: vec![bookmark],
 ///             children: rsx! {
 ///                 p { "Custom button in the header!" }
 ///             }
@@ -1270,48 +3393,316 @@ pub struct BrowserFrameProps {
 /// - Accessibility attributes (`aria-*`) are provided.
 #[component]
 pub fn BrowserFrame(props: BrowserFrameProps) -> Element {
-    let on_close = props.on_close;
+    let frame_id = use_element_id(props.id, "browser-frame");
+    let announcer = use_announcer();
+    let on_error = props.on_error;
+    let announce = move |announcement: Announcement| {
+        if let Err(error) = announcer.announce(&announcement) {
+            on_error.call(error);
+        }
+    };
+
+    let animation = props.animation;
+    let on_animation_end = props.on_animation_end;
+    let mut animation_state =
+        use_signal(|| AnimationState::start(animation, AnimationPhase::Open, false));
+
+    let start_animation = move |phase: AnimationPhase| {
+        spawn(async move {
+            let reduced_motion = platform::prefers_reduced_motion().await;
+            let state = AnimationState::start(animation, phase, reduced_motion);
+            animation_state.set(state);
+            if state == AnimationState::Done(phase) {
+                on_animation_end.call(phase);
+            }
+        });
+    };
+
+    let (on_close, on_minimize, on_maximize) =
+        (props.on_close, props.on_minimize, props.on_maximize);
+    let close = move |_| {
+        on_close.call(());
+        announce(Announcement::Closed);
+        start_animation(AnimationPhase::Close);
+    };
+    let minimize = move |_| {
+        on_minimize.call(());
+        announce(Announcement::Minimized);
+        start_animation(AnimationPhase::Minimize);
+    };
+    let maximize = move |_| {
+        on_maximize.call(());
+        announce(Announcement::Maximized);
+        start_animation(AnimationPhase::Maximize);
+    };
 
+    // `Escape` is handled by `on_key_down` on the frame itself: the webview
+    // listener behind `use_keyboard` cannot tell which frame a key press
+    // started in.
     let mut container_ref = use_keyboard(KeyboardNavigationOptions {
-        on_escape: Some(EventHandler::new(move |_| {
-            on_close.call(());
-        })),
+        on_escape: None,
         on_enter: None,
         trap_focus: false,
+        on_error: Some(props.on_error),
+    });
+
+    use_effect(move || {
+        if let AnimationState::Done(phase) = *animation_state.peek() {
+            on_animation_end.call(phase);
+        }
+    });
+    use_effect(move || {
+        let AnimationState::Pending(phase) = animation_state() else {
+            return;
+        };
+        spawn(async move {
+            if platform::prefers_reduced_motion().await {
+                animation_state.set(AnimationState::Done(phase));
+                on_animation_end.call(phase);
+                return;
+            }
+            // Lay out the start style first so the switch transitions.
+            if let Some(container) = container_ref
+                .peek()
+                .as_ref()
+                .and_then(|r| r.downcast::<web_sys::HtmlElement>().cloned())
+            {
+                let _ = container.offset_width();
+            }
+            animation_state.set(AnimationState::Running(phase));
+        });
+    });
+    use_effect(use_reactive((&props.minimized,), move |(minimized,)| {
+        if let Some(phase) = animation_state.peek().follow_minimized(minimized) {
+            start_animation(phase);
+        }
+    }));
+
+    let on_transition_end = move |e: TransitionEvent| {
+        let state = animation_state();
+        let AnimationState::Running(phase) = state else {
+            return;
+        };
+        let property =
+            platform::transition_end(&e.data(), &container_ref.peek(), phase.end_property());
+        if let Some(phase) = property.and_then(|property| state.finished(&property)) {
+            animation_state.set(AnimationState::Done(phase));
+            on_animation_end.call(phase);
+        }
+    };
+
+    let mut current_url = use_signal(|| props.url.clone());
+    let mut page_title = use_signal(|| props.title.clone());
+    let mut page_favicon = use_signal(|| props.favicon.clone());
+
+    use_effect(use_reactive((&props.url,), move |(url,)| {
+        current_url.set(url);
+    }));
+    use_effect(use_reactive((&props.title,), move |(title,)| {
+        page_title.set(title);
+    }));
+    use_effect(use_reactive((&props.favicon,), move |(favicon,)| {
+        page_favicon.set(favicon);
+    }));
+
+    let mut was_loading = use_signal(|| false);
+    use_effect(use_reactive((&props.load_state,), move |(load_state,)| {
+        let previous = *was_loading.peek();
+        was_loading.set(load_state.is_loading());
+        if let Some(announcement) =
+            Announcement::for_load(previous, &load_state, &page_title.peek())
+        {
+            announce(announcement);
+        }
+    }));
+
+    let load_listener: Rc<LoadListener> = use_hook(|| Rc::new(RefCell::new(None)));
+    let load_listener_name = use_hook(platform::listener_name);
+
+    {
+        let load_listener = load_listener.clone();
+        let load_listener_name = load_listener_name.clone();
+        use_effect(move || {
+            if !platform::WEB {
+                if container_ref.read().is_none() {
+                    return;
+                }
+                platform::stop_listening(&load_listener_name);
+                let mut pages =
+                    platform::listen_for_page_info(&load_listener_name, &frame_id.read());
+                spawn(async move {
+                    while let Ok((title, favicon)) = pages.recv::<(String, Option<String>)>().await
+                    {
+                        if !title.is_empty() {
+                            page_title.set(title);
+                        }
+                        page_favicon.set(favicon.unwrap_or_default());
+                    }
+                });
+                return;
+            }
+
+            let Some(container) = container_ref
+                .read()
+                .as_ref()
+                .and_then(|r| r.downcast::<web_sys::Element>().cloned())
+            else {
+                return;
+            };
+
+            // `load` does not bubble, so listen in the capture phase to catch
+            // iframes anywhere inside the frame.
+            let closure =
+                Closure::<dyn FnMut(web_sys::Event)>::new(move |event: web_sys::Event| {
+                    if let Some((title, favicon)) = page::read_page_info(&event) {
+                        if !title.is_empty() {
+                            page_title.set(title);
+                        }
+                        page_favicon.set(favicon.unwrap_or_default());
+                    }
+                });
+            let _ = container.add_event_listener_with_callback_and_bool(
+                "load",
+                closure.as_ref().unchecked_ref(),
+                true,
+            );
+            remove_load_listener(&load_listener);
+            *load_listener.borrow_mut() = Some((container, closure));
+        });
+    }
+
+    use_drop(move || {
+        if platform::WEB {
+            remove_load_listener(&load_listener);
+        } else {
+            platform::stop_listening(&load_listener_name);
+        }
     });
 
-    let size_style = props.size.to_style();
-    let combined_style = format!("{} {}", size_style, props.style);
+    let on_navigate = props.on_navigate;
+    let navigate = move |url: String| {
+        current_url.set(url.clone());
+        platform::navigate_iframe(&container_ref.read(), &frame_id.read(), &url);
+        on_navigate.call(url);
+    };
+
+    let mut find_open = use_signal(|| false);
+    let mut content_ref = use_signal(|| None::<Rc<MountedData>>);
+
+    let mut zoom = use_signal(|| props.zoom);
+    use_effect(use_reactive((&props.zoom,), move |(level,)| {
+        zoom.set(level);
+    }));
+
+    let on_zoom_change = props.on_zoom_change;
+    let mut set_zoom = move |level: f64| {
+        if (level - zoom()).abs() > f64::EPSILON {
+            zoom.set(level);
+            on_zoom_change.call(level);
+        }
+    };
+
+    // The find bar walks and highlights the rendered text through `web_sys`,
+    // so the desktop and mobile renderers do not offer it.
+    let find_in_page = props.find_in_page && platform::WEB;
+    let on_key_down = move |e: KeyboardEvent| {
+        let key = e.key().to_string();
+        let modifiers = e.modifiers();
+        let input = KeyInput::new(&key)
+            .with_ctrl(modifiers.ctrl())
+            .with_meta(modifiers.meta());
+        if navigation_command(&input, false, true) == Some(NavigationCommand::Escape) {
+            e.prevent_default();
+            close(());
+            return;
+        }
+        let Some(command) = frame_command(&input, find_in_page) else {
+            return;
+        };
+        e.prevent_default();
+        match command {
+            FrameCommand::Find => {
+                if !find_open() {
+                    find_open.set(true);
+                } else {
+                    platform::focus_search_input(&container_ref.peek());
+                }
+            }
+            FrameCommand::ZoomIn => set_zoom(zoom_step(zoom(), true)),
+            FrameCommand::ZoomOut => set_zoom(zoom_step(zoom(), false)),
+            FrameCommand::ZoomReset => set_zoom(1.0),
+        }
+    };
+
+    let on_find_close = move |_| {
+        find_open.set(false);
+        if let Some(content) = content_ref() {
+            spawn(async move {
+                let _ = content.set_focus(true).await;
+            });
+        }
+    };
+
+    let mode = props.style_mode;
+    let mut combined_style = match mode {
+        StyleMode::Inline => frame_style(props.base_style, &props.size, props.style),
+        StyleMode::Stylesheet => props.style.to_string(),
+    };
+    let motion_style = animation_state().style(animation);
+    if !motion_style.is_empty() {
+        combined_style = format!("{combined_style} {motion_style}");
+    }
+    let article_class = mode.class(&frame_class(&props.variant, &props.size), props.class);
 
     rsx! {
         article {
-            id: "{props.id}",
-            class: "{props.class}",
+            id: "{frame_id}",
+            class: "{article_class}",
             style: "{combined_style}",
             role: "application",
             aria_label: "{props.aria_label}",
             aria_describedby: "{props.aria_describedby}",
+            aria_busy: if props.load_state.is_loading() { "true" },
             tabindex: "-1",
             onmounted: move |cx| container_ref.set(Some(cx.data())),
+            onkeydown: on_key_down,
+            ontransitionend: on_transition_end,
 
             BrowserHeader {
-                url: props.url,
+                id_prefix: props.id,
+                url: current_url(),
                 placeholder: props.placeholder,
                 on_url_change: props.on_url_change,
-                on_close: props.on_close,
-                on_minimize: props.on_minimize,
-                on_maximize: props.on_maximize,
+                suggestions: props.suggestions,
+                on_suggestion_select: props.on_suggestion_select,
+                smart_display: props.smart_display,
+                title: page_title(),
+                favicon: page_favicon(),
+                on_close: close,
+                on_minimize: minimize,
+                on_maximize: maximize,
                 show_controls: props.show_controls,
+                inactive: props.inactive,
                 show_address_bar: props.show_address_bar,
                 read_only: props.read_only,
                 variant: props.variant,
                 size: props.size,
-                custom_buttons: props.custom_buttons,
+                style_mode: mode,
+                toolbar_items: props.toolbar_items.clone(),
+                toolbar_item_width: props.toolbar_item_width,
+                toolbar_reserved_width: props.toolbar_reserved_width,
                 class: props.frame_class,
                 container_class: props.container_class,
                 input_class: props.input_class,
                 refresh_button_style: props.refresh_button_style,
                 refresh_button_aria_label: props.refresh_button_aria_label,
+                load_state: props.load_state.clone(),
+                on_refresh: props.on_refresh,
+                on_stop: props.on_stop,
+                zoom: zoom(),
+                on_zoom_reset: move |_| set_zoom(1.0),
+                on_submit: props.on_submit,
+                on_error: props.on_error,
                 icon_button_style: props.icon_button_style,
                 address_wrapper_base_style: props.address_wrapper_base_style,
                 header_base_style: props.header_base_style,
@@ -1349,6 +3740,8 @@ pub fn BrowserFrame(props: BrowserFrameProps) -> Element {
                 maximize_title: props.maximize_title,
                 maximize_tabindex: props.maximize_tabindex,
                 share_button_style: props.share_button_style,
+                share_menu: props.share_menu,
+                on_share: props.on_share,
                 share_onclick: props.share_onclick,
                 share_onmouseover: props.share_onmouseover,
                 share_onmouseout: props.share_onmouseout,
@@ -1363,6 +3756,8 @@ pub fn BrowserFrame(props: BrowserFrameProps) -> Element {
                 tabs_onblur: props.tabs_onblur,
                 tabs_tabindex: props.tabs_tabindex,
                 more_button_style: props.more_button_style,
+                menu_items: props.menu_items.clone(),
+                on_menu_select: props.on_menu_select,
                 more_onclick: props.more_onclick,
                 more_onmouseover: props.more_onmouseover,
                 more_onmouseout: props.more_onmouseout,
@@ -1370,8 +3765,28 @@ pub fn BrowserFrame(props: BrowserFrameProps) -> Element {
                 more_onblur: props.more_onblur,
                 more_tabindex: props.more_tabindex,
             }
+            if !props.bookmarks.is_empty() {
+                BookmarksBar {
+                    id: part_id(props.id, "bookmarks"),
+                    bookmarks: props.bookmarks.clone(),
+                    on_select: navigate,
+                    style_mode: mode,
+                }
+            }
+            if find_open() {
+                FindBar {
+                    id: part_id(props.id, "find"),
+                    target: content_ref,
+                    on_close: on_find_close,
+                    on_error: props.on_error,
+                    style_mode: mode,
+                }
+            }
             BrowserContent {
                 aria_describedby: props.aria_describedby,
+                onmounted: move |cx: MountedEvent| content_ref.set(Some(cx.data())),
+                zoom: zoom(),
+                style_mode: mode,
                 {props.children}
             }
         }
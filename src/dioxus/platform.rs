@@ -0,0 +1,446 @@
+//! Renderer-neutral access to the page behind the Dioxus components.
+//!
+//! Dioxus web runs on `wasm32` and reaches the DOM through `web_sys`. The
+//! desktop and mobile renderers draw into a webview where `web_sys` calls
+//! would abort the app, so there the same behavior runs as JavaScript through
+//! `document::eval`. Dynamic values are sent to the script rather than
+//! formatted into it.
+
+use crate::common::{
+    DomError,
+    animation::REDUCED_MOTION_QUERY,
+    announcer::{Politeness, region_id},
+    style::VISUALLY_HIDDEN,
+};
+use crate::dom;
+use dioxus::prelude::*;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use web_sys::{HtmlElement, HtmlIFrameElement, HtmlInputElement, wasm_bindgen::JsCast, window};
+
+/// Whether the components run on Dioxus web, where `web_sys` is available.
+pub(super) const WEB: bool = cfg!(target_arch = "wasm32");
+
+fn eval_with(script: &str, args: &[&str]) -> document::Eval {
+    let eval = document::eval(script);
+    let _ = eval.send(args);
+    eval
+}
+
+fn element_by_id(id: &str) -> Option<HtmlElement> {
+    window()?
+        .document()?
+        .get_element_by_id(id)?
+        .dyn_into::<HtmlElement>()
+        .ok()
+}
+
+pub(super) fn input_element(node: &Option<Rc<MountedData>>) -> Option<HtmlInputElement> {
+    node.as_ref()?
+        .downcast::<web_sys::Element>()?
+        .dyn_ref::<HtmlInputElement>()
+        .cloned()
+}
+
+/// Announces `message` through the persistent live region for `politeness`.
+pub(super) fn announce(message: &str, politeness: Politeness) -> Result<(), DomError> {
+    if WEB {
+        return dom::announce(message, politeness);
+    }
+    let _ = eval_with(
+        r#"
+        const [message, id, live, style] = await dioxus.recv();
+        let region = document.getElementById(id);
+        if (!region) {
+            region = document.createElement("div");
+            region.id = id;
+            region.setAttribute("aria-live", live);
+            region.setAttribute("aria-atomic", "true");
+            region.setAttribute("style", style);
+            document.body.appendChild(region);
+        }
+        region.textContent = "";
+        clearTimeout(region.browserRsPending);
+        region.browserRsPending = setTimeout(() => (region.textContent = message), 100);
+        "#,
+        &[
+            message,
+            region_id(politeness),
+            &politeness.to_string(),
+            VISUALLY_HIDDEN,
+        ],
+    );
+    Ok(())
+}
+
+/// Whether the user asked for less motion.
+pub(super) async fn prefers_reduced_motion() -> bool {
+    if WEB {
+        return dom::prefers_reduced_motion();
+    }
+    eval_with(
+        "const [query] = await dioxus.recv(); return window.matchMedia(query).matches;",
+        &[REDUCED_MOTION_QUERY],
+    )
+    .join::<bool>()
+    .await
+    .unwrap_or(false)
+}
+
+/// Calls `on_change` with whether `query` matches, now and whenever that
+/// changes, for as long as the returned future is polled.
+pub(super) async fn watch_media(query: &str, mut on_change: impl FnMut(bool) + 'static) {
+    if WEB {
+        let _listener = dom::MediaListener::new(query, on_change);
+        return std::future::pending().await;
+    }
+    let mut eval = eval_with(
+        r#"
+        const [query] = await dioxus.recv();
+        const list = window.matchMedia(query);
+        dioxus.send(list.matches);
+        list.addEventListener("change", () => dioxus.send(list.matches));
+        await new Promise(() => {});
+        "#,
+        &[query],
+    );
+    while let Ok(matches) = eval.recv::<bool>().await {
+        on_change(matches);
+    }
+}
+
+/// The property whose transition ended on the element mounted as `element`,
+/// or `None` when the event bubbled up from inside it. The webview renderers
+/// expose neither, so there every transition counts as `fallback`.
+pub(super) fn transition_end(
+    event: &TransitionData,
+    element: &Option<Rc<MountedData>>,
+    fallback: &str,
+) -> Option<String> {
+    let Some(event) = event.downcast::<web_sys::TransitionEvent>() else {
+        return Some(fallback.to_string());
+    };
+    let element = element.as_ref()?.downcast::<web_sys::Element>()?;
+    (event.target().as_ref() == Some(element.as_ref())).then(|| event.property_name())
+}
+
+/// Reloads the current page.
+pub(super) fn reload() -> Result<(), DomError> {
+    if WEB {
+        return dom::reload();
+    }
+    let _ = document::eval("window.location.reload();");
+    Ok(())
+}
+
+/// Selects the text of the input mounted as `input` with the id `id`.
+pub(super) fn select_input(input: &Option<Rc<MountedData>>, id: &str) {
+    if WEB {
+        if let Some(input) = input_element(input) {
+            input.select();
+        }
+        return;
+    }
+    let _ = eval_with(
+        "const [id] = await dioxus.recv(); document.getElementById(id)?.select();",
+        &[id],
+    );
+}
+
+/// Sets the value of the input with the id `id` and selects from `start` to
+/// `end`, counted in UTF-16 code units.
+pub(super) fn complete_input(
+    input: &Option<Rc<MountedData>>,
+    id: &str,
+    value: &str,
+    start: usize,
+    end: usize,
+) {
+    if WEB {
+        if let Some(input) = input_element(input) {
+            input.set_value(value);
+            let _ = input.set_selection_range(start as u32, end as u32);
+        }
+        return;
+    }
+    let (start, end) = (start.to_string(), end.to_string());
+    let _ = eval_with(
+        r#"
+        const [id, value, start, end] = await dioxus.recv();
+        const input = document.getElementById(id);
+        if (input) {
+            input.value = value;
+            input.setSelectionRange(Number(start), Number(end));
+        }
+        "#,
+        &[id, value, &start, &end],
+    );
+}
+
+/// Moves focus to the element with the id `id`, selecting its text when it
+/// is an input.
+pub(super) fn focus_by_id(id: &str) {
+    if WEB {
+        dom::page::focus_by_id(id);
+        return;
+    }
+    let _ = eval_with(
+        r#"
+        const [id] = await dioxus.recv();
+        const element = document.getElementById(id);
+        element?.focus();
+        if (element instanceof HTMLInputElement) element.select();
+        "#,
+        &[id],
+    );
+}
+
+/// Moves focus to the find bar input inside `frame` and selects its text.
+pub(super) fn focus_search_input(frame: &Option<Rc<MountedData>>) {
+    if WEB {
+        if let Some(input) = frame
+            .as_ref()
+            .and_then(|frame| frame.downcast::<web_sys::Element>())
+            .and_then(|frame| frame.query_selector("input[type='search']").ok().flatten())
+            .and_then(|input| input.dyn_into::<HtmlInputElement>().ok())
+        {
+            let _ = input.focus();
+            input.select();
+        }
+        return;
+    }
+    // The shortcut asking for the find bar was pressed inside the frame, so
+    // the frame is found from the focused element.
+    let _ = document::eval(
+        r#"
+        const input = document.activeElement
+            ?.closest('[role="application"]')
+            ?.querySelector('input[type="search"]');
+        input?.focus();
+        input?.select();
+        "#,
+    );
+}
+
+/// Offset in pixels of the element with the id `id` from its offset parent.
+pub(super) async fn offset_left(id: &str) -> i32 {
+    if WEB {
+        return element_by_id(id).map_or(0, |element| element.offset_left());
+    }
+    eval_with(
+        "const [id] = await dioxus.recv(); return document.getElementById(id)?.offsetLeft ?? 0;",
+        &[id],
+    )
+    .join::<i32>()
+    .await
+    .unwrap_or(0)
+}
+
+/// How many children of the element with the id `id` fit within its right
+/// edge, or `None` when it is not in the document.
+pub(super) async fn fitting_children(id: &str) -> Option<usize> {
+    if WEB {
+        return element_by_id(id).map(|list| dom::page::fitting_children(&list));
+    }
+    eval_with(
+        r#"
+        const [id] = await dioxus.recv();
+        const list = document.getElementById(id);
+        if (!list) return null;
+        const limit = list.getBoundingClientRect().right + 0.5;
+        let count = 0;
+        for (const child of list.children) {
+            if (child.getBoundingClientRect().right > limit) break;
+            count += 1;
+        }
+        return count;
+        "#,
+        &[id],
+    )
+    .join::<Option<usize>>()
+    .await
+    .ok()
+    .flatten()
+}
+
+/// Points the first `iframe` inside the mounted `frame`, whose id is `id`, at
+/// `url`.
+pub(super) fn navigate_iframe(frame: &Option<Rc<MountedData>>, id: &str, url: &str) {
+    if WEB {
+        if let Some(iframe) = frame
+            .as_ref()
+            .and_then(|frame| frame.downcast::<web_sys::Element>())
+            .and_then(|frame| frame.query_selector("iframe").ok().flatten())
+            .and_then(|iframe| iframe.dyn_into::<HtmlIFrameElement>().ok())
+        {
+            iframe.set_src(url);
+        }
+        return;
+    }
+    let _ = eval_with(
+        r#"
+        const [id, url] = await dioxus.recv();
+        const iframe = document.getElementById(id)?.querySelector("iframe");
+        if (iframe) iframe.src = url;
+        "#,
+        &[id, url],
+    );
+}
+
+/// Resolves after `millis` milliseconds.
+pub(super) async fn sleep(millis: u32) {
+    if WEB {
+        gloo_timers::future::sleep(Duration::from_millis(millis.into())).await;
+        return;
+    }
+    let _ = eval_with(
+        "const [millis] = await dioxus.recv(); await new Promise((done) => setTimeout(done, Number(millis)));",
+        &[&millis.to_string()],
+    )
+    .await;
+}
+
+/// Shares the page through `navigator.share`, resolving to `None` when the
+/// browser has no share sheet and to `Some(false)` when sharing failed for a
+/// reason other than the user dismissing it.
+pub(super) async fn share(url: String, title: String) -> Option<bool> {
+    if !WEB {
+        return eval_with(
+            r#"
+            const [url, title] = await dioxus.recv();
+            if (!navigator.share) return null;
+            try {
+                await navigator.share(title ? { url, title } : { url });
+                return true;
+            } catch (error) {
+                return error.name === "AbortError" ? null : false;
+            }
+            "#,
+            &[&url, &title],
+        )
+        .join::<Option<bool>>()
+        .await
+        .ok()
+        .flatten();
+    }
+
+    dom::page::share(url, title).await
+}
+
+/// Writes `text` to the clipboard, resolving to whether it succeeded.
+pub(super) async fn copy_text(text: String) -> bool {
+    if !WEB {
+        return eval_with(
+            "const [text] = await dioxus.recv(); await navigator.clipboard.writeText(text); return true;",
+            &[&text],
+        )
+        .join::<bool>()
+        .await
+        .unwrap_or(false);
+    }
+
+    dom::page::copy_text(text).await
+}
+
+/// Name under which a webview listener stores its removal on `window`, so it
+/// can be removed again with [`stop_listening`]. Removing a listener also
+/// ends its eval, closing the channel it sends on.
+pub(super) fn listener_name() -> String {
+    static NEXT: AtomicUsize = AtomicUsize::new(0);
+    format!(
+        "__browserRsListener{}",
+        NEXT.fetch_add(1, Ordering::Relaxed)
+    )
+}
+
+/// Forwards every `Escape` keydown on the webview window as a message,
+/// preventing its default action.
+pub(super) fn listen_for_escape(name: &str) -> document::Eval {
+    eval_with(
+        r#"
+        const [name] = await dioxus.recv();
+        const listener = (event) => {
+            if (event.key === "Escape") {
+                event.preventDefault();
+                dioxus.send(event.key);
+            }
+        };
+        window.addEventListener("keydown", listener);
+        await new Promise((resolve) => {
+            window[name] = () => {
+                window.removeEventListener("keydown", listener);
+                resolve();
+            };
+        });
+        "#,
+        &[name],
+    )
+}
+
+/// Forwards the title and icon URL of every same-origin iframe that finishes
+/// loading inside the element with the id `id`. Cross-origin documents are
+/// not accessible and are skipped.
+pub(super) fn listen_for_page_info(name: &str, id: &str) -> document::Eval {
+    eval_with(
+        r#"
+        const [name, id] = await dioxus.recv();
+        const container = document.getElementById(id);
+        if (!container) return;
+        // `load` does not bubble, so listen in the capture phase.
+        const listener = (event) => {
+            if (!(event.target instanceof HTMLIFrameElement)) return;
+            let page;
+            try {
+                page = event.target.contentDocument;
+            } catch {
+                return;
+            }
+            if (!page) return;
+            const icon = page.querySelector("link[rel~='icon']");
+            dioxus.send([page.title, icon ? icon.href : null]);
+        };
+        container.addEventListener("load", listener, true);
+        await new Promise((resolve) => {
+            window[name] = () => {
+                container.removeEventListener("load", listener, true);
+                resolve();
+            };
+        });
+        "#,
+        &[name, id],
+    )
+}
+
+/// Removes the listener stored under `name`.
+pub(super) fn stop_listening(name: &str) {
+    let _ = eval_with(
+        r#"
+        const [name] = await dioxus.recv();
+        if (window[name]) {
+            window[name]();
+            delete window[name];
+        }
+        "#,
+        &[name],
+    );
+}
+
+/// Sends a message whenever the webview window is resized, until the
+/// listener stored under `name` is stopped.
+pub(super) fn watch_resize(name: &str) -> document::Eval {
+    eval_with(
+        r#"
+        const [name] = await dioxus.recv();
+        const listener = () => dioxus.send(true);
+        window.addEventListener("resize", listener);
+        await new Promise((resolve) => {
+            window[name] = () => {
+                window.removeEventListener("resize", listener);
+                resolve();
+            };
+        });
+        "#,
+        &[name],
+    )
+}
@@ -0,0 +1,272 @@
+//! Fallible DOM access shared by the components.
+//!
+//! The helpers return a [`DomError`] where a raw `web_sys` call would need an
+//! `unwrap()`, so a missing global or a throwing call is reported through the
+//! components' `on_error` callbacks instead of aborting the app.
+
+use crate::common::{
+    DomError,
+    animation::REDUCED_MOTION_QUERY,
+    announcer::{Politeness, region_id},
+    keyboard::{KeyInput, toolbar_index},
+    style::VISUALLY_HIDDEN,
+};
+use gloo_timers::callback::Timeout;
+use std::cell::RefCell;
+use web_sys::{
+    Document, Element, Event, EventTarget, HtmlElement, KeyboardEvent, MediaQueryList, Node,
+    NodeList, ShadowRoot, Window, js_sys,
+    wasm_bindgen::{JsCast, JsValue, closure::Closure},
+};
+
+pub(crate) mod page;
+
+/// Converts a thrown JavaScript value into a [`DomError::Js`].
+pub(crate) fn js_error(operation: &'static str, error: JsValue) -> DomError {
+    let message = error
+        .dyn_ref::<js_sys::Error>()
+        .map(|error| String::from(error.message()))
+        .or_else(|| error.as_string())
+        .unwrap_or_else(|| format!("{error:?}"));
+    DomError::Js { operation, message }
+}
+
+pub(crate) fn window() -> Result<Window, DomError> {
+    web_sys::window().ok_or(DomError::NoWindow)
+}
+
+pub(crate) fn document() -> Result<Document, DomError> {
+    window()?.document().ok_or(DomError::NoDocument)
+}
+
+pub(crate) fn create_element(document: &Document, tag: &str) -> Result<Element, DomError> {
+    document
+        .create_element(tag)
+        .map_err(|error| js_error("createElement", error))
+}
+
+pub(crate) fn set_attribute(element: &Element, name: &str, value: &str) -> Result<(), DomError> {
+    element
+        .set_attribute(name, value)
+        .map_err(|error| js_error("setAttribute", error))
+}
+
+pub(crate) fn query_selector_all(element: &Element, selector: &str) -> Result<NodeList, DomError> {
+    element
+        .query_selector_all(selector)
+        .map_err(|error| js_error("querySelectorAll", error))
+}
+
+pub(crate) fn add_event_listener(
+    target: &EventTarget,
+    event: &str,
+    listener: &js_sys::Function,
+) -> Result<(), DomError> {
+    target
+        .add_event_listener_with_callback(event, listener)
+        .map_err(|error| js_error("addEventListener", error))
+}
+
+pub(crate) fn remove_event_listener(
+    target: &EventTarget,
+    event: &str,
+    listener: &js_sys::Function,
+) -> Result<(), DomError> {
+    target
+        .remove_event_listener_with_callback(event, listener)
+        .map_err(|error| js_error("removeEventListener", error))
+}
+
+/// Keeps `Tab` and `Shift+Tab` focus cycling inside `container`.
+pub(crate) fn trap_focus(event: &KeyboardEvent, container: &Element) -> Result<(), DomError> {
+    let selector = "button, [href], input, select, textarea, [tabindex]:not([tabindex=\"-1\"])";
+    let focusables = query_selector_all(container, selector)?;
+
+    let length = focusables.length();
+    if length == 0 {
+        return Ok(());
+    }
+
+    let first = focusables
+        .item(0)
+        .and_then(|e| e.dyn_into::<HtmlElement>().ok());
+    let last = focusables
+        .item(length - 1)
+        .and_then(|e| e.dyn_into::<HtmlElement>().ok());
+    let active = active_element(container)?;
+
+    let (boundary, wrap_to) = if event.shift_key() {
+        (first, last)
+    } else {
+        (last, first)
+    };
+    if active.is_some() && active == boundary.map(Element::from) {
+        event.prevent_default();
+        if let Some(element) = wrap_to {
+            element.focus().map_err(|error| js_error("focus", error))?;
+        }
+    }
+    Ok(())
+}
+
+/// The focused element in the tree of `node`. Inside a shadow root the
+/// document only reports the host.
+fn active_element(node: &Node) -> Result<Option<Element>, DomError> {
+    match node.get_root_node().dyn_into::<ShadowRoot>() {
+        Ok(shadow) => Ok(shadow.active_element()),
+        Err(_) => Ok(document()?.active_element()),
+    }
+}
+
+/// Whether `event` started inside `container`, including in shadow roots
+/// below it, where `event.target()` only shows their host.
+pub(crate) fn started_inside(event: &Event, container: &Element) -> bool {
+    event.composed_path().includes(container, 0)
+}
+
+/// Where `event` was dispatched, also for listeners outside the shadow root
+/// it came from, for which `event.target()` is the host.
+pub(crate) fn origin(event: &Event) -> Option<EventTarget> {
+    event.composed_path().get(0).dyn_into().ok()
+}
+
+fn is_target(element: &HtmlElement, target: &EventTarget) -> bool {
+    AsRef::<EventTarget>::as_ref(element) == target
+}
+
+/// Enabled buttons of `toolbar`, leaving out those inside its popups.
+fn toolbar_items(toolbar: &Element) -> Result<Vec<HtmlElement>, DomError> {
+    let buttons = query_selector_all(toolbar, "button:not([disabled])")?;
+    Ok((0..buttons.length())
+        .filter_map(|index| buttons.item(index)?.dyn_into::<HtmlElement>().ok())
+        .filter(|button| {
+            button
+                .closest("[role='menu'], [role='dialog'], [role='listbox']")
+                .ok()
+                .flatten()
+                .is_none()
+        })
+        .collect())
+}
+
+/// Leaves a single tab stop in `toolbar`: the item `active` when it is one,
+/// else the current tab stop, else the first item.
+pub(crate) fn sync_roving_tabindex(
+    toolbar: &Element,
+    active: Option<&EventTarget>,
+) -> Result<(), DomError> {
+    let items = toolbar_items(toolbar)?;
+    let stop = active
+        .and_then(|active| items.iter().position(|item| is_target(item, active)))
+        .or_else(|| items.iter().position(|item| item.tab_index() == 0))
+        .unwrap_or(0);
+    for (index, item) in items.iter().enumerate() {
+        set_attribute(item, "tabindex", if index == stop { "0" } else { "-1" })?;
+    }
+    Ok(())
+}
+
+/// Moves focus and the tab stop of `toolbar` for an arrow, `Home` or `End`
+/// key pressed on one of its items.
+pub(crate) fn rove_toolbar(event: &KeyboardEvent, toolbar: &Element) -> Result<(), DomError> {
+    let items = toolbar_items(toolbar)?;
+    let Some(current) = event
+        .target()
+        .and_then(|target| items.iter().position(|item| is_target(item, &target)))
+    else {
+        return Ok(());
+    };
+    let key = event.key();
+    let input = KeyInput::new(&key)
+        .with_ctrl(event.ctrl_key())
+        .with_meta(event.meta_key())
+        .with_alt(event.alt_key());
+    let Some(next) = toolbar_index(&input, current, items.len()) else {
+        return Ok(());
+    };
+    event.prevent_default();
+    sync_roving_tabindex(toolbar, Some(items[next].as_ref()))?;
+    items[next]
+        .focus()
+        .map_err(|error| js_error("focus", error))
+}
+
+fn match_media(query: &str) -> Option<MediaQueryList> {
+    web_sys::window()?.match_media(query).ok().flatten()
+}
+
+/// Whether the user asked the system for reduced motion. `false` when the
+/// browser can't tell.
+pub(crate) fn prefers_reduced_motion() -> bool {
+    match_media(REDUCED_MOTION_QUERY).is_some_and(|query| query.matches())
+}
+
+/// Reports whether a media query matches, now and on every change, until
+/// dropped.
+pub(crate) struct MediaListener {
+    query: MediaQueryList,
+    closure: Closure<dyn FnMut()>,
+}
+
+impl MediaListener {
+    /// Calls `on_change` with the current result of `query` and again when
+    /// it changes. `None` when the browser can't evaluate the query.
+    pub(crate) fn new(query: &str, mut on_change: impl FnMut(bool) + 'static) -> Option<Self> {
+        let query = match_media(query)?;
+        on_change(query.matches());
+        let changed = query.clone();
+        let closure = Closure::<dyn FnMut()>::new(move || on_change(changed.matches()));
+        add_event_listener(&query, "change", closure.as_ref().unchecked_ref()).ok()?;
+        Some(Self { query, closure })
+    }
+}
+
+impl Drop for MediaListener {
+    fn drop(&mut self) {
+        let _ = remove_event_listener(&self.query, "change", self.closure.as_ref().unchecked_ref());
+    }
+}
+
+/// Reloads the current page.
+pub(crate) fn reload() -> Result<(), DomError> {
+    window()?
+        .location()
+        .reload()
+        .map_err(|error| js_error("location.reload", error))
+}
+
+thread_local! {
+    /// Message waiting to be written into each live region. Replacing it
+    /// drops the timer, so a newer message wins over one not yet read.
+    static PENDING: RefCell<[Option<Timeout>; 2]> = const { RefCell::new([None, None]) };
+}
+
+/// The live region for `politeness`, added to `body` the first time.
+fn live_region(document: &Document, politeness: Politeness) -> Result<Element, DomError> {
+    let id = region_id(politeness);
+    if let Some(region) = document.get_element_by_id(id) {
+        return Ok(region);
+    }
+    let body = document.body().ok_or(DomError::NoBody)?;
+    let region = create_element(document, "div")?;
+    set_attribute(&region, "id", id)?;
+    set_attribute(&region, "aria-live", &politeness.to_string())?;
+    set_attribute(&region, "aria-atomic", "true")?;
+    set_attribute(&region, "style", VISUALLY_HIDDEN)?;
+    body.append_child(&region)
+        .map_err(|error| js_error("appendChild", error))?;
+    Ok(region)
+}
+
+/// Announces `message` through the persistent live region for `politeness`.
+///
+/// The region is emptied first and filled shortly after, so a repeated
+/// message is read again.
+pub(crate) fn announce(message: &str, politeness: Politeness) -> Result<(), DomError> {
+    let region = live_region(&document()?, politeness)?;
+    region.set_text_content(None);
+    let message = message.to_string();
+    let timeout = Timeout::new(100, move || region.set_text_content(Some(&message)));
+    PENDING.with(|pending| pending.borrow_mut()[politeness as usize] = Some(timeout));
+    Ok(())
+}
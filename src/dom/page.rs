@@ -0,0 +1,233 @@
+//! Page access behind the share menu, bookmarks bar and find bar.
+
+use super::document;
+use crate::common::{
+    find::{current_highlight, match_highlight},
+    find_matches,
+};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{
+    Element, Event, HtmlElement, HtmlIFrameElement, HtmlInputElement, HtmlLinkElement, Node, Range,
+    js_sys, wasm_bindgen::JsCast,
+};
+
+/// Moves focus to the element with the id `id`, selecting its text when it
+/// is an input.
+pub(crate) fn focus_by_id(id: &str) {
+    if let Some(element) = document()
+        .ok()
+        .and_then(|document| document.get_element_by_id(id))
+        .and_then(|element| element.dyn_into::<HtmlElement>().ok())
+    {
+        let _ = element.focus();
+        if let Some(input) = element.dyn_ref::<HtmlInputElement>() {
+            input.select();
+        }
+    }
+}
+
+/// How many children of `list` fit within its right edge, counted from the
+/// start until the first one that overflows.
+pub(crate) fn fitting_children(list: &Element) -> usize {
+    let limit = list.get_bounding_client_rect().right() + 0.5;
+    let children = list.children();
+    (0..children.length())
+        .filter_map(|index| children.item(index))
+        .take_while(|child| child.get_bounding_client_rect().right() <= limit)
+        .count()
+}
+
+/// Shares the page through `navigator.share`, resolving to `None` when the
+/// browser has no share sheet and to `Some(false)` when sharing failed for a
+/// reason other than the user dismissing it.
+pub(crate) async fn share(url: String, title: String) -> Option<bool> {
+    let navigator = web_sys::window()?.navigator();
+    if !js_sys::Reflect::has(&navigator, &"share".into()).unwrap_or(false) {
+        return None;
+    }
+
+    let data = web_sys::ShareData::new();
+    data.set_url(&url);
+    if !title.is_empty() {
+        data.set_title(&title);
+    }
+
+    match JsFuture::from(navigator.share_with_data(&data)).await {
+        Ok(_) => Some(true),
+        Err(error) => {
+            let dismissed = js_sys::Reflect::get(&error, &"name".into())
+                .ok()
+                .and_then(|name| name.as_string())
+                .is_some_and(|name| name == "AbortError");
+            (!dismissed).then_some(false)
+        }
+    }
+}
+
+/// Writes `text` to the clipboard, resolving to whether it succeeded.
+pub(crate) async fn copy_text(text: String) -> bool {
+    let Some(window) = web_sys::window() else {
+        return false;
+    };
+    JsFuture::from(window.navigator().clipboard().write_text(&text))
+        .await
+        .is_ok()
+}
+
+/// Reads the title and icon of a same-origin iframe that finished loading.
+///
+/// Returns `None` when the event did not come from an iframe or when the
+/// iframe document is cross-origin and therefore not accessible.
+pub(crate) fn read_page_info(event: &Event) -> Option<(String, Option<String>)> {
+    let frame = event.target()?.dyn_into::<HtmlIFrameElement>().ok()?;
+    let document = frame.content_document()?;
+    let favicon = document
+        .query_selector("link[rel~='icon']")
+        .ok()
+        .flatten()
+        .and_then(|link| link.dyn_into::<HtmlLinkElement>().ok())
+        .map(|link| link.href());
+    Some((document.title(), favicon))
+}
+
+fn collect_text_nodes(node: &Node, nodes: &mut Vec<Node>) {
+    if node.node_type() == Node::TEXT_NODE {
+        nodes.push(node.clone());
+        return;
+    }
+    if matches!(
+        node.node_name().as_str(),
+        "SCRIPT" | "STYLE" | "NOSCRIPT" | "TEMPLATE"
+    ) {
+        return;
+    }
+    let children = node.child_nodes();
+    for index in 0..children.length() {
+        if let Some(child) = children.item(index) {
+            collect_text_nodes(&child, nodes);
+        }
+    }
+}
+
+/// Builds a DOM range for every match of `query` in the text under `root`,
+/// including matches that span several text nodes.
+pub(crate) fn find_ranges(root: &Node, query: &str) -> Vec<Range> {
+    let mut nodes = Vec::new();
+    collect_text_nodes(root, &mut nodes);
+
+    let mut text = String::new();
+    let mut starts = Vec::with_capacity(nodes.len());
+    let mut length = 0;
+    for node in &nodes {
+        let data = node.node_value().unwrap_or_default();
+        starts.push(length);
+        length += data.encode_utf16().count();
+        text.push_str(&data);
+    }
+
+    let Ok(document) = document() else {
+        return Vec::new();
+    };
+    find_matches(&text, query)
+        .into_iter()
+        .filter_map(|(start, end)| {
+            let first = starts.partition_point(|&s| s <= start) - 1;
+            let last = starts.partition_point(|&s| s < end) - 1;
+            let range = document.create_range().ok()?;
+            range
+                .set_start(&nodes[first], (start - starts[first]) as u32)
+                .ok()?;
+            range
+                .set_end(&nodes[last], (end - starts[last]) as u32)
+                .ok()?;
+            Some(range)
+        })
+        .collect()
+}
+
+/// The `CSS.highlights` registry, or `None` when the browser does not support
+/// the CSS Custom Highlight API.
+fn highlight_registry() -> Option<js_sys::Object> {
+    let window = web_sys::window()?;
+    js_sys::Reflect::get(&window, &"CSS".into())
+        .and_then(|css| js_sys::Reflect::get(&css, &"highlights".into()))
+        .ok()?
+        .dyn_into()
+        .ok()
+}
+
+/// Calls the registry method `method` with `args`.
+fn call_registry(registry: &js_sys::Object, method: &str, args: &js_sys::Array) -> bool {
+    js_sys::Reflect::get(registry, &method.into())
+        .and_then(|method| method.dyn_into::<js_sys::Function>())
+        .and_then(|method| method.apply(registry, args))
+        .is_ok()
+}
+
+/// Registers `ranges` with the CSS Custom Highlight API under `name`, returning
+/// `false` when the browser does not support it.
+fn set_highlight(name: &str, ranges: &[&Range]) -> bool {
+    let Some(highlights) = highlight_registry() else {
+        return false;
+    };
+    let Some(constructor) = web_sys::window()
+        .and_then(|window| js_sys::Reflect::get(&window, &"Highlight".into()).ok())
+        .and_then(|constructor| constructor.dyn_into::<js_sys::Function>().ok())
+    else {
+        return false;
+    };
+
+    let args: js_sys::Array = ranges.iter().copied().collect();
+    let Ok(highlight) = js_sys::Reflect::construct(&constructor, &args) else {
+        return false;
+    };
+    call_registry(
+        &highlights,
+        "set",
+        &js_sys::Array::of2(&name.into(), &highlight),
+    )
+}
+
+/// Removes the highlights of the find bar with the id `id`, leaving those of
+/// other find bars on the page in place.
+pub(crate) fn clear_find_highlights(id: &str) {
+    let Some(highlights) = highlight_registry() else {
+        return;
+    };
+    for name in [match_highlight(id), current_highlight(id)] {
+        let _ = call_registry(&highlights, "delete", &js_sys::Array::of1(&name.into()));
+    }
+}
+
+/// Highlights every match and the one at `current` for the find bar with the
+/// id `id`, scrolling the current match into view. Without highlight support
+/// it is selected instead.
+pub(crate) fn show_find_matches(id: &str, ranges: &[Range], current: Option<usize>) {
+    let all: Vec<&Range> = ranges.iter().collect();
+    let current = current.and_then(|index| ranges.get(index));
+    let supported = set_highlight(&match_highlight(id), &all)
+        && set_highlight(
+            &current_highlight(id),
+            &current.into_iter().collect::<Vec<_>>(),
+        );
+
+    let Some(range) = current else {
+        return;
+    };
+    if !supported {
+        if let Some(selection) = web_sys::window().and_then(|w| w.get_selection().ok().flatten()) {
+            let _ = selection.remove_all_ranges();
+            let _ = selection.add_range(range);
+        }
+    }
+    let element = range.start_container().ok().and_then(|node| {
+        node.dyn_ref::<Element>()
+            .cloned()
+            .or_else(|| node.parent_element())
+    });
+    if let Some(element) = element {
+        let options = web_sys::ScrollIntoViewOptions::new();
+        options.set_block(web_sys::ScrollLogicalPosition::Nearest);
+        element.scroll_into_view_with_scroll_into_view_options(&options);
+    }
+}
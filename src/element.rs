@@ -0,0 +1,314 @@
+#![doc = include_str!("../ELEMENT.md")]
+
+use crate::common::{DomError, Size, Variant};
+use crate::dom;
+use crate::yew::{BrowserFrame, ToolbarItem};
+use std::cell::RefCell;
+use web_sys::{
+    CustomEvent, CustomEventInit, Element, HtmlElement, MutationObserver, MutationObserverInit,
+    ShadowRootInit, ShadowRootMode, js_sys,
+    wasm_bindgen::{JsCast, JsValue, prelude::*},
+};
+use yew::AppHandle;
+use yew::prelude::*;
+
+/// Tag name registered by [`define`].
+pub const TAG_NAME: &str = "browser-frame";
+
+/// Attributes mapped to `BrowserFrameProps`; changing one re-renders the frame.
+pub const OBSERVED_ATTRIBUTES: [&str; 4] = ["url", "variant", "size", "read-only"];
+
+/// Prefix of the slots that add toolbar buttons, e.g. `slot="toolbar-star"`
+/// adds a button with the id `star`.
+pub const TOOLBAR_SLOT_PREFIX: &str = "toolbar-";
+
+#[wasm_bindgen(wasm_bindgen = web_sys::wasm_bindgen, inline_js = r#"
+export function define_element(name, observed, connected, disconnected, changed) {
+    if (customElements.get(name)) {
+        return;
+    }
+    customElements.define(name, class extends HTMLElement {
+        static get observedAttributes() {
+            return observed;
+        }
+        connectedCallback() {
+            connected(this);
+        }
+        disconnectedCallback() {
+            disconnected(this);
+        }
+        attributeChangedCallback(_name, oldValue, newValue) {
+            if (oldValue !== newValue && this.isConnected) {
+                changed(this);
+            }
+        }
+    });
+}
+"#)]
+extern "C" {
+    #[wasm_bindgen(catch)]
+    fn define_element(
+        name: &str,
+        observed: js_sys::Array,
+        connected: &js_sys::Function,
+        disconnected: &js_sys::Function,
+        changed: &js_sys::Function,
+    ) -> Result<(), JsValue>;
+}
+
+/// A connected element and the frame rendered into its shadow root.
+struct Mounted {
+    host: HtmlElement,
+    handle: AppHandle<ElementFrame>,
+    props: ElementFrameProps,
+    /// Watches the `toolbar-*` children, which `slotchange` misses when the
+    /// matching slot is not rendered yet.
+    observer: MutationObserver,
+    _on_mutation: Closure<dyn Fn()>,
+}
+
+thread_local! {
+    static FRAMES: RefCell<Vec<Mounted>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Where a URL or text handed to the host came from.
+#[derive(Clone, Copy)]
+enum Navigation {
+    Bookmark,
+    AddressBar,
+}
+
+/// Events fired for `source`. Address bar submits are also `submit` events
+/// for hosts that treat the text as a search term.
+fn navigation_events(source: Navigation) -> &'static [&'static str] {
+    match source {
+        Navigation::Bookmark => &["navigate"],
+        Navigation::AddressBar => &["navigate", "submit"],
+    }
+}
+
+/// Registers the `<browser-frame>` custom element.
+///
+/// Call it once from the wasm entry point; later calls do nothing. Each
+/// element renders a `BrowserFrame` into its shadow root and fires `close`,
+/// `minimize`, `maximize`, `navigate`, `submit` and `toolbar-click`
+/// `CustomEvent`s. Adding, removing or relabeling `toolbar-*` children
+/// updates the toolbar.
+pub fn define() -> Result<(), DomError> {
+    let connected = Closure::<dyn Fn(HtmlElement)>::new(|host: HtmlElement| {
+        if let Err(error) = mount(&host) {
+            dispatch(&host, "error", &error.to_string().into());
+        }
+    });
+    let disconnected = Closure::<dyn Fn(HtmlElement)>::new(|host: HtmlElement| unmount(&host));
+    let changed = Closure::<dyn Fn(HtmlElement)>::new(|host: HtmlElement| update(&host));
+
+    let observed = OBSERVED_ATTRIBUTES
+        .iter()
+        .map(|name| JsValue::from_str(name))
+        .collect::<js_sys::Array>();
+    define_element(
+        TAG_NAME,
+        observed,
+        connected.as_ref().unchecked_ref(),
+        disconnected.as_ref().unchecked_ref(),
+        changed.as_ref().unchecked_ref(),
+    )
+    .map_err(|error| dom::js_error("customElements.define", error))?;
+
+    // The element class keeps calling these for the lifetime of the page.
+    connected.forget();
+    disconnected.forget();
+    changed.forget();
+    Ok(())
+}
+
+fn mount(host: &HtmlElement) -> Result<(), DomError> {
+    unmount(host);
+    let shadow = match host.shadow_root() {
+        Some(shadow) => shadow,
+        None => host
+            .attach_shadow(&ShadowRootInit::new(ShadowRootMode::Open))
+            .map_err(|error| dom::js_error("attachShadow", error))?,
+    };
+    shadow.set_inner_html("");
+    let root = dom::create_element(&dom::document()?, "div")?;
+    shadow
+        .append_child(&root)
+        .map_err(|error| dom::js_error("appendChild", error))?;
+
+    let on_mutation = Closure::<dyn Fn()>::new({
+        let host = host.clone();
+        move || update(&host)
+    });
+    let observer = MutationObserver::new(on_mutation.as_ref().unchecked_ref())
+        .map_err(|error| dom::js_error("MutationObserver", error))?;
+    let init = MutationObserverInit::new();
+    init.set_child_list(true);
+    init.set_attribute_filter(&js_sys::Array::of3(
+        &"slot".into(),
+        &"aria-label".into(),
+        &"title".into(),
+    ));
+    init.set_subtree(true);
+    observer
+        .observe_with_options(host, &init)
+        .map_err(|error| dom::js_error("MutationObserver.observe", error))?;
+
+    let props = props_from(host);
+    let handle = yew::Renderer::<ElementFrame>::with_root_and_props(root, props.clone()).render();
+    FRAMES.with(|frames| {
+        frames.borrow_mut().push(Mounted {
+            host: host.clone(),
+            handle,
+            props,
+            observer,
+            _on_mutation: on_mutation,
+        })
+    });
+    Ok(())
+}
+
+/// Re-renders the frame of `host` if its attributes or toolbar children
+/// changed. Mutations elsewhere in the content are ignored.
+fn update(host: &HtmlElement) {
+    let props = props_from(host);
+    FRAMES.with(|frames| {
+        if let Some(mounted) = frames.borrow_mut().iter_mut().find(|m| m.host == *host) {
+            if mounted.props != props {
+                mounted.props = props.clone();
+                mounted.handle.update(props);
+            }
+        }
+    });
+}
+
+fn unmount(host: &HtmlElement) {
+    let mounted = FRAMES.with(|frames| {
+        let mut frames = frames.borrow_mut();
+        let index = frames.iter().position(|m| m.host == *host)?;
+        Some(frames.remove(index))
+    });
+    if let Some(mounted) = mounted {
+        mounted.observer.disconnect();
+        mounted.handle.destroy();
+    }
+}
+
+fn props_from(host: &HtmlElement) -> ElementFrameProps {
+    let attribute = |name| host.get_attribute(name);
+    let children = host.children();
+    let toolbar = (0..children.length())
+        .filter_map(|index| children.item(index))
+        .filter_map(|child| {
+            let id = child
+                .get_attribute("slot")?
+                .strip_prefix(TOOLBAR_SLOT_PREFIX)?
+                .to_string();
+            let label = child
+                .get_attribute("aria-label")
+                .or_else(|| child.get_attribute("title"))
+                .unwrap_or_else(|| id.clone());
+            Some((id, label))
+        })
+        .collect();
+
+    ElementFrameProps {
+        host: host.clone(),
+        url: attribute("url").unwrap_or_default(),
+        variant: attribute("variant")
+            .and_then(|value| value.parse().ok())
+            .unwrap_or_default(),
+        size: attribute("size")
+            .and_then(|value| value.parse().ok())
+            .unwrap_or_default(),
+        read_only: host.has_attribute("read-only"),
+        toolbar,
+    }
+}
+
+fn dispatch(host: &Element, name: &str, detail: &JsValue) {
+    let init = CustomEventInit::new();
+    init.set_bubbles(true);
+    init.set_detail(detail);
+    if let Ok(event) = CustomEvent::new_with_event_init_dict(name, &init) {
+        let _ = host.dispatch_event(&event);
+    }
+}
+
+#[derive(Properties, PartialEq, Clone)]
+struct ElementFrameProps {
+    host: HtmlElement,
+    url: String,
+    variant: Variant,
+    size: Size,
+    read_only: bool,
+    /// `(id, label)` of each `toolbar-*` slot.
+    toolbar: Vec<(String, String)>,
+}
+
+#[function_component(ElementFrame)]
+fn element_frame(props: &ElementFrameProps) -> Html {
+    let event = |name: &'static str| {
+        let host = props.host.clone();
+        Callback::from(move |_: ()| dispatch(&host, name, &JsValue::NULL))
+    };
+    let navigation = |source: Navigation| {
+        let host = props.host.clone();
+        Callback::from(move |url: String| {
+            let detail = JsValue::from_str(&url);
+            for name in navigation_events(source) {
+                dispatch(&host, name, &detail);
+            }
+        })
+    };
+    let on_error = {
+        let host = props.host.clone();
+        Callback::from(move |error: DomError| dispatch(&host, "error", &error.to_string().into()))
+    };
+    let toolbar_items = props
+        .toolbar
+        .iter()
+        .map(|(id, label)| {
+            let host = props.host.clone();
+            let detail = JsValue::from_str(id);
+            ToolbarItem::new(id.clone(), label.clone())
+                .with_icon(html! { <slot name={format!("{TOOLBAR_SLOT_PREFIX}{id}")} /> })
+                .with_on_click(Callback::from(move |_| {
+                    dispatch(&host, "toolbar-click", &detail)
+                }))
+        })
+        .collect::<Vec<_>>();
+
+    html! {
+        <BrowserFrame
+            url={props.url.clone()}
+            variant={props.variant.clone()}
+            size={props.size.clone()}
+            read_only={props.read_only}
+            toolbar_items={toolbar_items}
+            on_close={event("close")}
+            on_minimize={event("minimize")}
+            on_maximize={event("maximize")}
+            on_navigate={navigation(Navigation::Bookmark)}
+            on_submit={navigation(Navigation::AddressBar)}
+            on_error={on_error}
+        >
+            <slot />
+        </BrowserFrame>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn navigates_from_bookmarks_and_the_address_bar() {
+        assert_eq!(navigation_events(Navigation::Bookmark), ["navigate"]);
+        assert_eq!(
+            navigation_events(Navigation::AddressBar),
+            ["navigate", "submit"]
+        );
+    }
+}
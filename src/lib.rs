@@ -16,4 +16,19 @@ pub mod dioxus;
 #[cfg(feature = "lep")]
 pub mod leptos;
 
-pub use common::{ButtonType, Size, Variant};
+#[cfg(feature = "sycamore")]
+pub mod sycamore;
+
+#[cfg(feature = "custom-element")]
+pub mod element;
+
+#[cfg(any(feature = "yew", feature = "dio", feature = "sycamore"))]
+mod dom;
+
+pub use common::animation::{Animation, AnimationPhase};
+pub use common::announcer::{Announcement, AnnouncerMessages, Politeness};
+pub use common::css::StyleMode;
+pub use common::{
+    Bookmark, ButtonType, ControlColors, ControlState, DisplayUrl, DomError, LoadState, MenuItem,
+    ShareTarget, Size, Suggestion, SuggestionProvider, SuggestionSource, ToolbarPlacement, Variant,
+};
@@ -0,0 +1,3683 @@
+#![doc = include_str!("../SYCAMORE.md")]
+
+use crate::common::{
+    ADDRESS_BAR_LABEL, Bookmark, ButtonType, ControlState, DisplayUrl, DomError, LoadState,
+    MenuItem, REFRESH_LABEL, ShareTarget, Size, Suggestion, SuggestionProvider, ToolbarLayout,
+    ToolbarPlacement, Variant,
+    animation::{Animation, AnimationPhase, AnimationState},
+    announcer::{Announcement, AnnouncerMessages, Politeness},
+    bookmarks::{self, BookmarkMenu, MORE_BOOKMARKS_LABEL},
+    css::{self, StyleMode, frame_class},
+    describedby_ids, element_id,
+    find::{FindState, highlight_css},
+    highlight_segments, icons, inline_completion, is_default_zoom,
+    keyboard::{
+        AddressBarCommand, FindCommand, FrameCommand, KeyInput, NavigationCommand,
+        address_bar_command, find_command, frame_command, navigation_command,
+    },
+    layout_toolbar,
+    menu::{MenuAction, MenuState, TYPEAHEAD_TIMEOUT, menu_item_id, submenu_id},
+    part_id,
+    qr::QrCode,
+    share::ShareState,
+    share_mailto,
+    style::{self, address_bar_style, address_wrapper_style, frame_style, header_style},
+    zoom_label, zoom_step,
+};
+use crate::dom::{self, page};
+use gloo_timers::callback::Timeout;
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::rc::Rc;
+use sycamore::prelude::*;
+use sycamore::web::events::{
+    ErrorEvent, Event, FocusEvent, InputEvent, KeyboardEvent, MouseEvent, TransitionEvent,
+};
+use wasm_bindgen_futures::spawn_local;
+use web_sys::{
+    Element, HtmlElement, HtmlIFrameElement, HtmlInputElement, Range, ResizeObserver,
+    wasm_bindgen::{JsCast, prelude::*},
+};
+
+impl_into_maybe_dyn!(LoadState);
+
+/// Event handler prop. Any `Fn(T)` closure converts into it, e.g.
+/// `on_close=|()| log::info!("closed")`.
+pub struct Callback<T>(Rc<dyn Fn(T)>);
+
+impl<T> Callback<T> {
+    pub fn call(&self, value: T) {
+        (self.0)(value);
+    }
+}
+
+impl<T> Clone for Callback<T> {
+    fn clone(&self) -> Self {
+        Self(Rc::clone(&self.0))
+    }
+}
+
+impl<T> Default for Callback<T> {
+    fn default() -> Self {
+        Self(Rc::new(|_| {}))
+    }
+}
+
+impl<T, F: Fn(T) + 'static> From<F> for Callback<T> {
+    fn from(f: F) -> Self {
+        Self(Rc::new(f))
+    }
+}
+
+#[derive(Props)]
+pub struct AddressBarProps {
+    #[prop(default = "".into(), setter(into))]
+    pub url: MaybeDyn<Cow<'static, str>>,
+    #[prop(default = "Enter URL or search...")]
+    pub placeholder: &'static str,
+    #[prop(default, setter(into))]
+    pub on_url_change: Callback<Event>,
+    #[prop(default = false)]
+    pub read_only: bool,
+
+    #[prop(default)]
+    pub class: &'static str,
+
+    #[prop(default = style::ADDRESS_BAR_STYLE)]
+    pub style: &'static str,
+
+    #[prop(default = ADDRESS_BAR_LABEL)]
+    pub label: &'static str,
+    /// Hint read after the label, rendered in a visually hidden element.
+    #[prop(default = "Enter a website URL or search term. Press Enter to navigate.")]
+    pub description: &'static str,
+    /// Ids of further elements describing the input, space separated.
+    #[prop(default)]
+    pub describedby: &'static str,
+    /// Id of the input; generated per instance when empty.
+    #[prop(default, setter(into))]
+    pub input_id: String,
+
+    #[prop(default)]
+    pub input_class: &'static str,
+
+    #[prop(default)]
+    pub container_class: &'static str,
+
+    #[prop(default = style::REFRESH_BUTTON_STYLE)]
+    pub refresh_button_style: &'static str,
+
+    #[prop(default = REFRESH_LABEL)]
+    pub refresh_button_aria_label: &'static str,
+
+    #[prop(default = LoadState::default().into(), setter(into))]
+    pub load_state: MaybeDyn<LoadState>,
+    #[prop(default, setter(strip_option, into))]
+    pub on_refresh: Option<Callback<()>>,
+    #[prop(default, setter(into))]
+    pub on_stop: Callback<()>,
+    #[prop(default = "Stop loading")]
+    pub stop_button_aria_label: &'static str,
+
+    #[prop(default = 1.0.into(), setter(into))]
+    pub zoom: MaybeDyn<f64>,
+    #[prop(default, setter(into))]
+    pub on_zoom_reset: Callback<()>,
+    #[prop(default = style::ZOOM_BUTTON_STYLE)]
+    pub zoom_button_style: &'static str,
+
+    #[prop(default = style::ADDRESS_INPUT_STYLE)]
+    pub input_style: &'static str,
+
+    #[prop(default)]
+    pub suggestions: SuggestionProvider,
+    #[prop(default = 8)]
+    pub max_suggestions: usize,
+    #[prop(default, setter(into))]
+    pub on_suggestion_select: Callback<Suggestion>,
+    #[prop(default, setter(into))]
+    pub on_submit: Callback<String>,
+
+    #[prop(default)]
+    pub listbox_class: &'static str,
+    #[prop(default = style::SUGGESTION_LISTBOX_STYLE)]
+    pub listbox_style: &'static str,
+    #[prop(default)]
+    pub option_class: &'static str,
+    #[prop(default = style::SUGGESTION_OPTION_STYLE)]
+    pub option_style: &'static str,
+    #[prop(default = style::ACTIVE_ITEM_STYLE)]
+    pub option_active_style: &'static str,
+    #[prop(default = style::SUGGESTION_MATCH_STYLE)]
+    pub match_style: &'static str,
+
+    #[prop(default = true)]
+    pub smart_display: bool,
+    #[prop(default = style::URL_DISPLAY_STYLE)]
+    pub display_style: &'static str,
+    #[prop(default = style::URL_DOMAIN_STYLE)]
+    pub domain_style: &'static str,
+    #[prop(default = style::URL_PATH_STYLE)]
+    pub path_style: &'static str,
+
+    #[prop(default, setter(into))]
+    pub on_error: Callback<DomError>,
+
+    #[prop(default)]
+    pub style_mode: StyleMode,
+}
+
+#[component]
+pub fn AddressBar(props: AddressBarProps) -> View {
+    let announcer = use_announcer();
+    let input_value = create_signal(props.url.get_clone().into_owned());
+    let is_focused = create_signal(false);
+    let input_ref = create_node_ref();
+
+    let query = create_signal(String::new());
+    let suggestions = create_signal(Vec::<Suggestion>::new());
+    let active_index = create_signal(None::<usize>);
+    let is_open = create_signal(false);
+    let request_id = create_signal(0u32);
+    let select_on_mouse_up = create_signal(false);
+
+    {
+        let url = props.url.clone();
+        create_effect(move || input_value.set(url.get_clone().into_owned()));
+    }
+
+    let input = move || {
+        input_ref
+            .try_get()
+            .and_then(|node| node.dyn_into::<HtmlInputElement>().ok())
+    };
+
+    // Renders a batch of suggestions and, while the user is typing forward,
+    // completes the input inline with the best match selected.
+    let show_suggestions = move |items: Vec<Suggestion>, typed: String, grew: bool| {
+        if grew {
+            if let Some(completed) = items
+                .first()
+                .and_then(|first| inline_completion(&typed, &first.url))
+            {
+                let end = completed.encode_utf16().count() as u32;
+                input_value.set(completed);
+                if let Some(input) = input() {
+                    // Selection offsets count UTF-16 code units.
+                    let _ = input.set_selection_range(typed.encode_utf16().count() as u32, end);
+                }
+            }
+        }
+        is_open.set(!items.is_empty());
+        suggestions.set(items);
+    };
+
+    let on_input = {
+        let on_url_change = props.on_url_change.clone();
+        let provider = props.suggestions.clone();
+        let max_suggestions = props.max_suggestions;
+        move |e: Event| {
+            let Some(input) = e
+                .target()
+                .and_then(|target| target.dyn_into::<HtmlInputElement>().ok())
+            else {
+                return;
+            };
+            let value = input.value();
+            let grew = e
+                .dyn_ref::<InputEvent>()
+                .is_none_or(|e| !e.input_type().starts_with("delete"));
+            input_value.set(value.clone());
+            query.set(value.clone());
+            active_index.set(None);
+            on_url_change.call(e);
+
+            request_id.update(|id| *id += 1);
+            let current = request_id.get_untracked();
+            match provider.filter(&value, max_suggestions) {
+                Some(items) => show_suggestions(items, value, grew),
+                None => {
+                    if let Some(future) = provider.fetch(value.clone()) {
+                        spawn_local(async move {
+                            let mut items = future.await;
+                            if request_id.is_alive() && request_id.get_untracked() == current {
+                                items.truncate(max_suggestions);
+                                show_suggestions(items, value, grew);
+                            }
+                        });
+                    }
+                }
+            }
+        }
+    };
+
+    let select_suggestion = {
+        let on_suggestion_select = props.on_suggestion_select.clone();
+        let on_submit = props.on_submit.clone();
+        Callback::from(move |suggestion: Suggestion| {
+            let url = suggestion.url.clone();
+            input_value.set(url.clone());
+            query.set(url.clone());
+            is_open.set(false);
+            active_index.set(None);
+            on_suggestion_select.call(suggestion);
+            on_submit.call(url);
+        })
+    };
+
+    let on_key_down = {
+        let select_suggestion = select_suggestion.clone();
+        let on_submit = props.on_submit.clone();
+        let on_error = props.on_error.clone();
+        move |e: KeyboardEvent| {
+            let count = suggestions.with(Vec::len);
+            let expanded = is_open.get() && count > 0;
+            let key = e.key();
+            let Some(command) =
+                address_bar_command(&KeyInput::new(&key), active_index.get(), count, expanded)
+            else {
+                return;
+            };
+            e.prevent_default();
+            match command {
+                AddressBarCommand::Highlight(next) => {
+                    active_index.set(Some(next));
+                    input_value.set(suggestions.with(|items| items[next].url.clone()));
+                }
+                AddressBarCommand::Open => is_open.set(true),
+                AddressBarCommand::Dismiss => {
+                    e.stop_propagation();
+                    is_open.set(false);
+                    active_index.set(None);
+                    input_value.set(query.get_clone());
+                }
+                AddressBarCommand::Submit(active) => {
+                    let value = match active {
+                        Some(index) => {
+                            let suggestion = suggestions.with(|items| items[index].clone());
+                            let url = suggestion.url.clone();
+                            select_suggestion.call(suggestion);
+                            url
+                        }
+                        None => {
+                            is_open.set(false);
+                            let value = input_value.get_clone();
+                            on_submit.call(value.clone());
+                            value
+                        }
+                    };
+                    if let Some(input) = input() {
+                        let _ = input.blur();
+                    }
+                    if let Err(error) = announcer.announce(&Announcement::Navigation(value)) {
+                        on_error.call(error);
+                    }
+                }
+            }
+        }
+    };
+
+    let on_focus = move |_: FocusEvent| {
+        is_focused.set(true);
+        if let Some(input) = input() {
+            input.select();
+            select_on_mouse_up.set(true);
+        }
+    };
+    // The click that focuses the input would otherwise collapse the selection
+    // made in `on_focus`.
+    let on_mouse_up = move |e: MouseEvent| {
+        if select_on_mouse_up.replace(false) {
+            e.prevent_default();
+        }
+    };
+    let on_blur = move |_: FocusEvent| {
+        is_focused.set(false);
+        is_open.set(false);
+        active_index.set(None);
+    };
+
+    let on_refresh = {
+        let on_refresh = props.on_refresh.clone();
+        let on_error = props.on_error.clone();
+        move |_: MouseEvent| match &on_refresh {
+            Some(on_refresh) => on_refresh.call(()),
+            None => {
+                if let Err(error) = dom::reload() {
+                    on_error.call(error);
+                }
+            }
+        }
+    };
+    let on_stop = {
+        let on_stop = props.on_stop.clone();
+        move |_: MouseEvent| on_stop.call(())
+    };
+
+    let smart_display = props.smart_display;
+    let display_url = create_memo(move || {
+        if smart_display && !is_focused.get() {
+            DisplayUrl::parse(&input_value.get_clone())
+        } else {
+            None
+        }
+    });
+    let zoom = {
+        let zoom = props.zoom.clone();
+        create_memo(move || zoom.get())
+    };
+    let zoomed = create_selector(move || !is_default_zoom(zoom.get()));
+    let expanded = move || is_open.get() && suggestions.with(|items| !items.is_empty());
+    let mode = props.style_mode;
+    let input_style = {
+        let base = props.input_style;
+        move || {
+            let mut style = if display_url.with(Option::is_some) {
+                format!("{} color: transparent;", base)
+            } else {
+                base.to_string()
+            };
+            if zoomed.get() {
+                style.push_str(" padding-right: 5rem;");
+            }
+            mode.inline(&style).to_string()
+        }
+    };
+    let input_class = {
+        let class = props.input_class;
+        move || {
+            if display_url.with(Option::is_some) {
+                mode.class(css::ADDRESS_INPUT_MASKED, class)
+            } else {
+                mode.class(css::ADDRESS_INPUT, class)
+            }
+        }
+    };
+    let load_state = props.load_state.clone();
+    let is_loading = move || load_state.get_clone().is_loading();
+
+    let AddressBarProps {
+        placeholder,
+        read_only,
+        class,
+        style,
+        label,
+        description,
+        describedby,
+        container_class,
+        refresh_button_style,
+        refresh_button_aria_label,
+        stop_button_aria_label,
+        zoom_button_style,
+        listbox_class,
+        listbox_style,
+        option_class,
+        option_style,
+        option_active_style,
+        match_style,
+        display_style,
+        domain_style,
+        path_style,
+        ..
+    } = props;
+
+    let input_id = element_id(&props.input_id, "browser-url-input");
+    let label_for = input_id.clone();
+    let listbox_id = format!("{input_id}-listbox");
+    let description_id = format!("{input_id}-description");
+    let option_id = {
+        let input_id = input_id.clone();
+        move |index: usize| format!("{input_id}-option-{index}")
+    };
+    let active_descendant = {
+        let option_id = option_id.clone();
+        move || active_index.get().filter(|_| expanded()).map(&option_id)
+    };
+    let aria_describedby = describedby_ids([
+        if description.is_empty() {
+            ""
+        } else {
+            description_id.as_str()
+        },
+        describedby,
+    ]);
+    let description = if description.is_empty() {
+        view! {}
+    } else {
+        view! {
+            span(
+                id=description_id,
+                class=mode.name(css::VISUALLY_HIDDEN),
+                style=mode.inline(style::VISUALLY_HIDDEN),
+            ) {
+                (description)
+            }
+        }
+    };
+    let bar_class = {
+        let class = format!("{} {}", container_class, class);
+        move || {
+            mode.class(
+                if zoomed.get() {
+                    css::ADDRESS_BAR_ZOOMED
+                } else {
+                    css::ADDRESS_BAR
+                },
+                &class,
+            )
+        }
+    };
+    let on_zoom_reset = props.on_zoom_reset;
+    let autocomplete = (!props.suggestions.is_none()).then_some("both");
+    let listbox = {
+        let listbox_id = listbox_id.clone();
+        move || {
+            if !expanded() {
+                return view! {};
+            }
+            let typed = query.get_clone();
+            let active = active_index.get();
+            let options = suggestions.with(|items| {
+                items
+                    .iter()
+                    .enumerate()
+                    .map(|(index, suggestion)| {
+                        let is_active = active == Some(index);
+                        let style = if is_active {
+                            format!("{} {}", option_style, option_active_style)
+                        } else {
+                            option_style.to_string()
+                        };
+                        let class = mode.class(
+                            if is_active {
+                                css::SUGGESTION_ACTIVE
+                            } else {
+                                css::SUGGESTION
+                            },
+                            option_class,
+                        );
+                        let segments: Vec<View> = highlight_segments(&suggestion.url, &typed)
+                            .into_iter()
+                            .map(|(text, is_match)| {
+                                let text = text.to_string();
+                                if is_match {
+                                    view! {
+                                        mark(
+                                            class=mode.name(css::SUGGESTION_MATCH),
+                                            style=mode.inline(match_style),
+                                        ) {
+                                            (text)
+                                        }
+                                    }
+                                } else {
+                                    view! { (text) }
+                                }
+                            })
+                            .collect();
+                        let title = if suggestion.title.is_empty() {
+                            view! {}
+                        } else {
+                            let title = suggestion.title.clone();
+                            view! {
+                                span(
+                                    class=mode.name(css::SUGGESTION_TITLE),
+                                    style=mode.inline("color: #6b7280; font-size: 0.75rem;"),
+                                ) {
+                                    (title)
+                                }
+                            }
+                        };
+                        let on_click = {
+                            let select_suggestion = select_suggestion.clone();
+                            let suggestion = suggestion.clone();
+                            move |_: MouseEvent| select_suggestion.call(suggestion.clone())
+                        };
+                        let id = option_id(index);
+                        view! {
+                            li(
+                                id=id,
+                                role="option",
+                                aria-selected=if is_active { "true" } else { "false" },
+                                class=class,
+                                style=mode.inline(&style).to_string(),
+                                on:mousedown=|e: MouseEvent| e.prevent_default(),
+                                on:click=on_click,
+                            ) {
+                                span { (segments) }
+                                (title)
+                            }
+                        }
+                    })
+                    .collect::<Vec<View>>()
+            });
+            let listbox_id = listbox_id.clone();
+            view! {
+                ul(
+                    id=listbox_id,
+                    role="listbox",
+                    aria-label=label,
+                    class=mode.class(css::SUGGESTIONS, listbox_class),
+                    style=mode.inline(listbox_style),
+                ) {
+                    (options)
+                }
+            }
+        }
+    };
+
+    view! {
+        div(
+            class=bar_class,
+            style=move || mode.inline(&address_bar_style(style, is_focused.get())).to_string(),
+        ) {
+            label(
+                r#for=label_for,
+                class=mode.name(css::VISUALLY_HIDDEN),
+                style=mode.inline(style::VISUALLY_HIDDEN),
+            ) {
+                (label)
+            }
+            (description)
+            input(
+                r#ref=input_ref,
+                id=input_id,
+                r#type="text",
+                role="combobox",
+                bind:value=input_value,
+                on:input=on_input,
+                on:keydown=on_key_down,
+                on:focus=on_focus,
+                on:blur=on_blur,
+                on:mouseup=on_mouse_up,
+                placeholder=placeholder,
+                readonly=read_only,
+                class=input_class,
+                style=input_style,
+                aria-describedby=aria_describedby,
+                aria-autocomplete=autocomplete,
+                aria-expanded=move || if expanded() { "true" } else { "false" },
+                aria-controls=listbox_id,
+                aria-activedescendant=active_descendant,
+                autocomplete="url",
+                "spellcheck"="false",
+            )
+            (move || match display_url.get_clone() {
+                Some(display_url) => {
+                    let style = if zoomed.get() {
+                        format!("{} right: 5rem;", display_style)
+                    } else {
+                        display_style.to_string()
+                    };
+                    view! {
+                        div(
+                            aria-hidden="true",
+                            class=mode.class(css::URL, props.input_class),
+                            style=mode.inline(&style).to_string(),
+                        ) {
+                            span { (display_url.subdomain) }
+                            span(class=mode.name(css::URL_DOMAIN), style=mode.inline(domain_style)) {
+                                (display_url.domain)
+                            }
+                            span(class=mode.name(css::URL_PATH), style=mode.inline(path_style)) {
+                                (display_url.rest)
+                            }
+                        }
+                    }
+                }
+                None => view! {},
+            })
+            (move || if zoomed.get() {
+                let label = zoom_label(zoom.get());
+                let aria_label = format!("Zoom {label}, reset to 100%");
+                let on_zoom_reset = on_zoom_reset.clone();
+                view! {
+                    button(
+                        r#type="button",
+                        class=mode.name(css::ZOOM),
+                        style=mode.inline(zoom_button_style),
+                        title="Reset zoom",
+                        aria-label=aria_label,
+                        on:click=move |_: MouseEvent| on_zoom_reset.call(()),
+                    ) {
+                        (label)
+                    }
+                }
+            } else {
+                view! {}
+            })
+            (move || if is_loading() {
+                let on_stop = on_stop.clone();
+                view! {
+                    button(
+                        class=mode.name(css::REFRESH),
+                        style=mode.inline(refresh_button_style),
+                        aria-label=stop_button_aria_label, on:click=on_stop) {
+                        svg(width="11", height="13", viewBox="0 0 11 13", fill="none", xmlns="http://www.w3.org/2000/svg") {
+                            path(d=icons::STOP, stroke="#767676", stroke-linecap="round", stroke-linejoin="round")
+                        }
+                    }
+                }
+            } else {
+                let on_refresh = on_refresh.clone();
+                view! {
+                    button(
+                        class=mode.name(css::REFRESH),
+                        style=mode.inline(refresh_button_style),
+                        aria-label=refresh_button_aria_label, on:click=on_refresh) {
+                        svg(width="11", height="13", viewBox="0 0 11 13", fill="none", xmlns="http://www.w3.org/2000/svg") {
+                            path(d=icons::REFRESH, stroke="#767676", stroke-linecap="round", stroke-linejoin="round")
+                        }
+                    }
+                }
+            })
+            (listbox)
+        }
+    }
+}
+
+#[derive(Props)]
+pub struct BrowserContentProps {
+    #[prop(default)]
+    pub children: Children,
+    #[prop(default)]
+    pub class: &'static str,
+    #[prop(default)]
+    pub style: &'static str,
+    #[prop(default = "Browser content area")]
+    pub aria_label: &'static str,
+    #[prop(default)]
+    pub aria_describedby: &'static str,
+    #[prop(default)]
+    pub node_ref: NodeRef,
+    #[prop(default = 1.0.into(), setter(into))]
+    pub zoom: MaybeDyn<f64>,
+    #[prop(default)]
+    pub style_mode: StyleMode,
+}
+
+#[component]
+pub fn BrowserContent(props: BrowserContentProps) -> View {
+    let children = props.children.call();
+    let mode = props.style_mode;
+    let zoom = props.zoom;
+    let layer_class = {
+        let zoom = zoom.clone();
+        move || mode.name(css::zoom_layer_class(zoom.get()))
+    };
+
+    view! {
+        main(
+            r#ref=props.node_ref,
+            class=props.style_mode.class(css::CONTENT, props.class),
+            style=props.style_mode.inline(props.style),
+            role="main",
+            aria-label=props.aria_label,
+            aria-describedby=props.aria_describedby,
+            tabindex="-1",
+        ) {
+            div(
+                class=layer_class,
+                style=move || css::zoom_layer_style(zoom.get(), mode),
+            ) {
+                (children)
+            }
+        }
+    }
+}
+
+#[derive(Props)]
+pub struct ControlButtonProps {
+    pub r#type: ButtonType,
+    #[prop(default, setter(into))]
+    pub on_click: Callback<()>,
+    #[prop(default, setter(into))]
+    pub on_mouse_over: Callback<()>,
+    #[prop(default, setter(into))]
+    pub on_mouse_out: Callback<()>,
+    #[prop(default, setter(into))]
+    pub on_focus: Callback<FocusEvent>,
+    #[prop(default, setter(into))]
+    pub on_blur: Callback<FocusEvent>,
+    #[prop(default)]
+    pub class: &'static str,
+    #[prop(default = style::CONTROL_BUTTON_STYLE)]
+    pub style: &'static str,
+    #[prop(default)]
+    pub svg_class: &'static str,
+    #[prop(default)]
+    pub path_class: &'static str,
+    #[prop(default = "button")]
+    pub button_type: &'static str,
+    #[prop(default)]
+    pub aria_label: &'static str,
+    #[prop(default)]
+    pub title: &'static str,
+    #[prop(default = "0")]
+    pub tabindex: &'static str,
+    /// Reveals the ×, − or ⤢ glyph, as when the pointer enters the controls.
+    #[prop(default = false.into(), setter(into))]
+    pub show_glyph: MaybeDyn<bool>,
+    /// Draws the control grey, as in an inactive window.
+    #[prop(default = false.into(), setter(into))]
+    pub inactive: MaybeDyn<bool>,
+    #[prop(default)]
+    pub style_mode: StyleMode,
+}
+
+#[component]
+pub fn ControlButton(props: ControlButtonProps) -> View {
+    let aria_label = if props.aria_label.is_empty() {
+        props.r#type.default_aria_label()
+    } else {
+        props.aria_label
+    };
+    let title = if props.title.is_empty() {
+        props.r#type.default_title()
+    } else {
+        props.title
+    };
+    let hovered = create_signal(false);
+    let forced_colors = use_forced_colors();
+    let button = props.r#type.clone();
+    let (show_glyph, inactive) = (props.show_glyph.clone(), props.inactive.clone());
+    let colors = create_memo(move || {
+        button.control_colors(ControlState {
+            hovered: hovered.get(),
+            show_glyph: show_glyph.get(),
+            inactive: inactive.get(),
+            forced_colors: forced_colors.get(),
+        })
+    });
+    let button = props.r#type.clone();
+    let glyph_class = props.style_mode.name(css::CONTROL_GLYPH);
+
+    let ControlButtonProps {
+        on_click,
+        on_mouse_over,
+        on_mouse_out,
+        on_focus,
+        on_blur,
+        ..
+    } = props;
+
+    view! {
+        button(
+            r#type=props.button_type,
+            class=props.style_mode.class(css::control_class(&props.r#type), props.class),
+            style=props.style_mode.inline(props.style),
+            on:click=move |_: MouseEvent| on_click.call(()),
+            on:mouseover=move |_: MouseEvent| {
+                hovered.set(true);
+                on_mouse_over.call(());
+            },
+            on:mouseout=move |_: MouseEvent| {
+                hovered.set(false);
+                on_mouse_out.call(());
+            },
+            on:focus=move |e: FocusEvent| on_focus.call(e),
+            on:blur=move |e: FocusEvent| on_blur.call(e),
+            aria-label=aria_label,
+            title=title,
+            tabindex=props.tabindex,
+        ) {
+            svg(
+                class=props.svg_class,
+                width="12",
+                height="12",
+                viewBox="0 0 12 12",
+                fill="none",
+                xmlns="http://www.w3.org/2000/svg",
+            ) {
+                path(
+                    class=props.path_class,
+                    d=icons::CONTROL_BUTTON,
+                    fill=move || colors.get().fill,
+                    stroke=move || colors.get().stroke,
+                )
+                (move || match colors.get().glyph {
+                    Some(stroke) => {
+                        let glyph = button.glyph(forced_colors.get());
+                        view! {
+                            path(
+                                class=glyph_class,
+                                d=glyph,
+                                stroke=stroke,
+                                stroke-width="1.25",
+                                stroke-linecap="round",
+                            )
+                        }
+                    }
+                    None => view! {},
+                })
+            }
+        }
+    }
+}
+
+#[derive(Props)]
+pub struct BrowserControlsProps {
+    #[prop(default)]
+    pub show_controls: bool,
+    #[prop(default, setter(into))]
+    pub on_error: Callback<DomError>,
+    #[prop(default)]
+    pub class: &'static str,
+    #[prop(default = style::CONTROLS_STYLE)]
+    pub style: &'static str,
+
+    #[prop(default, setter(into))]
+    pub on_close: Callback<()>,
+    #[prop(default)]
+    pub close_class: &'static str,
+    #[prop(default)]
+    pub close_aria_label: &'static str,
+    #[prop(default)]
+    pub close_title: &'static str,
+    #[prop(default = "0")]
+    pub close_tabindex: &'static str,
+
+    #[prop(default, setter(into))]
+    pub on_minimize: Callback<()>,
+    #[prop(default)]
+    pub minimize_class: &'static str,
+    #[prop(default)]
+    pub minimize_aria_label: &'static str,
+    #[prop(default)]
+    pub minimize_title: &'static str,
+    #[prop(default = "0")]
+    pub minimize_tabindex: &'static str,
+
+    #[prop(default, setter(into))]
+    pub on_maximize: Callback<()>,
+    #[prop(default)]
+    pub maximize_class: &'static str,
+    #[prop(default)]
+    pub maximize_aria_label: &'static str,
+    #[prop(default)]
+    pub maximize_title: &'static str,
+    #[prop(default = "0")]
+    pub maximize_tabindex: &'static str,
+
+    /// Draws the controls grey until the pointer or focus enters them, as in
+    /// an inactive window.
+    #[prop(default = false.into(), setter(into))]
+    pub inactive: MaybeDyn<bool>,
+
+    #[prop(default)]
+    pub style_mode: StyleMode,
+}
+
+#[component]
+pub fn BrowserControls(props: BrowserControlsProps) -> View {
+    if !props.show_controls {
+        return view! {};
+    }
+    let roving = use_roving_tabindex(props.on_error);
+    let (on_key_down, on_focus_in) = roving.handlers();
+    let hovered = create_signal(false);
+    let focused = create_signal(false);
+    let show_glyph = move || hovered.get() || focused.get();
+    let inactive = props.inactive.clone();
+    let [close_inactive, minimize_inactive, maximize_inactive] =
+        [(); 3].map(|_| props.inactive.clone());
+    let (mode, class) = (props.style_mode, props.class);
+    let nav_class = move || {
+        mode.class(
+            if inactive.get() {
+                css::CONTROLS_INACTIVE
+            } else {
+                css::CONTROLS
+            },
+            class,
+        )
+    };
+
+    view! {
+        nav(
+            r#ref=roving.node_ref,
+            class=nav_class,
+            style=props.style_mode.inline(props.style),
+            role="toolbar",
+            aria-label="Browser window controls",
+            on:keydown=on_key_down,
+            on:focusin=move |e: FocusEvent| {
+                focused.set(true);
+                on_focus_in(e);
+            },
+            // Moving focus between the buttons fires `focusout` right before
+            // `focusin`, so the glyphs stay while focus remains in the group.
+            on:focusout=move |_: FocusEvent| focused.set(false),
+            on:mouseenter=move |_: MouseEvent| hovered.set(true),
+            on:mouseleave=move |_: MouseEvent| hovered.set(false),
+        ) {
+            ControlButton(
+                r#type=ButtonType::Close,
+                on_click=props.on_close,
+                class=props.close_class,
+                aria_label=props.close_aria_label,
+                title=props.close_title,
+                tabindex=props.close_tabindex,
+                show_glyph=show_glyph,
+                inactive=close_inactive,
+                style_mode=props.style_mode,
+            )
+            ControlButton(
+                r#type=ButtonType::Minimize,
+                on_click=props.on_minimize,
+                class=props.minimize_class,
+                aria_label=props.minimize_aria_label,
+                title=props.minimize_title,
+                tabindex=props.minimize_tabindex,
+                show_glyph=show_glyph,
+                inactive=minimize_inactive,
+                style_mode=props.style_mode,
+            )
+            ControlButton(
+                r#type=ButtonType::Maximize,
+                on_click=props.on_maximize,
+                class=props.maximize_class,
+                aria_label=props.maximize_aria_label,
+                title=props.maximize_title,
+                tabindex=props.maximize_tabindex,
+                show_glyph=show_glyph,
+                inactive=maximize_inactive,
+                style_mode=props.style_mode,
+            )
+        }
+    }
+}
+
+#[derive(Props)]
+pub struct TitleBarProps {
+    #[prop(default = "".into(), setter(into))]
+    pub title: MaybeDyn<Cow<'static, str>>,
+    #[prop(default = "".into(), setter(into))]
+    pub favicon: MaybeDyn<Cow<'static, str>>,
+    #[prop(default)]
+    pub variant: Variant,
+    #[prop(default = "New Tab")]
+    pub fallback_title: &'static str,
+    #[prop(default)]
+    pub class: &'static str,
+    #[prop(default = style::TITLE_BAR_STYLE)]
+    pub style: &'static str,
+    #[prop(default = style::TAB_LIST_STYLE)]
+    pub tablist_style: &'static str,
+    #[prop(default = style::TAB_STYLE)]
+    pub tab_style: &'static str,
+    #[prop(default)]
+    pub style_mode: StyleMode,
+}
+
+#[component]
+pub fn TitleBar(props: TitleBarProps) -> View {
+    let favicon_failed = create_signal(false);
+    let favicon = {
+        let favicon = props.favicon.clone();
+        create_memo(move || favicon.get_clone().into_owned())
+    };
+    create_effect(move || {
+        favicon.track();
+        favicon_failed.set(false);
+    });
+
+    let mode = props.style_mode;
+    let icon = move || {
+        let src = favicon.get_clone();
+        if src.is_empty() || favicon_failed.get() {
+            view! {
+                svg(
+                    width="16",
+                    height="16",
+                    viewBox="0 0 16 16",
+                    fill="none",
+                    xmlns="http://www.w3.org/2000/svg",
+                    aria-hidden="true",
+                    class=mode.name(css::FAVICON),
+                    style=mode.inline("flex-shrink: 0;"),
+                ) {
+                    circle(cx="8", cy="8", r="6.5", stroke="#767676")
+                    path(
+                        d="M1.5 8H14.5M8 1.5C9.7 3.3 10.5 5.5 10.5 8C10.5 10.5 9.7 12.7 8 14.5C6.3 12.7 5.5 10.5 5.5 8C5.5 5.5 6.3 3.3 8 1.5Z",
+                        stroke="#767676",
+                    )
+                }
+            }
+        } else {
+            view! {
+                img(
+                    src=src,
+                    alt="",
+                    width="16",
+                    height="16",
+                    class=mode.name(css::FAVICON),
+                    style=mode.inline("flex-shrink: 0;"),
+                    on:error=move |_: ErrorEvent| favicon_failed.set(true),
+                )
+            }
+        }
+    };
+
+    let title = {
+        let (title, fallback) = (props.title.clone(), props.fallback_title);
+        create_memo(move || match title.get_clone() {
+            title if title.is_empty() => fallback.to_string(),
+            title => title.into_owned(),
+        })
+    };
+    let label = view! {
+        span(
+            class=mode.name(css::TITLE),
+            style=mode.inline("overflow: hidden; text-overflow: ellipsis; white-space: nowrap;"),
+        ) {
+            (title.get_clone())
+        }
+    };
+
+    match props.variant {
+        Variant::Tabs => view! {
+            div(
+                class=mode.class(css::TAB_LIST, props.class),
+                style=mode.inline(props.tablist_style),
+                role="tablist",
+                aria-label="Browser tabs",
+            ) {
+                div(
+                    class=mode.name(css::TAB),
+                    style=mode.inline(props.tab_style),
+                    role="tab",
+                    aria-selected="true",
+                    title=move || title.get_clone(),
+                ) {
+                    (icon)
+                    (label)
+                }
+            }
+        },
+        Variant::Default => view! {
+            div(
+                class=mode.class(css::TITLE_BAR, props.class),
+                style=mode.inline(props.style),
+                title=move || title.get_clone(),
+            ) {
+                (icon)
+                (label)
+            }
+        },
+        Variant::Ios => view! {},
+    }
+}
+
+#[derive(Props)]
+pub struct ShareMenuProps {
+    #[prop(default = "".into(), setter(into))]
+    pub url: MaybeDyn<Cow<'static, str>>,
+    #[prop(default = "".into(), setter(into))]
+    pub title: MaybeDyn<Cow<'static, str>>,
+    #[prop(default = true)]
+    pub enabled: bool,
+    #[prop(default, setter(into))]
+    pub on_share: Callback<ShareTarget>,
+    #[prop(default, setter(into))]
+    pub on_click: Callback<()>,
+    #[prop(default)]
+    pub tabindex: &'static str,
+    #[prop(default)]
+    pub button_style: &'static str,
+    #[prop(default)]
+    pub button_class: &'static str,
+    #[prop(default = style::SHARE_POPOVER_STYLE)]
+    pub popover_style: &'static str,
+    #[prop(default = style::SHARE_ITEM_STYLE)]
+    pub item_style: &'static str,
+    #[prop(default = style::SHARE_QR_STYLE)]
+    pub qr_style: &'static str,
+    #[prop(default)]
+    pub style_mode: StyleMode,
+}
+
+#[component]
+pub fn ShareMenu(props: ShareMenuProps) -> View {
+    let share = create_signal(ShareState::default());
+    let button_ref = create_node_ref();
+    let copy_ref = create_node_ref();
+    let focus = |node_ref: NodeRef| {
+        if let Some(element) = node_ref
+            .try_get()
+            .and_then(|node| node.dyn_into::<HtmlElement>().ok())
+        {
+            let _ = element.focus();
+        }
+    };
+    let url = {
+        let url = props.url.clone();
+        create_memo(move || url.get_clone().into_owned())
+    };
+    let title = {
+        let title = props.title.clone();
+        create_memo(move || title.get_clone().into_owned())
+    };
+
+    let close = move || {
+        share.update(ShareState::close);
+        focus(button_ref);
+    };
+
+    let on_click = {
+        let (on_click, on_share, enabled) = (
+            props.on_click.clone(),
+            props.on_share.clone(),
+            props.enabled,
+        );
+        move |_: MouseEvent| {
+            on_click.call(());
+            if !enabled {
+                return;
+            }
+            if share.with(|share| share.open) {
+                share.update(ShareState::close);
+                return;
+            }
+
+            let on_share = on_share.clone();
+            spawn_local(async move {
+                let shared = page::share(url.get_clone(), title.get_clone()).await;
+                if !share.is_alive() {
+                    return;
+                }
+                match shared {
+                    Some(true) => on_share.call(ShareTarget::Native),
+                    Some(false) | None => {
+                        share.update(ShareState::open_fallback);
+                        focus(copy_ref);
+                    }
+                }
+            });
+        }
+    };
+
+    let on_copy = {
+        let on_share = props.on_share.clone();
+        move |_: MouseEvent| {
+            let on_share = on_share.clone();
+            spawn_local(async move {
+                if page::copy_text(url.get_clone()).await && share.is_alive() {
+                    share.update(ShareState::mark_copied);
+                    on_share.call(ShareTarget::Clipboard);
+                }
+            });
+        }
+    };
+
+    let on_email = {
+        let on_share = props.on_share.clone();
+        move |_: MouseEvent| {
+            on_share.call(ShareTarget::Email);
+            close();
+        }
+    };
+
+    let on_toggle_qr = {
+        let on_share = props.on_share.clone();
+        move |_: MouseEvent| {
+            if share.update(ShareState::toggle_qr) {
+                on_share.call(ShareTarget::QrCode);
+            }
+        }
+    };
+
+    // Handled on the wrapper so Escape on the trigger closes the popover
+    // instead of reaching the frame's window-level shortcuts.
+    let on_key_down = move |e: KeyboardEvent| {
+        if share.with(|share| share.open) && e.key() == "Escape" {
+            e.prevent_default();
+            e.stop_propagation();
+            close();
+        }
+    };
+
+    let mode = props.style_mode;
+    let ShareMenuProps {
+        enabled,
+        tabindex,
+        button_style,
+        button_class,
+        popover_style,
+        item_style,
+        qr_style,
+        ..
+    } = props;
+    let qr_code = move || {
+        if !share.with(|share| share.show_qr) {
+            return view! {};
+        }
+        let url = url.get_clone();
+        match QrCode::encode(url.as_bytes()) {
+            Some(qr) => {
+                let extent = qr.size() + 8;
+                let view_box = format!("0 0 {extent} {extent}");
+                let label = format!("QR code for {url}");
+                let path = qr.to_svg_path(4);
+                view! {
+                    svg(
+                        class=mode.name(css::SHARE_QR),
+                        style=mode.inline(qr_style),
+                        viewBox=view_box,
+                        "role"="img",
+                        aria-label=label,
+                        shape-rendering="crispEdges",
+                        xmlns="http://www.w3.org/2000/svg",
+                    ) {
+                        rect(width="100%", height="100%", fill="#ffffff")
+                        path(d=path, fill="#000000")
+                    }
+                }
+            }
+            None => view! {},
+        }
+    };
+    let popover = move || {
+        if !share.with(|share| share.open) {
+            return view! {};
+        }
+        let state = share.get();
+        let (on_copy, on_email, on_toggle_qr) =
+            (on_copy.clone(), on_email.clone(), on_toggle_qr.clone());
+        view! {
+            div(
+                class=mode.name(css::BACKDROP),
+                style=mode.inline("position: fixed; inset: 0; z-index: 40;"),
+                on:click=move |_: MouseEvent| share.update(ShareState::close),
+            )
+            div(
+                class=mode.name(css::SHARE),
+                style=mode.inline(popover_style),
+                role="dialog",
+                aria-label="Share",
+            ) {
+                button(
+                    r#ref=copy_ref,
+                    class=mode.name(css::SHARE_ITEM),
+                    style=mode.inline(item_style),
+                    on:click=on_copy,
+                    aria-live="polite",
+                ) {
+                    (state.copy_label())
+                }
+                a(
+                    class=mode.name(css::SHARE_ITEM),
+                    style=mode.inline(item_style),
+                    href=share_mailto(&url.get_clone(), &title.get_clone()),
+                    on:click=on_email,
+                ) {
+                    "Email"
+                }
+                button(
+                    class=mode.name(css::SHARE_ITEM),
+                    style=mode.inline(item_style),
+                    on:click=on_toggle_qr,
+                    aria-expanded=state.show_qr.to_string(),
+                ) {
+                    (state.qr_label())
+                }
+                (qr_code)
+            }
+        }
+    };
+
+    view! {
+        div(
+            class=mode.name(css::POPUP),
+            style=mode.inline("position: relative; display: flex;"),
+            on:keydown=on_key_down,
+        ) {
+            button(
+                r#ref=button_ref,
+                class=button_class,
+                style=button_style,
+                on:click=on_click,
+                aria-label="Share",
+                aria-haspopup=enabled.then_some("dialog"),
+                aria-expanded=move || enabled.then(|| share.with(|share| share.open).to_string()),
+                title="Share",
+                tabindex=tabindex,
+            ) {
+                svg(width="15", height="19", viewBox="0 0 15 19", fill="none", xmlns="http://www.w3.org/2000/svg") {
+                    path(d=icons::SHARE, fill="#767676")
+                }
+            }
+            (popover)
+        }
+    }
+}
+
+#[derive(Props)]
+pub struct MenuListProps {
+    pub items: Vec<MenuItem>,
+    #[prop(setter(into))]
+    pub id: String,
+    #[prop(default, setter(into))]
+    pub label: String,
+    #[prop(default, setter(into))]
+    pub on_select: Callback<String>,
+    #[prop(default, setter(into))]
+    pub on_close: Callback<()>,
+    /// Set on submenus: returns focus to the parent menu on `ArrowLeft`/`Escape`.
+    #[prop(default, setter(strip_option, into))]
+    pub on_collapse: Option<Callback<()>>,
+    #[prop(default = style::MENU_STYLE)]
+    pub menu_style: &'static str,
+    #[prop(default = style::SUBMENU_STYLE)]
+    pub submenu_style: &'static str,
+    #[prop(default = style::MENU_ITEM_STYLE)]
+    pub item_style: &'static str,
+    #[prop(default = style::ACTIVE_ITEM_STYLE)]
+    pub item_active_style: &'static str,
+    #[prop(default = style::MENU_ITEM_DISABLED_STYLE)]
+    pub item_disabled_style: &'static str,
+    #[prop(default = style::MENU_SEPARATOR_STYLE)]
+    pub separator_style: &'static str,
+    #[prop(default = style::MENU_SHORTCUT_STYLE)]
+    pub shortcut_style: &'static str,
+    #[prop(default)]
+    pub style_mode: StyleMode,
+}
+
+#[component]
+pub fn MenuList(props: MenuListProps) -> View {
+    let items = Rc::new(props.items);
+    let menu = create_signal(MenuState::new(&items));
+    let typeahead_reset = create_signal(None::<Timeout>);
+    let list_ref = create_node_ref();
+    let focus_list = move || {
+        if let Some(list) = list_ref
+            .try_get()
+            .and_then(|node| node.dyn_into::<HtmlElement>().ok())
+        {
+            let _ = list.focus();
+        }
+    };
+    on_mount(focus_list);
+
+    // Carries out what the menu state asks for after an event. Selecting or
+    // closing may unmount the menu, so nothing touches its state afterwards.
+    let apply = {
+        let (on_select, on_close) = (props.on_select.clone(), props.on_close.clone());
+        let on_collapse = props.on_collapse.clone();
+        Callback::from(move |action: MenuAction| match action {
+            MenuAction::Update => {}
+            MenuAction::Typeahead => {
+                typeahead_reset.set(Some(Timeout::new(TYPEAHEAD_TIMEOUT, move || {
+                    if menu.is_alive() {
+                        menu.update(MenuState::reset_typeahead);
+                    }
+                })));
+            }
+            MenuAction::Select(id) => {
+                on_select.call(id);
+                on_close.call(());
+            }
+            MenuAction::Collapse => {
+                if let Some(on_collapse) = &on_collapse {
+                    on_collapse.call(());
+                }
+            }
+            MenuAction::Close | MenuAction::Leave => on_close.call(()),
+        })
+    };
+
+    let on_key_down = {
+        let items = Rc::clone(&items);
+        let apply = apply.clone();
+        let nested = props.on_collapse.is_some();
+        move |e: KeyboardEvent| {
+            let key = e.key();
+            let input = KeyInput::new(&key)
+                .with_ctrl(e.ctrl_key())
+                .with_meta(e.meta_key())
+                .with_alt(e.alt_key());
+            let Some(action) = menu.update(|menu| menu.key(&input, &items, nested)) else {
+                return;
+            };
+            if action.prevents_default() {
+                e.prevent_default();
+                e.stop_propagation();
+            }
+            apply.call(action);
+        }
+    };
+
+    let collapse_submenu = Callback::from(move |()| {
+        menu.update(MenuState::collapse);
+        focus_list();
+    });
+
+    let mode = props.style_mode;
+    let (class, style) = if props.on_collapse.is_some() {
+        (
+            css::SUBMENU,
+            format!("{} {}", props.menu_style, props.submenu_style),
+        )
+    } else {
+        (css::MENU, props.menu_style.to_string())
+    };
+    let MenuListProps {
+        id,
+        label,
+        on_select,
+        on_close,
+        menu_style,
+        submenu_style,
+        item_style,
+        item_active_style,
+        item_disabled_style,
+        separator_style,
+        shortcut_style,
+        ..
+    } = props;
+
+    let entries: Vec<View> = items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| {
+            if item.separator {
+                return view! {
+                    li(
+                        role="separator",
+                        class=mode.name(css::MENU_SEPARATOR),
+                        style=mode.inline(separator_style),
+                    )
+                };
+            }
+
+            let is_active = create_selector(move || menu.with(|menu| menu.active == Some(index)));
+            let is_expanded =
+                create_selector(move || menu.with(|menu| menu.expanded == Some(index)));
+            let disabled = item.disabled;
+            let item_class = move || css::menu_item_class(is_active.get(), disabled);
+            let entry_style = move || {
+                let mut style = item_style.to_string();
+                if is_active.get() {
+                    style = format!("{} {}", style, item_active_style);
+                }
+                if disabled {
+                    style = format!("{} {}", style, item_disabled_style);
+                }
+                mode.inline(&style).to_string()
+            };
+            let on_click = {
+                let (items, apply) = (Rc::clone(&items), apply.clone());
+                move |e: MouseEvent| {
+                    e.stop_propagation();
+                    if let Some(action) = menu.update(|menu| menu.activate(&items, index)) {
+                        apply.call(action);
+                    }
+                }
+            };
+            let on_mouse_enter = {
+                let items = Rc::clone(&items);
+                move |_: MouseEvent| menu.update(|menu| menu.hover(&items, index))
+            };
+
+            let check = match item.checked {
+                Some(checked) => view! {
+                    span(
+                        class=mode.name(css::MENU_CHECK),
+                        style=mode.inline("width: 16px; text-align: center;"),
+                        aria-hidden="true",
+                    ) {
+                        (if checked { "✓" } else { "" })
+                    }
+                },
+                None => view! {},
+            };
+            let icon = if item.icon.is_empty() {
+                view! {}
+            } else {
+                let src = item.icon.clone();
+                view! { img(src=src, alt="", width="16", height="16") }
+            };
+            let shortcut = if item.shortcut.is_empty() {
+                view! {}
+            } else {
+                let shortcut = item.shortcut.clone();
+                view! {
+                    span(
+                        class=mode.name(css::MENU_SHORTCUT),
+                        style=mode.inline(shortcut_style),
+                        aria-hidden="true",
+                    ) {
+                        (shortcut)
+                    }
+                }
+            };
+            let has_submenu = item.has_submenu();
+            let arrow = if has_submenu {
+                view! {
+                    span(
+                        class=mode.name(css::MENU_ARROW),
+                        style=mode.inline("margin-left: auto; padding-left: 16px;"),
+                        aria-hidden="true",
+                    ) {
+                        "›"
+                    }
+                }
+            } else {
+                view! {}
+            };
+            let submenu = {
+                let (submenu, submenu_label) = (item.submenu.clone(), item.label.clone());
+                let submenu_id = submenu_id(&id, index);
+                let (on_select, on_close) = (on_select.clone(), on_close.clone());
+                let collapse_submenu = collapse_submenu.clone();
+                move || {
+                    if !is_expanded.get() {
+                        return view! {};
+                    }
+                    let (items, id, label) =
+                        (submenu.clone(), submenu_id.clone(), submenu_label.clone());
+                    let (on_select, on_close, on_collapse) = (
+                        on_select.clone(),
+                        on_close.clone(),
+                        collapse_submenu.clone(),
+                    );
+                    view! {
+                        MenuList(
+                            items=items,
+                            id=id,
+                            label=label,
+                            on_select=on_select,
+                            on_close=on_close,
+                            on_collapse=on_collapse,
+                            menu_style=menu_style,
+                            submenu_style=submenu_style,
+                            item_style=item_style,
+                            item_active_style=item_active_style,
+                            item_disabled_style=item_disabled_style,
+                            separator_style=separator_style,
+                            shortcut_style=shortcut_style,
+                            style_mode=mode,
+                        )
+                    }
+                }
+            };
+
+            let item_id = menu_item_id(&id, index);
+            let role = if item.checked.is_some() {
+                "menuitemcheckbox"
+            } else {
+                "menuitem"
+            };
+            let checked = item.checked.map(|checked| checked.to_string());
+            let shortcuts = (!item.shortcut.is_empty()).then(|| item.shortcut.clone());
+            let label = item.label.clone();
+
+            // The children of a menu item are presentational, so a submenu
+            // sits next to its trigger instead of inside it.
+            view! {
+                li(
+                    role="none",
+                    class=mode.name(css::MENU_ENTRY),
+                    style=mode.inline("position: relative;"),
+                ) {
+                    div(
+                        id=item_id,
+                        "role"=role,
+                        aria-checked=checked,
+                        aria-disabled=disabled.then_some("true"),
+                        aria-haspopup=has_submenu.then_some("menu"),
+                        aria-expanded=move || has_submenu.then(|| is_expanded.get().to_string()),
+                        aria-keyshortcuts=shortcuts,
+                        class=move || mode.class(&item_class(), ""),
+                        style=entry_style,
+                        on:click=on_click,
+                        on:mouseenter=on_mouse_enter,
+                    ) {
+                        (check)
+                        (icon)
+                        span { (label) }
+                        (shortcut)
+                        (arrow)
+                    }
+                    (submenu)
+                }
+            }
+        })
+        .collect();
+
+    let active_descendant = {
+        let id = id.clone();
+        move || menu.with(|menu| menu.active.map(|index| menu_item_id(&id, index)))
+    };
+    let label = (!label.is_empty()).then_some(label);
+
+    view! {
+        ul(
+            r#ref=list_ref,
+            id=id,
+            role="menu",
+            tabindex="-1",
+            aria-label=label,
+            aria-orientation="vertical",
+            aria-activedescendant=active_descendant,
+            class=mode.name(class),
+            style=mode.inline(&style).to_string(),
+            on:keydown=on_key_down,
+        ) {
+            (entries)
+        }
+    }
+}
+
+#[derive(Props)]
+pub struct MoreMenuProps {
+    #[prop(default)]
+    pub items: Vec<MenuItem>,
+    /// Id of the menu; generated per instance when empty.
+    #[prop(default, setter(into))]
+    pub id: String,
+    #[prop(default, setter(into))]
+    pub on_select: Callback<String>,
+    #[prop(default, setter(into))]
+    pub on_click: Callback<()>,
+    #[prop(default)]
+    pub tabindex: &'static str,
+    #[prop(default)]
+    pub button_style: &'static str,
+    #[prop(default)]
+    pub button_class: &'static str,
+    #[prop(default)]
+    pub style_mode: StyleMode,
+}
+
+#[component]
+pub fn MoreMenu(props: MoreMenuProps) -> View {
+    let id = element_id(&props.id, "browser-more-menu");
+    let open = create_signal(false);
+    let button_ref = create_node_ref();
+    let has_menu = !props.items.is_empty();
+
+    let close = Callback::from(move |()| {
+        open.set(false);
+        if let Some(button) = button_ref
+            .try_get()
+            .and_then(|node| node.dyn_into::<HtmlElement>().ok())
+        {
+            let _ = button.focus();
+        }
+    });
+    let on_click = {
+        let on_click = props.on_click.clone();
+        move |_: MouseEvent| {
+            on_click.call(());
+            if has_menu {
+                open.set(!open.get());
+            }
+        }
+    };
+    let on_key_down = move |e: KeyboardEvent| {
+        if has_menu && e.key() == "ArrowDown" {
+            e.prevent_default();
+            open.set(true);
+        }
+    };
+
+    let mode = props.style_mode;
+    let menu = {
+        let (items, id, on_select) = (props.items, id.clone(), props.on_select);
+        move || {
+            if !open.get() {
+                return view! {};
+            }
+            let (items, id) = (items.clone(), id.clone());
+            let (on_select, on_close) = (on_select.clone(), close.clone());
+            view! {
+                div(
+                    class=mode.name(css::BACKDROP),
+                    style=mode.inline("position: fixed; inset: 0; z-index: 40;"),
+                    on:click=move |_: MouseEvent| open.set(false),
+                )
+                MenuList(
+                    items=items,
+                    id=id,
+                    label="More options",
+                    on_select=on_select,
+                    on_close=on_close,
+                    style_mode=mode,
+                )
+            }
+        }
+    };
+
+    view! {
+        div(
+            class=mode.name(css::POPUP),
+            style=mode.inline("position: relative; display: flex;"),
+        ) {
+            button(
+                r#ref=button_ref,
+                class=props.button_class,
+                style=props.button_style,
+                on:click=on_click,
+                on:keydown=on_key_down,
+                aria-label="More options",
+                aria-haspopup=has_menu.then_some("menu"),
+                aria-expanded=move || has_menu.then(|| open.get().to_string()),
+                aria-controls=move || (has_menu && open.get()).then(|| id.clone()),
+                title="More options",
+                tabindex=props.tabindex,
+            ) {
+                svg(width="18", height="19", viewBox="0 0 18 19", fill="none", xmlns="http://www.w3.org/2000/svg") {
+                    path(d=icons::MORE, fill="#767676")
+                }
+            }
+            (menu)
+        }
+    }
+}
+
+/// A button rendered in the header toolbar.
+///
+/// Build one with [`ToolbarItem::new`] and the `with_*` methods. Within a
+/// placement, items are shown by ascending `order`; when the header is too
+/// narrow the last ones move into the "More options" menu.
+#[derive(Clone, Default)]
+pub struct ToolbarItem {
+    pub id: String,
+    /// Renders the icon; called again whenever the item moves.
+    pub icon: Option<Rc<dyn Fn() -> View>>,
+    pub label: String,
+    /// Tooltip shown on hover; falls back to `label`.
+    pub tooltip: String,
+    /// Short text drawn over the icon, such as an unread count.
+    pub badge: Option<String>,
+    /// Makes the item a toggle button in the given state.
+    pub toggled: Option<bool>,
+    pub placement: ToolbarPlacement,
+    pub order: i32,
+    pub on_click: Callback<()>,
+}
+
+impl ToolbarItem {
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_icon(mut self, icon: impl Fn() -> View + 'static) -> Self {
+        self.icon = Some(Rc::new(icon));
+        self
+    }
+
+    pub fn with_tooltip(mut self, tooltip: impl Into<String>) -> Self {
+        self.tooltip = tooltip.into();
+        self
+    }
+
+    pub fn with_badge(mut self, badge: impl Into<String>) -> Self {
+        self.badge = Some(badge.into());
+        self
+    }
+
+    pub fn with_toggled(mut self, toggled: bool) -> Self {
+        self.toggled = Some(toggled);
+        self
+    }
+
+    pub fn with_placement(mut self, placement: ToolbarPlacement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    pub fn with_order(mut self, order: i32) -> Self {
+        self.order = order;
+        self
+    }
+
+    pub fn with_on_click(mut self, on_click: impl Into<Callback<()>>) -> Self {
+        self.on_click = on_click.into();
+        self
+    }
+
+    /// The entry representing this item once it overflows into the menu.
+    pub fn to_menu_item(&self) -> MenuItem {
+        let label = match &self.badge {
+            Some(badge) => format!("{} ({})", self.label, badge),
+            None => self.label.clone(),
+        };
+        MenuItem {
+            checked: self.toggled,
+            ..MenuItem::new(self.id.clone(), label)
+        }
+    }
+}
+
+#[derive(Props)]
+pub struct ToolbarButtonProps {
+    pub item: ToolbarItem,
+    #[prop(default)]
+    pub button_style: &'static str,
+    #[prop(default)]
+    pub button_class: &'static str,
+    #[prop(default = style::TOOLBAR_TOGGLED_STYLE)]
+    pub toggled_style: &'static str,
+    #[prop(default = style::TOOLBAR_BADGE_STYLE)]
+    pub badge_style: &'static str,
+    #[prop(default)]
+    pub style_mode: StyleMode,
+}
+
+#[component]
+pub fn ToolbarButton(props: ToolbarButtonProps) -> View {
+    let item = props.item;
+    let mode = props.style_mode;
+    let toggled = item.toggled == Some(true);
+    let style = if toggled {
+        format!(
+            "{} position: relative; {}",
+            props.button_style, props.toggled_style
+        )
+    } else {
+        format!("{} position: relative;", props.button_style)
+    };
+    let class = if toggled {
+        mode.class(css::TOOLBAR_BUTTON_TOGGLED, props.button_class)
+    } else {
+        mode.class(css::TOOLBAR_BUTTON, props.button_class)
+    };
+    let aria_label = match &item.badge {
+        Some(badge) => format!("{}, {}", item.label, badge),
+        None => item.label.clone(),
+    };
+    let title = if item.tooltip.is_empty() {
+        item.label.clone()
+    } else {
+        item.tooltip.clone()
+    };
+    let icon = item.icon.as_ref().map_or_else(View::default, |icon| icon());
+    let badge = match item.badge {
+        Some(badge) => view! {
+            span(
+                class=mode.name(css::TOOLBAR_BADGE),
+                style=mode.inline(props.badge_style),
+                aria-hidden="true",
+            ) {
+                (badge)
+            }
+        },
+        None => view! {},
+    };
+    let on_click = item.on_click;
+    let pressed = item.toggled.map(|toggled| toggled.to_string());
+
+    view! {
+        button(
+            r#type="button",
+            class=class,
+            style=mode.inline(&style).to_string(),
+            on:click=move |_: MouseEvent| on_click.call(()),
+            aria-label=aria_label,
+            aria-pressed=pressed,
+            title=title,
+        ) {
+            (icon)
+            (badge)
+        }
+    }
+}
+
+#[derive(Props)]
+pub struct BrowserHeaderProps {
+    /// Prefix of the ids of the address bar and the "More options" menu;
+    /// each generates its own when empty.
+    #[prop(default, setter(into))]
+    pub id_prefix: String,
+    #[prop(default = "".into(), setter(into))]
+    pub url: MaybeDyn<Cow<'static, str>>,
+    #[prop(default)]
+    pub placeholder: &'static str,
+    #[prop(default, setter(into))]
+    pub on_url_change: Callback<Event>,
+    #[prop(default)]
+    pub suggestions: SuggestionProvider,
+    #[prop(default, setter(into))]
+    pub on_suggestion_select: Callback<Suggestion>,
+    #[prop(default, setter(into))]
+    pub on_submit: Callback<String>,
+    #[prop(default = true)]
+    pub smart_display: bool,
+    #[prop(default = "".into(), setter(into))]
+    pub title: MaybeDyn<Cow<'static, str>>,
+    #[prop(default = "".into(), setter(into))]
+    pub favicon: MaybeDyn<Cow<'static, str>>,
+    #[prop(default, setter(into))]
+    pub on_close: Callback<()>,
+    #[prop(default, setter(into))]
+    pub on_minimize: Callback<()>,
+    #[prop(default, setter(into))]
+    pub on_maximize: Callback<()>,
+    #[prop(default = true)]
+    pub show_controls: bool,
+    #[prop(default = false.into(), setter(into))]
+    pub inactive: MaybeDyn<bool>,
+    #[prop(default = true)]
+    pub show_address_bar: bool,
+    #[prop(default)]
+    pub read_only: bool,
+    #[prop(default)]
+    pub variant: Variant,
+    #[prop(default)]
+    pub size: Size,
+    #[prop(default)]
+    pub style_mode: StyleMode,
+    #[prop(default)]
+    pub toolbar_items: Vec<ToolbarItem>,
+    #[prop(default = 32.0)]
+    pub toolbar_item_width: f64,
+    #[prop(default = 420.0)]
+    pub toolbar_reserved_width: f64,
+    #[prop(default)]
+    pub class: &'static str,
+    #[prop(default)]
+    pub input_class: &'static str,
+    #[prop(default)]
+    pub container_class: &'static str,
+    #[prop(default)]
+    pub refresh_button_style: &'static str,
+    #[prop(default = REFRESH_LABEL)]
+    pub refresh_button_aria_label: &'static str,
+    #[prop(default = LoadState::default().into(), setter(into))]
+    pub load_state: MaybeDyn<LoadState>,
+    #[prop(default, setter(strip_option, into))]
+    pub on_refresh: Option<Callback<()>>,
+    #[prop(default, setter(into))]
+    pub on_stop: Callback<()>,
+    #[prop(default = 1.0.into(), setter(into))]
+    pub zoom: MaybeDyn<f64>,
+    #[prop(default, setter(into))]
+    pub on_zoom_reset: Callback<()>,
+    #[prop(default, setter(into))]
+    pub on_error: Callback<DomError>,
+    #[prop(default = style::ICON_BUTTON_STYLE)]
+    pub icon_button_style: &'static str,
+    #[prop(default = style::ADDRESS_WRAPPER_BASE_STYLE)]
+    pub address_wrapper_base_style: &'static str,
+    #[prop(default = style::HEADER_BASE_STYLE)]
+    pub header_base_style: &'static str,
+    #[prop(default = style::PROGRESS_BAR_STYLE)]
+    pub progress_bar_style: &'static str,
+
+    #[prop(default)]
+    pub close_class: &'static str,
+    #[prop(default)]
+    pub close_aria_label: &'static str,
+    #[prop(default)]
+    pub close_title: &'static str,
+    #[prop(default = "0")]
+    pub close_tabindex: &'static str,
+    #[prop(default)]
+    pub minimize_class: &'static str,
+    #[prop(default)]
+    pub minimize_aria_label: &'static str,
+    #[prop(default)]
+    pub minimize_title: &'static str,
+    #[prop(default = "0")]
+    pub minimize_tabindex: &'static str,
+    #[prop(default)]
+    pub maximize_class: &'static str,
+    #[prop(default)]
+    pub maximize_aria_label: &'static str,
+    #[prop(default)]
+    pub maximize_title: &'static str,
+    #[prop(default = "0")]
+    pub maximize_tabindex: &'static str,
+
+    #[prop(default = true)]
+    pub share_menu: bool,
+    #[prop(default, setter(into))]
+    pub on_share: Callback<ShareTarget>,
+    #[prop(default, setter(into))]
+    pub share_onclick: Callback<()>,
+    #[prop(default)]
+    pub share_tabindex: &'static str,
+    #[prop(default, setter(into))]
+    pub tabs_onclick: Callback<()>,
+    #[prop(default)]
+    pub tabs_tabindex: &'static str,
+    #[prop(default)]
+    pub menu_items: Vec<MenuItem>,
+    #[prop(default, setter(into))]
+    pub on_menu_select: Callback<String>,
+    #[prop(default, setter(into))]
+    pub more_onclick: Callback<()>,
+    #[prop(default)]
+    pub more_tabindex: &'static str,
+}
+
+#[component]
+pub fn BrowserHeader(props: BrowserHeaderProps) -> View {
+    let action_toolbar = use_roving_tabindex(props.on_error.clone());
+    let (on_actions_key_down, on_actions_focus_in) = action_toolbar.handlers();
+    let is_ios = props.variant == Variant::Ios;
+    let base_style = header_style(props.header_base_style, &props.variant, &props.size);
+    let address_wrapper_style =
+        address_wrapper_style(props.address_wrapper_base_style, props.show_controls);
+    let mode = props.style_mode;
+    let icon_button_style = mode.inline(props.icon_button_style);
+    let icon_button_class = mode.name(css::ICON_BUTTON);
+    let progress_bar_style = mode.inline(props.progress_bar_style);
+    let load_state = props.load_state.clone();
+    let url = props.url.clone();
+
+    let header_ref = create_node_ref();
+    let header_width = use_element_width(header_ref, !props.toolbar_items.is_empty());
+    let toolbar_items = Rc::new(props.toolbar_items);
+    let toolbar = {
+        let positions: Vec<_> = toolbar_items
+            .iter()
+            .map(|item| (item.placement, item.order))
+            .collect();
+        let (reserved_width, item_width) = (props.toolbar_reserved_width, props.toolbar_item_width);
+        create_selector(move || {
+            layout_toolbar(
+                &positions,
+                header_width.get().map(|width| width - reserved_width),
+                item_width,
+            )
+        })
+    };
+    let toolbar_buttons = |group: fn(&ToolbarLayout) -> &Vec<usize>| {
+        let toolbar_items = toolbar_items.clone();
+        move || {
+            let indices = toolbar.with(|toolbar| group(toolbar).clone());
+            let buttons: Vec<View> = indices
+                .into_iter()
+                .map(|index| {
+                    let item = toolbar_items[index].clone();
+                    view! {
+                        ToolbarButton(
+                            item=item,
+                            button_style=icon_button_style,
+                            button_class=icon_button_class,
+                            style_mode=mode,
+                        )
+                    }
+                })
+                .collect();
+            View::from(buttons)
+        }
+    };
+    let left_buttons = toolbar_buttons(|toolbar| &toolbar.left);
+    let right_buttons = toolbar_buttons(|toolbar| &toolbar.right);
+
+    let title_bar = {
+        let (title, favicon) = (props.title.clone(), props.favicon.clone());
+        let show = create_selector({
+            let (title, favicon) = (title.clone(), favicon.clone());
+            move || !is_ios && (!title.get_clone().is_empty() || !favicon.get_clone().is_empty())
+        });
+        let variant = props.variant.clone();
+        move || {
+            if !show.get() {
+                return view! {};
+            }
+            let (title, favicon, variant) = (title.clone(), favicon.clone(), variant.clone());
+            view! {
+                TitleBar(title=title, favicon=favicon, variant=variant, style_mode=mode)
+            }
+        }
+    };
+
+    let controls = if props.show_controls {
+        let on_error = props.on_error.clone();
+        view! {
+            BrowserControls(
+                show_controls=true,
+                on_close=props.on_close,
+                on_minimize=props.on_minimize,
+                on_maximize=props.on_maximize,
+                close_class=props.close_class,
+                close_aria_label=props.close_aria_label,
+                close_title=props.close_title,
+                close_tabindex=props.close_tabindex,
+                minimize_class=props.minimize_class,
+                minimize_aria_label=props.minimize_aria_label,
+                minimize_title=props.minimize_title,
+                minimize_tabindex=props.minimize_tabindex,
+                maximize_class=props.maximize_class,
+                maximize_aria_label=props.maximize_aria_label,
+                maximize_title=props.maximize_title,
+                maximize_tabindex=props.maximize_tabindex,
+                inactive=props.inactive,
+                style_mode=mode,
+                on_error=on_error,
+            )
+            (if !is_ios {
+                view! {
+                    button(class=icon_button_class, style=icon_button_style, aria-label="Sidebar") {
+                        svg(width="20", height="15", viewBox="0 0 20 15", fill="none", xmlns="http://www.w3.org/2000/svg") {
+                            path(d=icons::SIDEBAR, fill="#767676")
+                        }
+                    }
+                    button(class=icon_button_class, style=icon_button_style, aria-label="Back") {
+                        svg(width="9", height="16", viewBox="0 0 9 16", fill="none", xmlns="http://www.w3.org/2000/svg") {
+                            path(d=icons::BACK, stroke="#737373", stroke-width="1.5", stroke-linecap="round", stroke-linejoin="round")
+                        }
+                    }
+                    button(
+                        class=mode.name(css::ICON_BUTTON_MUTED),
+                        style=icon_button_style,
+                        aria-label="Forward",
+                    ) {
+                        svg(width="9", height="16", viewBox="0 0 9 16", fill="none", xmlns="http://www.w3.org/2000/svg") {
+                            path(d=icons::FORWARD, stroke="#BFBFBF", stroke-width="1.5", stroke-linecap="round", stroke-linejoin="round")
+                        }
+                    }
+                }
+            } else {
+                view! {}
+            })
+        }
+    } else {
+        view! {}
+    };
+
+    let address_bar = if props.show_address_bar {
+        let mut address_bar = AddressBarProps::builder()
+            .input_id(part_id(&props.id_prefix, "address"))
+            .url(props.url)
+            .placeholder(props.placeholder)
+            .on_url_change(props.on_url_change)
+            .suggestions(props.suggestions)
+            .on_suggestion_select(props.on_suggestion_select)
+            .on_submit(props.on_submit)
+            .smart_display(props.smart_display)
+            .read_only(props.read_only)
+            .input_class(props.input_class)
+            .container_class(props.container_class)
+            .refresh_button_style(props.refresh_button_style)
+            .refresh_button_aria_label(props.refresh_button_aria_label)
+            .load_state(props.load_state)
+            .on_stop(props.on_stop)
+            .zoom(props.zoom)
+            .on_zoom_reset(props.on_zoom_reset)
+            .on_error(props.on_error)
+            .style_mode(mode)
+            .build();
+        address_bar.on_refresh = props.on_refresh;
+        let address_bar = AddressBar(address_bar);
+        let address_class = mode.name(if props.show_controls {
+            css::ADDRESS_WITH_CONTROLS
+        } else {
+            css::ADDRESS
+        });
+        view! {
+            div(class=address_class, style=mode.inline(&address_wrapper_style).to_string()) {
+                (address_bar)
+            }
+        }
+    } else {
+        view! {}
+    };
+
+    let share = if props.show_controls {
+        view! {
+            ShareMenu(
+                url=url,
+                title=props.title,
+                enabled=props.share_menu,
+                on_share=props.on_share,
+                on_click=props.share_onclick,
+                tabindex=props.share_tabindex,
+                button_style=icon_button_style,
+                button_class=icon_button_class,
+                style_mode=mode,
+            )
+        }
+    } else {
+        view! {}
+    };
+    let tabs = if props.show_controls {
+        let tabs_onclick = props.tabs_onclick;
+        view! {
+            button(
+                class=icon_button_class,
+                style=icon_button_style,
+                on:click=move |_: MouseEvent| tabs_onclick.call(()),
+                aria-label="Tabs",
+                title="Tabs",
+                tabindex=props.tabs_tabindex,
+            ) {
+                svg(width="15", height="15", viewBox="0 0 15 15", fill="none", xmlns="http://www.w3.org/2000/svg") {
+                    path(d=icons::TABS, fill="#767676")
+                }
+            }
+        }
+    } else {
+        view! {}
+    };
+
+    let on_menu_select = {
+        let toolbar_items = toolbar_items.clone();
+        let on_menu_select = props.on_menu_select;
+        Callback::from(
+            move |id: String| match toolbar_items.iter().find(|item| item.id == id) {
+                Some(item) => item.on_click.call(()),
+                None => on_menu_select.call(id),
+            },
+        )
+    };
+    let more = {
+        let show_controls = props.show_controls;
+        let menu_items = props.menu_items;
+        let id = part_id(&props.id_prefix, "more-menu");
+        let (on_click, tabindex) = (props.more_onclick, props.more_tabindex);
+        move || {
+            let overflow = toolbar.with(|toolbar| toolbar.overflow.clone());
+            if !show_controls && overflow.is_empty() {
+                return view! {};
+            }
+            let mut items: Vec<MenuItem> = overflow
+                .iter()
+                .map(|&index| toolbar_items[index].to_menu_item())
+                .collect();
+            if !items.is_empty() && !menu_items.is_empty() {
+                items.push(MenuItem::separator());
+            }
+            items.extend(menu_items.iter().cloned());
+            let (id, on_select, on_click) = (id.clone(), on_menu_select.clone(), on_click.clone());
+            view! {
+                MoreMenu(
+                    id=id,
+                    items=items,
+                    on_select=on_select,
+                    on_click=on_click,
+                    tabindex=tabindex,
+                    button_style=icon_button_style,
+                    button_class=icon_button_class,
+                    style_mode=mode,
+                )
+            }
+        }
+    };
+
+    view! {
+        (title_bar)
+        header(
+            r#ref=header_ref,
+            style=mode.inline(&base_style).to_string(),
+            class=mode.class(css::HEADER, props.class),
+            aria-label="Browser window header",
+        ) {
+            div(
+                class=mode.name(css::NAV),
+                style=mode.inline("display: flex; align-items: center; gap: 6px;"),
+            ) {
+                (controls)
+                (left_buttons)
+            }
+            (address_bar)
+            div(
+                r#ref=action_toolbar.node_ref,
+                class=mode.name(css::ACTIONS),
+                style=mode.inline("display: flex; align-items: center; gap: 6px; margin-left: auto;"),
+                role="toolbar",
+                aria-label="Page actions",
+                on:keydown=on_actions_key_down,
+                on:focusin=on_actions_focus_in,
+            ) {
+                (right_buttons)
+                (share)
+                (tabs)
+                (more)
+            }
+            (move || match load_state.get_clone().progress() {
+                Some(progress) => {
+                    let loading = load_state.get_clone();
+                    let hidden = (!loading.is_loading()).then_some("true");
+                    let style = format!("{} {}", progress_bar_style, loading.progress_style());
+                    view! {
+                        div(
+                            role="progressbar",
+                            aria-label="Page load progress",
+                            aria-valuemin="0",
+                            aria-valuemax="100",
+                            aria-valuenow=progress.to_string(),
+                            aria-hidden=hidden,
+                            class=mode.name(css::PROGRESS),
+                            style=style,
+                        )
+                    }
+                }
+                None => view! {},
+            })
+        }
+    }
+}
+
+#[derive(Props)]
+pub struct BookmarksBarProps {
+    #[prop(default)]
+    pub bookmarks: Vec<Bookmark>,
+    /// Called with the URL of the bookmark that was clicked.
+    #[prop(default, setter(into))]
+    pub on_select: Callback<String>,
+    /// Prefix of the bar's element ids; generated per instance when empty.
+    #[prop(default, setter(into))]
+    pub id: String,
+    #[prop(default)]
+    pub class: &'static str,
+    #[prop(default = style::BOOKMARKS_BAR_STYLE)]
+    pub style: &'static str,
+    #[prop(default = style::BOOKMARK_ITEM_STYLE)]
+    pub item_style: &'static str,
+    #[prop(default = style::BOOKMARK_MENU_STYLE)]
+    pub menu_style: &'static str,
+    #[prop(default = style::BOOKMARK_OVERFLOW_MENU_STYLE)]
+    pub overflow_menu_style: &'static str,
+    #[prop(default)]
+    pub style_mode: StyleMode,
+}
+
+#[component]
+pub fn BookmarksBar(props: BookmarksBarProps) -> View {
+    let mode = props.style_mode;
+    let bar_id = element_id(&props.id, "browser-bookmarks");
+    let list_ref = create_node_ref();
+    let list_width = use_element_width(list_ref, true);
+    let bookmarks = Rc::new(props.bookmarks);
+    let visible_count = create_signal(bookmarks.len());
+    let open_menu = create_signal(None::<(BookmarkMenu, i32)>);
+
+    {
+        let count = bookmarks.len();
+        create_effect(move || {
+            list_width.track();
+            // Every entry stays rendered so it can be measured; the ones that
+            // do not fit are hidden and listed behind the chevron.
+            if let Some(list) = list_ref
+                .try_get()
+                .and_then(|node| node.dyn_into::<Element>().ok())
+            {
+                visible_count.set(page::fitting_children(&list).min(count));
+            }
+        });
+    }
+
+    let close = {
+        let id = bar_id.clone();
+        Callback::from(move |()| {
+            if let Some((menu, _)) = open_menu.get() {
+                page::focus_by_id(&menu.trigger_id(&id));
+            }
+            open_menu.set(None);
+        })
+    };
+
+    let toggle_menu = move |menu: BookmarkMenu| {
+        move |e: MouseEvent| {
+            if bookmarks::is_open(open_menu.get(), menu) {
+                open_menu.set(None);
+                return;
+            }
+            let left = e
+                .current_target()
+                .and_then(|target| target.dyn_into::<HtmlElement>().ok())
+                .map_or(0, |trigger| trigger.offset_left());
+            open_menu.set(Some((menu, left)));
+        }
+    };
+
+    let menu = {
+        let (bookmarks, bar_id, on_select) =
+            (bookmarks.clone(), bar_id.clone(), props.on_select.clone());
+        let (menu_style, overflow_menu_style) = (props.menu_style, props.overflow_menu_style);
+        move || {
+            let Some((menu, left)) = open_menu.get() else {
+                return view! {};
+            };
+            let (items, label) = menu.entries(&bookmarks, visible_count.get());
+            let menu_style = match menu {
+                BookmarkMenu::Folder(_) => menu_style,
+                BookmarkMenu::Overflow => overflow_menu_style,
+            };
+            let position = menu.position(left, mode);
+            let (id, on_select, on_close) =
+                (menu.menu_id(&bar_id), on_select.clone(), close.clone());
+            view! {
+                div(
+                    class=mode.name(css::BACKDROP),
+                    style=mode.inline("position: fixed; inset: 0; z-index: 40;"),
+                    on:click=move |_: MouseEvent| open_menu.set(None),
+                )
+                div(class=mode.name(menu.class()), style=position) {
+                    MenuList(
+                        items=items,
+                        id=id,
+                        label=label,
+                        on_select=on_select,
+                        on_close=on_close,
+                        menu_style=menu_style,
+                        style_mode=mode,
+                    )
+                }
+            }
+        }
+    };
+
+    let item_style = mode.inline(props.item_style);
+    let entries: Vec<View> = bookmarks
+        .iter()
+        .enumerate()
+        .map(|(index, bookmark)| {
+            let icon = if !bookmark.icon.is_empty() {
+                let src = bookmark.icon.clone();
+                view! { img(src=src, alt="", width="14", height="14") }
+            } else if bookmark.is_folder() {
+                view! {
+                    svg(width="14", height="14", viewBox="0 0 16 16", fill="none", aria-hidden="true") {
+                        path(
+                            d="M1.5 3.5C1.5 2.95 1.95 2.5 2.5 2.5H6L7.5 4H13.5C14.05 4 14.5 4.45 14.5 5V12.5C14.5 13.05 14.05 13.5 13.5 13.5H2.5C1.95 13.5 1.5 13.05 1.5 12.5V3.5Z",
+                            stroke="#767676",
+                        )
+                    }
+                }
+            } else {
+                view! {
+                    svg(width="14", height="14", viewBox="0 0 16 16", fill="none", aria-hidden="true") {
+                        circle(cx="8", cy="8", r="6.5", stroke="#767676")
+                        path(
+                            d="M1.5 8H14.5M8 1.5C9.7 3.3 10.5 5.5 10.5 8C10.5 10.5 9.7 12.7 8 14.5C6.3 12.7 5.5 10.5 5.5 8C5.5 5.5 6.3 3.3 8 1.5Z",
+                            stroke="#767676",
+                        )
+                    }
+                }
+            };
+            let title = bookmark.title.clone();
+            let title = view! {
+                span(
+                    class=mode.name(css::BOOKMARK_TITLE),
+                    style=mode.inline("overflow: hidden; text-overflow: ellipsis;"),
+                ) {
+                    (title)
+                }
+            };
+
+            let entry = if bookmark.is_folder() {
+                let folder = BookmarkMenu::Folder(index);
+                let id = folder.trigger_id(&bar_id);
+                view! {
+                    button(
+                        id=id,
+                        r#type="button",
+                        class=mode.name(css::BOOKMARK),
+                        style=item_style,
+                        aria-haspopup="menu",
+                        aria-expanded=move || bookmarks::is_open(open_menu.get(), folder).to_string(),
+                        on:click=toggle_menu(folder),
+                    ) {
+                        (icon)
+                        (title)
+                    }
+                }
+            } else {
+                let on_click = {
+                    let on_select = props.on_select.clone();
+                    let url = bookmark.url.clone();
+                    move |e: MouseEvent| {
+                        e.prevent_default();
+                        on_select.call(url.clone());
+                    }
+                };
+                let (href, url) = (bookmark.url.clone(), bookmark.url.clone());
+                view! {
+                    a(
+                        href=href,
+                        class=mode.name(css::BOOKMARK),
+                        style=item_style,
+                        title=url,
+                        on:click=on_click,
+                    ) {
+                        (icon)
+                        (title)
+                    }
+                }
+            };
+
+            let hidden = move || index >= visible_count.get();
+            view! {
+                li(
+                    class=move || mode.name(if hidden() { css::BOOKMARK_ENTRY_HIDDEN } else { css::BOOKMARK_ENTRY }),
+                    style=move || mode.inline(if hidden() { "flex-shrink: 0; visibility: hidden;" } else { "flex-shrink: 0;" }),
+                ) {
+                    (entry)
+                }
+            }
+        })
+        .collect();
+
+    let overflow = {
+        let id = BookmarkMenu::Overflow.trigger_id(&bar_id);
+        let count = bookmarks.len();
+        move || {
+            if visible_count.get() >= count {
+                return view! {};
+            }
+            let id = id.clone();
+            view! {
+                button(
+                    id=id,
+                    r#type="button",
+                    class=mode.name(css::BOOKMARK),
+                    style=item_style,
+                    aria-label=MORE_BOOKMARKS_LABEL,
+                    aria-haspopup="menu",
+                    aria-expanded=move || {
+                        bookmarks::is_open(open_menu.get(), BookmarkMenu::Overflow).to_string()
+                    },
+                    on:click=toggle_menu(BookmarkMenu::Overflow),
+                ) {
+                    svg(width="12", height="12", viewBox="0 0 12 12", fill="none", aria-hidden="true") {
+                        path(
+                            d="M2.5 2.5L6 6L2.5 9.5M6.5 2.5L10 6L6.5 9.5",
+                            stroke="#767676",
+                            stroke-width="1.5",
+                            stroke-linecap="round",
+                            stroke-linejoin="round",
+                        )
+                    }
+                }
+            }
+        }
+    };
+
+    view! {
+        nav(
+            class=mode.class(css::BOOKMARKS, props.class),
+            style=mode.inline(props.style),
+            aria-label="Bookmarks",
+        ) {
+            ul(
+                r#ref=list_ref,
+                class=mode.name(css::BOOKMARK_LIST),
+                style=mode.inline("display: flex; flex: 1; min-width: 0; gap: 2px; margin: 0; padding: 0; list-style: none; overflow: hidden;"),
+            ) {
+                (entries)
+            }
+            (overflow)
+            (menu)
+        }
+    }
+}
+
+#[derive(Props)]
+pub struct FindBarProps {
+    /// Element whose rendered text is searched.
+    pub target: NodeRef,
+    #[prop(default, setter(into))]
+    pub on_close: Callback<()>,
+    #[prop(default, setter(into))]
+    pub on_error: Callback<DomError>,
+    /// Id of the bar; generated per instance when empty.
+    #[prop(default, setter(into))]
+    pub id: String,
+    #[prop(default)]
+    pub class: &'static str,
+    #[prop(default = style::FIND_BAR_STYLE)]
+    pub style: &'static str,
+    #[prop(default = style::FIND_INPUT_STYLE)]
+    pub input_style: &'static str,
+    #[prop(default = style::FIND_STATUS_STYLE)]
+    pub status_style: &'static str,
+    #[prop(default = style::FIND_BUTTON_STYLE)]
+    pub button_style: &'static str,
+    #[prop(default = style::FIND_HIGHLIGHT_COLOR)]
+    pub highlight_color: &'static str,
+    #[prop(default = style::FIND_CURRENT_HIGHLIGHT_COLOR)]
+    pub current_highlight_color: &'static str,
+    #[prop(default)]
+    pub style_mode: StyleMode,
+}
+
+#[component]
+pub fn FindBar(props: FindBarProps) -> View {
+    let announcer = use_announcer();
+    let id = element_id(&props.id, "browser-find");
+    let input_ref = create_node_ref();
+    let find = create_signal(FindState::default());
+    let ranges = Rc::new(RefCell::new(Vec::<Range>::new()));
+
+    {
+        let id = id.clone();
+        on_mount(move || {
+            if let Some(input) = input_ref
+                .try_get()
+                .and_then(|node| node.dyn_into::<HtmlElement>().ok())
+            {
+                let _ = input.focus();
+            }
+            // Highlights are registered under the bar's id, so only this
+            // bar's are removed when it unmounts.
+            on_cleanup(move || page::clear_find_highlights(&id));
+        });
+    }
+
+    let on_input = {
+        let (id, ranges, target) = (id.clone(), ranges.clone(), props.target);
+        let on_error = props.on_error.clone();
+        move |e: Event| {
+            let value = e
+                .target()
+                .and_then(|target| target.dyn_into::<HtmlInputElement>().ok())
+                .map(|input| input.value())
+                .unwrap_or_default();
+            let found = match target.try_get() {
+                Some(root) if !value.is_empty() => page::find_ranges(&root, &value),
+                _ => Vec::new(),
+            };
+            let mut state = find.get_clone();
+            let first = state.search(value, found.len());
+            page::show_find_matches(&id, &found, first);
+            if !state.query.is_empty() {
+                if let Err(error) = announcer.say(&state.status(), Politeness::Polite) {
+                    on_error.call(error);
+                }
+            }
+            *ranges.borrow_mut() = found;
+            find.set(state);
+        }
+    };
+
+    let step = {
+        let (id, on_error) = (id.clone(), props.on_error.clone());
+        Callback::from(move |forward: bool| {
+            let mut state = find.get_clone();
+            let Some(next) = state.step(forward) else {
+                return;
+            };
+            page::show_find_matches(&id, &ranges.borrow(), Some(next));
+            if let Err(error) = announcer.say(&state.status(), Politeness::Polite) {
+                on_error.call(error);
+            }
+            find.set(state);
+        })
+    };
+
+    let on_key_down = {
+        let (step, on_close) = (step.clone(), props.on_close.clone());
+        move |e: KeyboardEvent| {
+            let key = e.key();
+            let input = KeyInput::new(&key).with_shift(e.shift_key());
+            match find_command(&input) {
+                Some(FindCommand::Next) => step.call(true),
+                Some(FindCommand::Previous) => step.call(false),
+                Some(FindCommand::Close) => {
+                    e.stop_propagation();
+                    on_close.call(());
+                }
+                None => return,
+            }
+            e.prevent_default();
+        }
+    };
+
+    let highlight_css = highlight_css(&id, props.highlight_color, props.current_highlight_color);
+    let mode = props.style_mode;
+    let button_class = mode.name(css::FIND_BUTTON);
+    let button_style = mode.inline(props.button_style);
+    let input_id = format!("{id}-input");
+    let status_id = format!("{id}-status");
+    let describedby = status_id.clone();
+    let (previous, next, on_close) = (step.clone(), step, props.on_close);
+
+    view! {
+        div(
+            id=id,
+            class=mode.class(css::FIND, props.class),
+            style=mode.inline(props.style),
+            role="search",
+            aria-label="Find in page",
+        ) {
+            style { (highlight_css) }
+            input(
+                r#ref=input_ref,
+                id=input_id,
+                r#type="search",
+                class=mode.name(css::FIND_INPUT),
+                style=mode.inline(props.input_style),
+                placeholder="Find in page",
+                aria-label="Find in page",
+                aria-describedby=describedby,
+                on:input=on_input,
+                on:keydown=on_key_down,
+            )
+            span(
+                id=status_id,
+                class=mode.name(css::FIND_STATUS),
+                style=mode.inline(props.status_style),
+            ) {
+                (find.with(|find| find.visible_status()))
+            }
+            button(
+                r#type="button",
+                class=button_class,
+                style=button_style,
+                aria-label="Previous match",
+                disabled=move || find.with(|find| find.total == 0),
+                on:click=move |_: MouseEvent| previous.call(false),
+            ) {
+                svg(width="14", height="14", viewBox="0 0 16 16", fill="none", aria-hidden="true") {
+                    path(d="M4 10L8 6L12 10", stroke="#767676", stroke-width="1.5", stroke-linecap="round", stroke-linejoin="round")
+                }
+            }
+            button(
+                r#type="button",
+                class=button_class,
+                style=button_style,
+                aria-label="Next match",
+                disabled=move || find.with(|find| find.total == 0),
+                on:click=move |_: MouseEvent| next.call(true),
+            ) {
+                svg(width="14", height="14", viewBox="0 0 16 16", fill="none", aria-hidden="true") {
+                    path(d="M4 6L8 10L12 6", stroke="#767676", stroke-width="1.5", stroke-linecap="round", stroke-linejoin="round")
+                }
+            }
+            button(
+                r#type="button",
+                class=button_class,
+                style=button_style,
+                aria-label="Close find bar",
+                on:click=move |_: MouseEvent| on_close.call(()),
+            ) {
+                svg(width="14", height="14", viewBox="0 0 16 16", fill="none", aria-hidden="true") {
+                    path(d="M4 4L12 12M12 4L4 12", stroke="#767676", stroke-width="1.5", stroke-linecap="round")
+                }
+            }
+        }
+    }
+}
+
+/// Tracks the width in pixels of the element behind `node_ref` with a
+/// `ResizeObserver` while the calling component is mounted and `enabled` is
+/// true.
+pub fn use_element_width(node_ref: NodeRef, enabled: bool) -> ReadSignal<Option<f64>> {
+    let width = create_signal(None::<f64>);
+
+    on_mount(move || {
+        let observer = node_ref
+            .try_get()
+            .and_then(|node| node.dyn_into::<Element>().ok())
+            .filter(|_| enabled)
+            .and_then(|element| {
+                let observed = element.clone();
+                let callback = Closure::<dyn Fn()>::new(move || {
+                    width.set(Some(observed.client_width() as f64));
+                });
+                let observer = ResizeObserver::new(callback.as_ref().unchecked_ref()).ok()?;
+                observer.observe(&element);
+                Some((observer, callback))
+            });
+        on_cleanup(move || {
+            if let Some((observer, _callback)) = observer {
+                observer.disconnect();
+            }
+        });
+    });
+
+    *width
+}
+
+/// A WAI-ARIA toolbar with a roving tabindex, returned by
+/// [`use_roving_tabindex`]. Put `node_ref` on the toolbar element and attach
+/// the [`RovingTabindex::handlers`] to its `keydown` and `focusin` events.
+pub struct RovingTabindex {
+    pub node_ref: NodeRef,
+    on_error: Callback<DomError>,
+}
+
+impl RovingTabindex {
+    fn toolbar(node_ref: NodeRef) -> Option<Element> {
+        node_ref.try_get()?.dyn_into::<Element>().ok()
+    }
+
+    /// The `keydown` and `focusin` handlers of the toolbar.
+    pub fn handlers(&self) -> (impl Fn(KeyboardEvent) + use<>, impl Fn(FocusEvent) + use<>) {
+        let (node_ref, on_error) = (self.node_ref, self.on_error.clone());
+        let on_key_down = move |e: KeyboardEvent| {
+            if let Some(toolbar) = Self::toolbar(node_ref) {
+                if let Err(error) = dom::rove_toolbar(&e, &toolbar) {
+                    on_error.call(error);
+                }
+            }
+        };
+        let (node_ref, on_error) = (self.node_ref, self.on_error.clone());
+        let on_focus_in = move |e: FocusEvent| {
+            if let Some(toolbar) = Self::toolbar(node_ref) {
+                if let Err(error) = dom::sync_roving_tabindex(&toolbar, e.target().as_ref()) {
+                    on_error.call(error);
+                }
+            }
+        };
+        (on_key_down, on_focus_in)
+    }
+}
+
+/// Makes a toolbar a single tab stop whose buttons are reached with the
+/// arrow keys, `Home` and `End`.
+pub fn use_roving_tabindex(on_error: Callback<DomError>) -> RovingTabindex {
+    let node_ref = create_node_ref();
+    {
+        let on_error = on_error.clone();
+        on_mount(move || {
+            if let Some(toolbar) = RovingTabindex::toolbar(node_ref) {
+                if let Err(error) = dom::sync_roving_tabindex(&toolbar, None) {
+                    on_error.call(error);
+                }
+            }
+        });
+    }
+    RovingTabindex { node_ref, on_error }
+}
+
+/// Reads messages to screen readers through live regions shared by every
+/// frame on the page.
+///
+/// Get one with [`use_announcer`]. The texts of [`Announcement`]s come from
+/// an `AnnouncerMessages` context when one is provided, e.g. with
+/// `provide_context(messages)`.
+#[derive(Clone, Copy, PartialEq, Default)]
+pub struct Announcer {
+    messages: AnnouncerMessages,
+}
+
+impl Announcer {
+    /// Reads `message` on the `politeness` channel.
+    pub fn say(&self, message: &str, politeness: Politeness) -> Result<(), DomError> {
+        dom::announce(message, politeness)
+    }
+
+    /// Reads the message for `announcement`.
+    pub fn announce(&self, announcement: &Announcement) -> Result<(), DomError> {
+        self.say(
+            &self.messages.message(announcement),
+            announcement.politeness(),
+        )
+    }
+}
+
+pub fn use_announcer() -> Announcer {
+    let messages = try_use_context::<AnnouncerMessages>().unwrap_or_default();
+    Announcer { messages }
+}
+
+/// Whether the page is shown in a forced colors mode such as Windows High
+/// Contrast, updated while the component is mounted.
+pub fn use_forced_colors() -> ReadSignal<bool> {
+    let forced_colors = create_signal(false);
+
+    on_mount(move || {
+        let listener = dom::MediaListener::new(style::FORCED_COLORS_QUERY, move |matches| {
+            forced_colors.set(matches)
+        });
+        on_cleanup(move || drop(listener));
+    });
+
+    *forced_colors
+}
+
+#[derive(Clone, Default)]
+pub struct KeyboardNavigationOptions {
+    pub on_escape: Option<Callback<()>>,
+    pub on_enter: Option<Callback<()>>,
+    pub trap_focus: bool,
+    pub on_error: Option<Callback<DomError>>,
+}
+
+fn report_error(on_error: &Option<Callback<DomError>>, error: DomError) {
+    if let Some(on_error) = on_error {
+        on_error.call(error);
+    }
+}
+
+/// Listens for `Escape`, `Enter` and `Tab` on the window while the calling
+/// component is mounted, and returns the ref to put on its container.
+/// `Escape` only counts when it is pressed inside that container.
+pub fn use_keyboard(options: KeyboardNavigationOptions) -> NodeRef {
+    let container_ref = create_node_ref();
+
+    on_mount(move || {
+        let on_error = options.on_error.clone();
+        let closure =
+            Closure::<dyn Fn(web_sys::KeyboardEvent)>::new(move |event: web_sys::KeyboardEvent| {
+                let key = event.key();
+                let container = container_ref
+                    .try_get()
+                    .and_then(|node| node.dyn_into::<Element>().ok());
+
+                let inside = container
+                    .as_ref()
+                    .is_none_or(|container| dom::started_inside(&event, container));
+
+                match navigation_command(&KeyInput::new(&key), options.trap_focus, inside) {
+                    Some(NavigationCommand::Escape) => {
+                        if let Some(callback) = &options.on_escape {
+                            event.prevent_default();
+                            callback.call(());
+                        }
+                    }
+                    Some(NavigationCommand::Enter) => {
+                        if let Some(callback) = &options.on_enter {
+                            let on_container = container.is_some_and(|container| {
+                                dom::origin(&event).as_ref() == Some(container.as_ref())
+                            });
+                            if on_container {
+                                event.prevent_default();
+                                callback.call(());
+                            }
+                        }
+                    }
+                    Some(NavigationCommand::TrapFocus) => {
+                        if let Some(container) = container {
+                            if let Err(error) = dom::trap_focus(&event, &container) {
+                                report_error(&options.on_error, error);
+                            }
+                        }
+                    }
+                    None => {}
+                }
+            });
+
+        let listening = dom::window().and_then(|window| {
+            dom::add_event_listener(&window, "keydown", closure.as_ref().unchecked_ref())
+                .map(|_| window)
+        });
+        if let Err(error) = &listening {
+            report_error(&on_error, error.clone());
+        }
+
+        on_cleanup(move || {
+            if let Ok(window) = listening {
+                if let Err(error) =
+                    dom::remove_event_listener(&window, "keydown", closure.as_ref().unchecked_ref())
+                {
+                    report_error(&on_error, error);
+                }
+            }
+        });
+    });
+
+    container_ref
+}
+
+/// Properties for the `BrowserFrame` component.
+///
+/// Defaults match the Yew and Dioxus components.
+#[derive(Props)]
+pub struct BrowserFrameProps {
+    /// Child elements rendered inside the browser frame body.
+    #[prop(default)]
+    pub children: Children,
+
+    /// The URL displayed in the address bar.
+    #[prop(default = "".into(), setter(into))]
+    pub url: MaybeDyn<Cow<'static, str>>,
+
+    /// Placeholder text shown in the address input field.
+    #[prop(default)]
+    pub placeholder: &'static str,
+
+    /// Callback invoked when the address bar input changes.
+    #[prop(default, setter(into))]
+    pub on_url_change: Callback<Event>,
+
+    /// Source of the autocomplete suggestions shown under the address bar.
+    ///
+    /// Defaults to `SuggestionProvider::None`, which disables the dropdown.
+    #[prop(default)]
+    pub suggestions: SuggestionProvider,
+
+    /// Callback invoked when a suggestion is picked from the dropdown.
+    #[prop(default, setter(into))]
+    pub on_suggestion_select: Callback<Suggestion>,
+
+    /// Callback invoked with the URL the frame navigates to, submitted from
+    /// the address bar or picked from the bookmarks bar.
+    ///
+    /// The address bar shows the new URL. For bookmarks, an `iframe` inside
+    /// the content is also pointed at it.
+    #[prop(default, setter(into))]
+    pub on_navigate: Callback<String>,
+
+    /// Callback invoked with the text submitted from the address bar with
+    /// `Enter` or by picking a suggestion, before `on_navigate`.
+    #[prop(default, setter(into))]
+    pub on_submit: Callback<String>,
+
+    /// Shows the registrable domain prominently while the address bar is not
+    /// focused.
+    #[prop(default = true)]
+    pub smart_display: bool,
+
+    /// Title of the displayed page, shown in the title bar (`Variant::Default`)
+    /// or in the active tab (`Variant::Tabs`). Accepts strings and signals.
+    ///
+    /// When the content contains a same-origin `iframe`, the title of the
+    /// loaded document replaces this value.
+    #[prop(default = "".into(), setter(into))]
+    pub title: MaybeDyn<Cow<'static, str>>,
+
+    /// URL of the page icon shown next to the title. A globe icon is shown
+    /// when empty or when the image fails to load.
+    #[prop(default = "".into(), setter(into))]
+    pub favicon: MaybeDyn<Cow<'static, str>>,
+
+    /// Saved links shown in a bookmarks bar under the header. The bar is
+    /// hidden when empty.
+    #[prop(default)]
+    pub bookmarks: Vec<Bookmark>,
+
+    /// Enables the find-in-page bar, opened with `Cmd/Ctrl+F` while focus is
+    /// inside the frame. It searches the rendered text of the content.
+    #[prop(default = true)]
+    pub find_in_page: bool,
+
+    /// Zoom factor of the content, e.g. `1.25` for 125%. Accepts a number or
+    /// a signal. The level is shown in the address bar when it is not 100%.
+    #[prop(default = 1.0.into(), setter(into))]
+    pub zoom: MaybeDyn<f64>,
+
+    /// Callback invoked with the new zoom factor when it changes through the
+    /// `Cmd/Ctrl` `+`, `-` and `0` shortcuts or the address bar badge.
+    #[prop(default, setter(into))]
+    pub on_zoom_change: Callback<f64>,
+
+    /// Callback invoked when a DOM call fails, e.g. a missing `window`.
+    #[prop(default, setter(into))]
+    pub on_error: Callback<DomError>,
+
+    /// Callback invoked when the close button or `Escape` is pressed.
+    #[prop(default, setter(into))]
+    pub on_close: Callback<()>,
+
+    /// Callback invoked when the minimize button is clicked.
+    #[prop(default, setter(into))]
+    pub on_minimize: Callback<()>,
+
+    /// Callback invoked when the maximize button is clicked.
+    #[prop(default, setter(into))]
+    pub on_maximize: Callback<()>,
+
+    /// Whether to show the window controls.
+    ///
+    /// Defaults to `true`.
+    #[prop(default = true)]
+    pub show_controls: bool,
+
+    /// Whether the window is inactive, which draws the window controls grey
+    /// until the pointer or focus enters them. Accepts a bool or a signal.
+    ///
+    /// Defaults to `false`.
+    #[prop(default = false.into(), setter(into))]
+    pub inactive: MaybeDyn<bool>,
+
+    /// Whether to show the address bar.
+    ///
+    /// Defaults to `true`.
+    #[prop(default = true)]
+    pub show_address_bar: bool,
+
+    /// Whether the address bar is read-only.
+    #[prop(default)]
+    pub read_only: bool,
+
+    /// Size of the browser frame.
+    #[prop(default)]
+    pub size: Size,
+
+    /// Visual variant of the browser frame.
+    #[prop(default)]
+    pub variant: Variant,
+
+    /// How the frame chrome is styled. `StyleMode::Stylesheet` replaces the
+    /// inline default styles with stable `browser-frame__*` classes styled by
+    /// `browser_rs::common::css::STYLESHEET`.
+    #[prop(default)]
+    pub style_mode: StyleMode,
+
+    /// Transitions played when the frame opens, closes, minimizes or
+    /// maximizes. Skipped while the user prefers reduced motion.
+    #[prop(default)]
+    pub animation: Animation,
+
+    /// Callback invoked when a window transition finishes, or right away
+    /// when the phase doesn't animate. Unmount the frame here after
+    /// `AnimationPhase::Close`.
+    #[prop(default, setter(into))]
+    pub on_animation_end: Callback<AnimationPhase>,
+
+    /// Whether the host shows the frame minimized. Setting it plays the
+    /// minimize transition, and clearing it restores a minimized frame with
+    /// `AnimationPhase::Restore`. Accepts a bool or a signal.
+    ///
+    /// Defaults to `false`.
+    #[prop(default = false.into(), setter(into))]
+    pub minimized: MaybeDyn<bool>,
+
+    /// Buttons added to the header toolbar, placed left or right of the
+    /// address bar or inside the "More options" menu.
+    ///
+    /// Inline items that do not fit in the header collapse into the menu.
+    #[prop(default)]
+    pub toolbar_items: Vec<ToolbarItem>,
+
+    /// Width in pixels taken by each inline toolbar item when deciding how
+    /// many fit in the header.
+    #[prop(default = 32.0)]
+    pub toolbar_item_width: f64,
+
+    /// Header width in pixels kept free for the window controls, address bar
+    /// and built-in buttons before toolbar items start to collapse.
+    #[prop(default = 420.0)]
+    pub toolbar_reserved_width: f64,
+
+    /// CSS class applied to the outer container.
+    /// `browser_rs::common::tailwind::FRAME_CLASS` restores the Tailwind look.
+    #[prop(default)]
+    pub class: &'static str,
+
+    /// Default look of the outer container: background, border, radius and
+    /// shadow, following the system color scheme. Set to `""` when styling
+    /// the container through `class`.
+    #[prop(default = style::FRAME_BASE_STYLE)]
+    pub base_style: &'static str,
+
+    /// CSS class applied to the content area.
+    #[prop(default)]
+    pub frame_class: &'static str,
+
+    /// Inline styles applied to the outer container.
+    #[prop(default)]
+    pub style: &'static str,
+
+    /// Optional ID for the outer container.
+    #[prop(default)]
+    pub id: &'static str,
+
+    /// ARIA label for the outer container.
+    ///
+    /// Defaults to `"Browser window"`.
+    #[prop(default = "Browser window")]
+    pub aria_label: &'static str,
+
+    /// ID of the element describing the outer container.
+    #[prop(default)]
+    pub aria_describedby: &'static str,
+
+    /// CSS class applied to the address bar container.
+    #[prop(default)]
+    pub container_class: &'static str,
+
+    /// CSS class applied to the address input.
+    #[prop(default)]
+    pub input_class: &'static str,
+
+    /// Inline styles of the refresh button.
+    #[prop(default = style::REFRESH_BUTTON_STYLE)]
+    pub refresh_button_style: &'static str,
+
+    /// ARIA label of the refresh button.
+    ///
+    /// Defaults to `"Refresh"`.
+    #[prop(default = REFRESH_LABEL)]
+    pub refresh_button_aria_label: &'static str,
+
+    /// Loading state of the page, shown as a progress bar and a stop button.
+    #[prop(default = LoadState::default().into(), setter(into))]
+    pub load_state: MaybeDyn<LoadState>,
+
+    /// Callback invoked by the refresh button. Reloads the page when unset.
+    #[prop(default, setter(strip_option, into))]
+    pub on_refresh: Option<Callback<()>>,
+
+    /// Callback invoked by the stop button while loading.
+    #[prop(default, setter(into))]
+    pub on_stop: Callback<()>,
+
+    /// Inline styles of the header icon buttons.
+    #[prop(default = style::ICON_BUTTON_STYLE)]
+    pub icon_button_style: &'static str,
+
+    /// Base inline styles of the address bar wrapper.
+    #[prop(default = style::ADDRESS_WRAPPER_BASE_STYLE)]
+    pub address_wrapper_base_style: &'static str,
+
+    /// Base inline styles of the header.
+    #[prop(default = style::HEADER_BASE_STYLE)]
+    pub header_base_style: &'static str,
+
+    /// Opens the built-in share menu: the native share sheet through
+    /// `navigator.share` when available, otherwise a popover with "Copy link",
+    /// "Email" and a QR code for the current URL.
+    #[prop(default = true)]
+    pub share_menu: bool,
+
+    /// Callback invoked with the target the page was shared to.
+    #[prop(default, setter(into))]
+    pub on_share: Callback<ShareTarget>,
+
+    /// Callback invoked by the share button.
+    #[prop(default, setter(into))]
+    pub share_onclick: Callback<()>,
+
+    /// Callback invoked by the tabs button.
+    #[prop(default, setter(into))]
+    pub tabs_onclick: Callback<()>,
+
+    /// Items of the menu opened by the "More options" button. The button
+    /// only calls `more_onclick` when this is empty.
+    #[prop(default)]
+    pub menu_items: Vec<MenuItem>,
+
+    /// Callback invoked with the `id` of the selected menu item.
+    #[prop(default, setter(into))]
+    pub on_menu_select: Callback<String>,
+
+    /// Callback invoked by the "More options" button.
+    #[prop(default, setter(into))]
+    pub more_onclick: Callback<()>,
+}
+
+#[component]
+pub fn BrowserFrame(props: BrowserFrameProps) -> View {
+    let announcer = use_announcer();
+    let announce = {
+        let on_error = props.on_error.clone();
+        move |announcement: Announcement| {
+            if let Err(error) = announcer.announce(&announcement) {
+                on_error.call(error);
+            }
+        }
+    };
+
+    let animation = props.animation;
+    let animation_state = create_signal(AnimationState::start(
+        animation,
+        AnimationPhase::Open,
+        false,
+    ));
+    let start_animation = {
+        let on_animation_end = props.on_animation_end.clone();
+        move |phase: AnimationPhase| {
+            let state = AnimationState::start(animation, phase, dom::prefers_reduced_motion());
+            animation_state.set(state);
+            if state == AnimationState::Done(phase) {
+                on_animation_end.call(phase);
+            }
+        }
+    };
+    let on_close = {
+        let on_close = props.on_close.clone();
+        let start_animation = start_animation.clone();
+        let announce = announce.clone();
+        Callback::from(move |()| {
+            on_close.call(());
+            announce(Announcement::Closed);
+            start_animation(AnimationPhase::Close);
+        })
+    };
+    let on_minimize = {
+        let on_minimize = props.on_minimize.clone();
+        let start_animation = start_animation.clone();
+        let announce = announce.clone();
+        Callback::from(move |()| {
+            on_minimize.call(());
+            announce(Announcement::Minimized);
+            start_animation(AnimationPhase::Minimize);
+        })
+    };
+    let on_maximize = {
+        let on_maximize = props.on_maximize.clone();
+        let start_animation = start_animation.clone();
+        let announce = announce.clone();
+        Callback::from(move |()| {
+            on_maximize.call(());
+            announce(Announcement::Maximized);
+            start_animation(AnimationPhase::Maximize);
+        })
+    };
+
+    let container_ref = use_keyboard(KeyboardNavigationOptions {
+        on_escape: Some(on_close.clone()),
+        on_enter: None,
+        trap_focus: false,
+        on_error: Some(props.on_error.clone()),
+    });
+
+    {
+        let on_animation_end = props.on_animation_end.clone();
+        on_mount(move || {
+            if let AnimationState::Done(phase) = animation_state.get_untracked() {
+                on_animation_end.call(phase);
+            }
+        });
+    }
+    {
+        let on_animation_end = props.on_animation_end.clone();
+        create_effect(move || {
+            let AnimationState::Pending(phase) = animation_state.get() else {
+                return;
+            };
+            // Wait until the start style is in the DOM.
+            let on_animation_end = on_animation_end.clone();
+            on_mount(move || {
+                if dom::prefers_reduced_motion() {
+                    animation_state.set(AnimationState::Done(phase));
+                    on_animation_end.call(phase);
+                    return;
+                }
+                // Lay out the start style first so the switch transitions.
+                if let Some(container) = container_ref
+                    .try_get()
+                    .and_then(|node| node.dyn_into::<HtmlElement>().ok())
+                {
+                    let _ = container.offset_width();
+                }
+                animation_state.set(AnimationState::Running(phase));
+            });
+        });
+    }
+    {
+        let minimized = props.minimized;
+        create_effect(move || {
+            let minimized = minimized.get();
+            if let Some(phase) = animation_state.get_untracked().follow_minimized(minimized) {
+                start_animation(phase);
+            }
+        });
+    }
+    let on_transition_end = {
+        let on_animation_end = props.on_animation_end.clone();
+        move |e: TransitionEvent| {
+            if e.target() != e.current_target() {
+                return;
+            }
+            if let Some(phase) = animation_state.get().finished(&e.property_name()) {
+                animation_state.set(AnimationState::Done(phase));
+                on_animation_end.call(phase);
+            }
+        }
+    };
+
+    let current_url = create_signal(props.url.get_clone().into_owned());
+    {
+        let url = props.url.clone();
+        create_effect(move || current_url.set(url.get_clone().into_owned()));
+    }
+    let page_title = create_signal(props.title.get_clone().into_owned());
+    {
+        let title = props.title.clone();
+        create_effect(move || page_title.set(title.get_clone().into_owned()));
+    }
+    let page_favicon = create_signal(props.favicon.get_clone().into_owned());
+    {
+        let favicon = props.favicon.clone();
+        create_effect(move || page_favicon.set(favicon.get_clone().into_owned()));
+    }
+    let on_submit = {
+        let on_navigate = props.on_navigate.clone();
+        let on_submit = props.on_submit.clone();
+        move |url: String| {
+            current_url.set(url.clone());
+            on_submit.call(url.clone());
+            on_navigate.call(url);
+        }
+    };
+
+    {
+        let load_state = props.load_state.clone();
+        let was_loading = create_signal(false);
+        create_effect(move || {
+            let load_state = load_state.get_clone();
+            let previous = was_loading.get_untracked();
+            was_loading.set(load_state.is_loading());
+            let announcement = page_title
+                .with_untracked(|title| Announcement::for_load(previous, &load_state, title));
+            if let Some(announcement) = announcement {
+                announce(announcement);
+            }
+        });
+    }
+
+    on_mount(move || {
+        // `load` does not bubble, so listen in the capture phase to catch
+        // iframes anywhere inside the frame.
+        let closure = Closure::<dyn Fn(Event)>::new(move |event: Event| {
+            if let Some((title, favicon)) = page::read_page_info(&event) {
+                if !title.is_empty() {
+                    page_title.set(title);
+                }
+                page_favicon.set(favicon.unwrap_or_default());
+            }
+        });
+        let container = container_ref
+            .try_get()
+            .and_then(|node| node.dyn_into::<Element>().ok());
+        if let Some(container) = &container {
+            let _ = container.add_event_listener_with_callback_and_bool(
+                "load",
+                closure.as_ref().unchecked_ref(),
+                true,
+            );
+        }
+        on_cleanup(move || {
+            if let Some(container) = container {
+                let _ = container.remove_event_listener_with_callback_and_bool(
+                    "load",
+                    closure.as_ref().unchecked_ref(),
+                    true,
+                );
+            }
+        });
+    });
+
+    let navigate = {
+        let on_navigate = props.on_navigate.clone();
+        move |url: String| {
+            current_url.set(url.clone());
+            if let Some(iframe) = container_ref
+                .try_get()
+                .and_then(|node| node.dyn_into::<Element>().ok())
+                .and_then(|container| container.query_selector("iframe").ok().flatten())
+                .and_then(|iframe| iframe.dyn_into::<HtmlIFrameElement>().ok())
+            {
+                iframe.set_src(&url);
+            }
+            on_navigate.call(url);
+        }
+    };
+
+    let find_open = create_signal(false);
+    let content_ref = create_node_ref();
+
+    let zoom = create_signal(props.zoom.get());
+    {
+        let level = props.zoom.clone();
+        create_effect(move || zoom.set(level.get()));
+    }
+    let set_zoom = {
+        let on_zoom_change = props.on_zoom_change.clone();
+        Callback::from(move |level: f64| {
+            if (level - zoom.get_untracked()).abs() > f64::EPSILON {
+                zoom.set(level);
+                on_zoom_change.call(level);
+            }
+        })
+    };
+
+    let on_key_down = {
+        let find_in_page = props.find_in_page;
+        let set_zoom = set_zoom.clone();
+        move |e: KeyboardEvent| {
+            let key = e.key();
+            let input = KeyInput::new(&key)
+                .with_ctrl(e.ctrl_key())
+                .with_meta(e.meta_key());
+            let Some(command) = frame_command(&input, find_in_page) else {
+                return;
+            };
+            e.prevent_default();
+            match command {
+                FrameCommand::Find => {
+                    if !find_open.get() {
+                        find_open.set(true);
+                    } else if let Some(input) = container_ref
+                        .try_get()
+                        .and_then(|node| node.dyn_into::<Element>().ok())
+                        .and_then(|frame| frame.query_selector("input[type='search']").ok())
+                        .flatten()
+                        .and_then(|input| input.dyn_into::<HtmlInputElement>().ok())
+                    {
+                        let _ = input.focus();
+                        input.select();
+                    }
+                }
+                FrameCommand::ZoomIn => set_zoom.call(zoom_step(zoom.get(), true)),
+                FrameCommand::ZoomOut => set_zoom.call(zoom_step(zoom.get(), false)),
+                FrameCommand::ZoomReset => set_zoom.call(1.0),
+            }
+        }
+    };
+
+    let on_find_close = move |()| {
+        find_open.set(false);
+        if let Some(content) = content_ref
+            .try_get()
+            .and_then(|node| node.dyn_into::<HtmlElement>().ok())
+        {
+            let _ = content.focus();
+        }
+    };
+
+    let load_state = props.load_state.clone();
+    let mode = props.style_mode;
+    let combined_style = match mode {
+        StyleMode::Inline => frame_style(props.base_style, &props.size, props.style),
+        StyleMode::Stylesheet => props.style.to_string(),
+    };
+    let article_style = move || match animation_state.get().style(animation) {
+        "" => combined_style.clone(),
+        motion_style => format!("{combined_style} {motion_style}"),
+    };
+    let article_class = mode.class(&frame_class(&props.variant, &props.size), props.class);
+    let mut header = BrowserHeaderProps::builder()
+        .url(current_url)
+        .placeholder(props.placeholder)
+        .on_url_change(props.on_url_change)
+        .suggestions(props.suggestions)
+        .on_suggestion_select(props.on_suggestion_select)
+        .on_submit(on_submit)
+        .smart_display(props.smart_display)
+        .title(page_title)
+        .favicon(page_favicon)
+        .on_close(on_close)
+        .on_minimize(on_minimize)
+        .on_maximize(on_maximize)
+        .show_controls(props.show_controls)
+        .inactive(props.inactive)
+        .show_address_bar(props.show_address_bar)
+        .read_only(props.read_only)
+        .variant(props.variant)
+        .size(props.size)
+        .style_mode(mode)
+        .toolbar_items(props.toolbar_items)
+        .toolbar_item_width(props.toolbar_item_width)
+        .toolbar_reserved_width(props.toolbar_reserved_width)
+        .input_class(props.input_class)
+        .container_class(props.container_class)
+        .refresh_button_style(props.refresh_button_style)
+        .refresh_button_aria_label(props.refresh_button_aria_label)
+        .load_state(props.load_state)
+        .on_stop(props.on_stop)
+        .zoom(zoom)
+        .on_zoom_reset(move |()| set_zoom.call(1.0))
+        .on_error(props.on_error.clone())
+        .icon_button_style(props.icon_button_style)
+        .address_wrapper_base_style(props.address_wrapper_base_style)
+        .header_base_style(props.header_base_style)
+        .share_menu(props.share_menu)
+        .on_share(props.on_share)
+        .share_onclick(props.share_onclick)
+        .tabs_onclick(props.tabs_onclick)
+        .menu_items(props.menu_items)
+        .on_menu_select(props.on_menu_select)
+        .more_onclick(props.more_onclick)
+        .id_prefix(props.id)
+        .build();
+    header.on_refresh = props.on_refresh;
+
+    let header = BrowserHeader(header);
+    let bookmarks = if props.bookmarks.is_empty() {
+        view! {}
+    } else {
+        view! {
+            BookmarksBar(
+                id=part_id(props.id, "bookmarks"),
+                bookmarks=props.bookmarks,
+                on_select=navigate,
+                style_mode=mode,
+            )
+        }
+    };
+    let find_bar = {
+        let id = part_id(props.id, "find");
+        let on_error = props.on_error;
+        move || {
+            if !find_open.get() {
+                return view! {};
+            }
+            let (id, on_error) = (id.clone(), on_error.clone());
+            view! {
+                FindBar(
+                    id=id,
+                    target=content_ref,
+                    on_close=on_find_close,
+                    on_error=on_error,
+                    style_mode=mode,
+                )
+            }
+        }
+    };
+    let content = BrowserContent(
+        BrowserContentProps::builder()
+            .class(props.frame_class)
+            .node_ref(content_ref)
+            .zoom(zoom)
+            .style_mode(mode)
+            .children(props.children)
+            .build(),
+    );
+
+    view! {
+        article(
+            r#ref=container_ref,
+            id=props.id,
+            class=article_class,
+            style=article_style,
+            role="application",
+            aria-label=props.aria_label,
+            aria-describedby=props.aria_describedby,
+            aria-busy=move || load_state.get_clone().is_loading().then_some("true"),
+            tabindex="-1",
+            on:keydown=on_key_down,
+            on:transitionend=on_transition_end,
+        ) {
+            (header)
+            (bookmarks)
+            (find_bar)
+            (content)
+        }
+    }
+}
@@ -1,11 +1,37 @@
 #![doc = include_str!("../YEW.md")]
 
-use crate::common::{ButtonType, Size, Variant};
+use crate::common::{
+    ADDRESS_BAR_LABEL, Bookmark, ButtonType, ControlState, DisplayUrl, DomError, LoadState,
+    MenuItem, REFRESH_LABEL, ShareTarget, Size, Suggestion, SuggestionProvider, ToolbarPlacement,
+    Variant,
+    animation::{Animation, AnimationPhase, AnimationState},
+    announcer::{Announcement, AnnouncerMessages, Politeness},
+    bookmarks::{self, BookmarkMenu, MORE_BOOKMARKS_LABEL},
+    css::{self, StyleMode, frame_class},
+    describedby_ids, element_id,
+    find::{FindState, highlight_css},
+    highlight_segments, icons, inline_completion, is_default_zoom,
+    keyboard::{
+        AddressBarCommand, FindCommand, FrameCommand, KeyInput, NavigationCommand,
+        address_bar_command, find_command, frame_command, navigation_command,
+    },
+    layout_toolbar,
+    menu::{MenuAction, MenuState, TYPEAHEAD_TIMEOUT, menu_item_id, submenu_id},
+    part_id,
+    qr::QrCode,
+    share::ShareState,
+    share_mailto,
+    style::{self, address_bar_style, address_wrapper_style, frame_style, header_style},
+    zoom_label, zoom_step,
+};
+use crate::dom::{self, page};
 use gloo_timers::callback::Timeout;
 use web_sys::{
-    Element, HtmlInputElement, KeyboardEvent,
+    Element, HtmlElement, HtmlIFrameElement, HtmlInputElement, KeyboardEvent, Range,
+    ResizeObserver, TransitionEvent,
     wasm_bindgen::{JsCast, prelude::*},
 };
+use yew::platform::spawn_local;
 use yew::prelude::*;
 
 #[derive(Properties, PartialEq, Clone)]
@@ -22,44 +48,104 @@ pub struct AddressBarProps {
     #[prop_or_default]
     pub class: &'static str,
 
-    #[prop_or(
-        "flex: 1; margin-left: 1rem; margin-right: 1rem; border: 1px solid #d1d5db; border-radius: 0.375rem; padding-left: 0.75rem; padding-right: 0.75rem; font-size: 0.875rem; position: relative;"
-    )]
+    #[prop_or(style::ADDRESS_BAR_STYLE)]
     pub style: &'static str,
 
-    #[prop_or("Website address or search query")]
+    #[prop_or(ADDRESS_BAR_LABEL)]
     pub label: &'static str,
+    /// Hint read after the label, rendered in a visually hidden element.
     #[prop_or("Enter a website URL or search term. Press Enter to navigate.")]
+    pub description: &'static str,
+    /// Ids of further elements describing the input, space separated.
+    #[prop_or_default]
     pub describedby: &'static str,
-    #[prop_or("browser-url-input")]
-    pub input_id: &'static str,
+    /// Id of the input; generated per instance when empty.
+    #[prop_or_default]
+    pub input_id: String,
 
-    #[prop_or("text-black dark:text-white")]
+    #[prop_or_default]
     pub input_class: &'static str,
 
     #[prop_or_default]
     pub container_class: &'static str,
 
-    #[prop_or(
-        "position: absolute; top: 50%; right: 8px; transform: translateY(-50%); padding: 4px; background: none; border: none; box-shadow: none; outline: none; cursor: pointer;"
-    )]
+    #[prop_or(style::REFRESH_BUTTON_STYLE)]
     pub refresh_button_style: &'static str,
 
-    #[prop_or("Refresh")]
+    #[prop_or(REFRESH_LABEL)]
     pub refresh_button_aria_label: &'static str,
 
-    #[prop_or(
-        "background-color: transparent; padding-right: 2rem; border: none; outline: none; box-shadow: none; height: 100%;"
-    )]
+    #[prop_or_default]
+    pub load_state: LoadState,
+    #[prop_or_default]
+    pub on_refresh: Option<Callback<()>>,
+    #[prop_or_default]
+    pub on_stop: Callback<()>,
+    #[prop_or("Stop loading")]
+    pub stop_button_aria_label: &'static str,
+
+    #[prop_or(1.0)]
+    pub zoom: f64,
+    #[prop_or_default]
+    pub on_zoom_reset: Callback<()>,
+    #[prop_or(style::ZOOM_BUTTON_STYLE)]
+    pub zoom_button_style: &'static str,
+
+    #[prop_or(style::ADDRESS_INPUT_STYLE)]
     pub input_style: &'static str,
+
+    #[prop_or_default]
+    pub suggestions: SuggestionProvider,
+    #[prop_or(8)]
+    pub max_suggestions: usize,
+    #[prop_or_default]
+    pub on_suggestion_select: Callback<Suggestion>,
+    #[prop_or_default]
+    pub on_submit: Callback<String>,
+
+    #[prop_or_default]
+    pub listbox_class: &'static str,
+    #[prop_or(style::SUGGESTION_LISTBOX_STYLE)]
+    pub listbox_style: &'static str,
+    #[prop_or_default]
+    pub option_class: &'static str,
+    #[prop_or(style::SUGGESTION_OPTION_STYLE)]
+    pub option_style: &'static str,
+    #[prop_or(style::ACTIVE_ITEM_STYLE)]
+    pub option_active_style: &'static str,
+    #[prop_or(style::SUGGESTION_MATCH_STYLE)]
+    pub match_style: &'static str,
+
+    #[prop_or(true)]
+    pub smart_display: bool,
+    #[prop_or(style::URL_DISPLAY_STYLE)]
+    pub display_style: &'static str,
+    #[prop_or(style::URL_DOMAIN_STYLE)]
+    pub domain_style: &'static str,
+    #[prop_or(style::URL_PATH_STYLE)]
+    pub path_style: &'static str,
+
+    #[prop_or_default]
+    pub on_error: Callback<DomError>,
+
+    #[prop_or_default]
+    pub style_mode: StyleMode,
 }
 
 #[function_component(AddressBar)]
 pub fn address_bar(props: &AddressBarProps) -> Html {
+    let announcer = use_announcer();
+    let input_id = use_element_id(&props.input_id, "browser-url-input");
     let input_value = use_state(|| props.url.to_string());
     let is_focused = use_state(|| false);
     let input_ref = use_node_ref();
 
+    let query = use_state(String::new);
+    let suggestions = use_state(Vec::<Suggestion>::new);
+    let active_index = use_state(|| None::<usize>);
+    let is_open = use_state(|| false);
+    let request_id = use_mut_ref(|| 0u32);
+
     {
         let input_value = input_value.clone();
         use_effect_with(props.url.clone(), move |url| {
@@ -67,108 +153,423 @@ pub fn address_bar(props: &AddressBarProps) -> Html {
         });
     }
 
+    let listbox_id = format!("{input_id}-listbox");
+    let description_id = format!("{input_id}-description");
+    let option_id = {
+        let input_id = input_id.clone();
+        move |index: usize| format!("{}-option-{}", input_id, index)
+    };
+
+    // Renders a batch of suggestions and, while the user is typing forward,
+    // completes the input inline with the best match selected.
+    let show_suggestions = {
+        let input_ref = input_ref.clone();
+        let input_value = input_value.clone();
+        let suggestions = suggestions.clone();
+        let is_open = is_open.clone();
+        Callback::from(
+            move |(items, typed, grew): (Vec<Suggestion>, String, bool)| {
+                if grew {
+                    if let Some(completed) = items
+                        .first()
+                        .and_then(|first| inline_completion(&typed, &first.url))
+                    {
+                        if let Some(input) = input_ref.cast::<HtmlInputElement>() {
+                            input.set_value(&completed);
+                            // Selection offsets count UTF-16 code units.
+                            let _ = input.set_selection_range(
+                                typed.encode_utf16().count() as u32,
+                                completed.encode_utf16().count() as u32,
+                            );
+                        }
+                        input_value.set(completed);
+                    }
+                }
+                is_open.set(!items.is_empty());
+                suggestions.set(items);
+            },
+        )
+    };
+
     let on_input_change = {
         let input_value = input_value.clone();
         let on_url_change = props.on_url_change.clone();
+        let provider = props.suggestions.clone();
+        let max_suggestions = props.max_suggestions;
+        let query = query.clone();
+        let active_index = active_index.clone();
+        let request_id = request_id.clone();
+        let show_suggestions = show_suggestions.clone();
         Callback::from(move |e: InputEvent| {
             if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
                 let value = input.value();
+                let grew = !e.input_type().starts_with("delete");
                 input_value.set(value.clone());
+                query.set(value.clone());
+                active_index.set(None);
                 on_url_change.emit(e);
+
+                *request_id.borrow_mut() += 1;
+                let current = *request_id.borrow();
+                match provider.filter(&value, max_suggestions) {
+                    Some(items) => show_suggestions.emit((items, value, grew)),
+                    None => {
+                        if let Some(future) = provider.fetch(value.clone()) {
+                            let request_id = request_id.clone();
+                            let show_suggestions = show_suggestions.clone();
+                            spawn_local(async move {
+                                let mut items = future.await;
+                                if *request_id.borrow() == current {
+                                    items.truncate(max_suggestions);
+                                    show_suggestions.emit((items, value, grew));
+                                }
+                            });
+                        }
+                    }
+                }
             }
         })
     };
 
+    let select_suggestion = {
+        let input_value = input_value.clone();
+        let query = query.clone();
+        let is_open = is_open.clone();
+        let active_index = active_index.clone();
+        let on_suggestion_select = props.on_suggestion_select.clone();
+        let on_submit = props.on_submit.clone();
+        Callback::from(move |suggestion: Suggestion| {
+            let url = suggestion.url.clone();
+            input_value.set(url.clone());
+            query.set(url.clone());
+            is_open.set(false);
+            active_index.set(None);
+            on_suggestion_select.emit(suggestion);
+            on_submit.emit(url);
+        })
+    };
+
     let on_key_down = {
         let input_ref = input_ref.clone();
-        let value = (*input_value).clone();
+        let input_value = input_value.clone();
+        let query = query.clone();
+        let suggestions = suggestions.clone();
+        let active_index = active_index.clone();
+        let is_open = is_open.clone();
+        let select_suggestion = select_suggestion.clone();
+        let on_submit = props.on_submit.clone();
+        let on_error = props.on_error.clone();
+        let announcer = announcer.clone();
         Callback::from(move |e: KeyboardEvent| {
-            if e.key() == "Enter" {
-                e.prevent_default();
-                if let Some(input) = input_ref.cast::<HtmlInputElement>() {
-                    input.blur().ok();
+            let count = suggestions.len();
+            let expanded = *is_open && count > 0;
+            let key = e.key();
+            let Some(command) =
+                address_bar_command(&KeyInput::new(&key), *active_index, count, expanded)
+            else {
+                return;
+            };
+            e.prevent_default();
+            match command {
+                AddressBarCommand::Highlight(next) => {
+                    active_index.set(Some(next));
+                    input_value.set(suggestions[next].url.clone());
+                }
+                AddressBarCommand::Open => is_open.set(true),
+                AddressBarCommand::Dismiss => {
+                    e.stop_propagation();
+                    is_open.set(false);
+                    active_index.set(None);
+                    input_value.set((*query).clone());
                 }
+                AddressBarCommand::Submit(active) => {
+                    let value = match active {
+                        Some(index) => {
+                            let suggestion = suggestions[index].clone();
+                            let url = suggestion.url.clone();
+                            select_suggestion.emit(suggestion);
+                            url
+                        }
+                        None => {
+                            is_open.set(false);
+                            let value = (*input_value).clone();
+                            on_submit.emit(value.clone());
+                            value
+                        }
+                    };
+                    if let Some(input) = input_ref.cast::<HtmlInputElement>() {
+                        input.blur().ok();
+                    }
 
-                let document = web_sys::window().unwrap().document().unwrap();
-                let live_region = document.create_element("div").unwrap();
-                live_region.set_attribute("aria-live", "polite").unwrap();
-                live_region.set_attribute("aria-atomic", "true").unwrap();
-                live_region.set_class_name("sr-only");
-                live_region.set_attribute("style", "position: absolute; width: 1px; height: 1px; padding: 0; margin: -1px; overflow: hidden; clip: rect(0, 0, 0, 0); white-space: nowrap; border: 0;").unwrap();
-                live_region.set_text_content(Some(&format!("Navigating to {}", value)));
-                document.body().unwrap().append_child(&live_region).unwrap();
-
-                let live_region_clone = live_region.clone();
-                Timeout::new(1000, move || {
-                    let _ = document.body().unwrap().remove_child(&live_region_clone);
-                })
-                .forget();
+                    if let Err(error) = announcer.announce(&Announcement::Navigation(value)) {
+                        on_error.emit(error);
+                    }
+                }
             }
         })
     };
 
+    let select_on_mouse_up = use_mut_ref(|| false);
+
     let on_focus = {
         let is_focused = is_focused.clone();
+        let input_ref = input_ref.clone();
+        let select_on_mouse_up = select_on_mouse_up.clone();
         Callback::from(move |_| {
             is_focused.set(true);
+            if let Some(input) = input_ref.cast::<HtmlInputElement>() {
+                input.select();
+                *select_on_mouse_up.borrow_mut() = true;
+            }
+        })
+    };
+
+    // The click that focuses the input would otherwise collapse the selection
+    // made in `on_focus`.
+    let on_mouse_up = {
+        let select_on_mouse_up = select_on_mouse_up.clone();
+        Callback::from(move |e: MouseEvent| {
+            if select_on_mouse_up.replace(false) {
+                e.prevent_default();
+            }
         })
     };
 
     let on_blur = {
         let is_focused = is_focused.clone();
+        let is_open = is_open.clone();
+        let active_index = active_index.clone();
         Callback::from(move |_| {
             is_focused.set(false);
+            is_open.set(false);
+            active_index.set(None);
+        })
+    };
+
+    let on_refresh = {
+        let on_refresh = props.on_refresh.clone();
+        let on_error = props.on_error.clone();
+        Callback::from(move |_| match &on_refresh {
+            Some(on_refresh) => on_refresh.emit(()),
+            None => {
+                if let Err(error) = dom::reload() {
+                    on_error.emit(error);
+                }
+            }
         })
     };
 
+    let on_stop = {
+        let on_stop = props.on_stop.clone();
+        Callback::from(move |_| on_stop.emit(()))
+    };
+
+    let expanded = *is_open && !suggestions.is_empty();
+    let active_descendant = (*active_index).filter(|_| expanded).map(&option_id);
+
+    let display_url = if props.smart_display && !*is_focused {
+        DisplayUrl::parse(&input_value)
+    } else {
+        None
+    };
+    let zoomed = !is_default_zoom(props.zoom);
+    let mut input_style = if display_url.is_some() {
+        format!("{} color: transparent;", props.input_style)
+    } else {
+        props.input_style.to_string()
+    };
+    let mut display_style = props.display_style.to_string();
+    if zoomed {
+        input_style.push_str(" padding-right: 5rem;");
+        display_style.push_str(" right: 5rem;");
+    }
+    let mode = props.style_mode;
+    let bar_class = if zoomed {
+        css::ADDRESS_BAR_ZOOMED
+    } else {
+        css::ADDRESS_BAR
+    };
+    let input_class = if display_url.is_some() {
+        css::ADDRESS_INPUT_MASKED
+    } else {
+        css::ADDRESS_INPUT
+    };
+
     html! {
-        <div class={format!("{} {}", props.container_class, props.class)} style={props.style}>
+        <div
+            class={mode.class(bar_class, &format!("{} {}", props.container_class, props.class))}
+            style={mode.inline(&address_bar_style(props.style, *is_focused)).to_string()}
+        >
             <label
-                for={props.input_id}
-                class="sr-only"
-                style="position: absolute; width: 1px; height: 1px; padding: 0; margin: -1px; overflow: hidden; clip: rect(0, 0, 0, 0); white-space: nowrap; border: 0;"
+                for={input_id.clone()}
+                class={mode.name(css::VISUALLY_HIDDEN)}
+                style={mode.inline(style::VISUALLY_HIDDEN)}
             >
                 { props.label }
             </label>
+            if !props.description.is_empty() {
+                <span
+                    id={description_id.clone()}
+                    class={mode.name(css::VISUALLY_HIDDEN)}
+                    style={mode.inline(style::VISUALLY_HIDDEN)}
+                >
+                    { props.description }
+                </span>
+            }
             <input
                 ref={input_ref.clone()}
-                id={props.input_id}
+                id={input_id}
                 type="text"
+                role="combobox"
                 value={(*input_value).clone()}
                 oninput={on_input_change}
                 onkeydown={on_key_down}
                 onfocus={on_focus}
                 onblur={on_blur}
+                onmouseup={on_mouse_up}
                 placeholder={props.placeholder}
                 readonly={props.read_only}
-                class={props.input_class}
-                style={props.input_style}
-                aria-describedby={props.describedby}
+                class={mode.class(input_class, props.input_class)}
+                style={mode.inline(&input_style).to_string()}
+                aria-describedby={describedby_ids([
+                    if props.description.is_empty() { "" } else { description_id.as_str() },
+                    props.describedby,
+                ])}
+                aria-autocomplete={(!props.suggestions.is_none()).then_some("both")}
+                aria-expanded={if expanded { "true" } else { "false" }}
+                aria-controls={listbox_id.clone()}
+                aria-activedescendant={active_descendant}
                 autocomplete="url"
                 spellcheck={Some("false")}
             />
-            <button
-                style={props.refresh_button_style}
-                aria-label={props.refresh_button_aria_label}
-                onclick={Callback::from(|_| {
-                    let _ = web_sys::window().unwrap().location().reload();
-                })}
-            >
-                <svg
-                    width="11"
-                    height="13"
-                    viewBox="0 0 11 13"
-                    fill="none"
-                    xmlns="http://www.w3.org/2000/svg"
+            if let Some(display_url) = display_url {
+                <div
+                    aria-hidden="true"
+                    class={mode.class(css::URL, props.input_class)}
+                    style={mode.inline(&display_style).to_string()}
                 >
-                    <path
-                        d="M4.99385 1.00002L7.33006 3.33623L4.99385 5.67244M10 7.61925C10 10.1998 7.9081 12.2917 5.3276 12.2917C2.74709 12.2917 0.655182 10.1998 0.655182 7.61925C0.655182 5.03875 2.74709 2.94684 5.3276 2.94684C5.8737 2.94684 6.4957 2.94684 7.27443 3.33621"
-                        stroke="#767676"
-                        stroke-linecap="round"
-                        stroke-linejoin="round"
-                    />
-                </svg>
-            </button>
+                    <span>{ display_url.subdomain }</span>
+                    <span class={mode.name(css::URL_DOMAIN)} style={mode.inline(props.domain_style)}>
+                        { display_url.domain }
+                    </span>
+                    <span class={mode.name(css::URL_PATH)} style={mode.inline(props.path_style)}>
+                        { display_url.rest }
+                    </span>
+                </div>
+            }
+            if zoomed {
+                <button
+                    type="button"
+                    class={mode.name(css::ZOOM)}
+                    style={mode.inline(props.zoom_button_style)}
+                    title="Reset zoom"
+                    aria-label={format!("Zoom {}, reset to 100%", zoom_label(props.zoom))}
+                    onclick={let on_zoom_reset = props.on_zoom_reset.clone(); Callback::from(move |_| on_zoom_reset.emit(()))}
+                >
+                    { zoom_label(props.zoom) }
+                </button>
+            }
+            if props.load_state.is_loading() {
+                <button
+                    class={mode.name(css::REFRESH)}
+                    style={mode.inline(props.refresh_button_style)}
+                    aria-label={props.stop_button_aria_label}
+                    onclick={on_stop}
+                >
+                    <svg
+                        width="11"
+                        height="13"
+                        viewBox="0 0 11 13"
+                        fill="none"
+                        xmlns="http://www.w3.org/2000/svg"
+                    >
+                        <path
+                            d={icons::STOP}
+                            stroke="#767676"
+                            stroke-linecap="round"
+                            stroke-linejoin="round"
+                        />
+                    </svg>
+                </button>
+            } else {
+                <button
+                    class={mode.name(css::REFRESH)}
+                    style={mode.inline(props.refresh_button_style)}
+                    aria-label={props.refresh_button_aria_label}
+                    onclick={on_refresh}
+                >
+                    <svg
+                        width="11"
+                        height="13"
+                        viewBox="0 0 11 13"
+                        fill="none"
+                        xmlns="http://www.w3.org/2000/svg"
+                    >
+                        <path
+                            d={icons::REFRESH}
+                            stroke="#767676"
+                            stroke-linecap="round"
+                            stroke-linejoin="round"
+                        />
+                    </svg>
+                </button>
+            }
+            if expanded {
+                <ul
+                    id={listbox_id}
+                    role="listbox"
+                    aria-label={props.label}
+                    class={mode.class(css::SUGGESTIONS, props.listbox_class)}
+                    style={mode.inline(props.listbox_style)}
+                >
+                    { for suggestions.iter().enumerate().map(|(index, suggestion)| {
+                        let is_active = *active_index == Some(index);
+                        let style = if is_active {
+                            format!("{} {}", props.option_style, props.option_active_style)
+                        } else {
+                            props.option_style.to_string()
+                        };
+                        let class = mode.class(
+                            if is_active { css::SUGGESTION_ACTIVE } else { css::SUGGESTION },
+                            props.option_class,
+                        );
+                        let onclick = {
+                            let select_suggestion = select_suggestion.clone();
+                            let suggestion = suggestion.clone();
+                            Callback::from(move |_| select_suggestion.emit(suggestion.clone()))
+                        };
+                        html! {
+                            <li
+                                id={option_id(index)}
+                                role="option"
+                                aria-selected={if is_active { "true" } else { "false" }}
+                                class={class}
+                                style={mode.inline(&style).to_string()}
+                                onmousedown={Callback::from(|e: MouseEvent| e.prevent_default())}
+                                {onclick}
+                            >
+                                <span>
+                                    { for highlight_segments(&suggestion.url, &query).into_iter().map(|(text, is_match)| {
+                                        if is_match {
+                                            html! { <mark class={mode.name(css::SUGGESTION_MATCH)} style={mode.inline(props.match_style)}>{ text }</mark> }
+                                        } else {
+                                            html! { { text } }
+                                        }
+                                    }) }
+                                </span>
+                                if !suggestion.title.is_empty() {
+                                    <span
+                                        class={mode.name(css::SUGGESTION_TITLE)}
+                                        style={mode.inline("color: #6b7280; font-size: 0.75rem;")}
+                                    >
+                                        { &suggestion.title }
+                                    </span>
+                                }
+                            </li>
+                        }
+                    }) }
+                </ul>
+            }
         </div>
     }
 }
@@ -185,20 +586,32 @@ pub struct BrowserContentProps {
     pub aria_label: &'static str,
     #[prop_or_default]
     pub aria_describedby: &'static str,
+    #[prop_or_default]
+    pub node_ref: NodeRef,
+    #[prop_or(1.0)]
+    pub zoom: f64,
+    #[prop_or_default]
+    pub style_mode: StyleMode,
 }
 
 #[function_component(BrowserContent)]
 pub fn browser_content(props: &BrowserContentProps) -> Html {
     html! {
         <main
-            class={props.class}
-            style={props.style}
+            ref={props.node_ref.clone()}
+            class={props.style_mode.class(css::CONTENT, props.class)}
+            style={props.style_mode.inline(props.style)}
             role="main"
             aria-label={props.aria_label}
             aria-describedby={props.aria_describedby}
             tabindex={Some("-1")}
         >
-            { for props.children.iter() }
+            <div
+                class={props.style_mode.name(css::zoom_layer_class(props.zoom))}
+                style={css::zoom_layer_style(props.zoom, props.style_mode)}
+            >
+                { for props.children.iter() }
+            </div>
         </main>
     }
 }
@@ -218,9 +631,7 @@ pub struct ControlButtonProps {
     #[prop_or_default]
     pub on_blur: Callback<FocusEvent>,
 
-    #[prop_or(
-        "width: 1rem; height: 1rem; display: flex; align-items: center; justify-content: center; transition: all 0.2s ease; cursor: pointer; background: none; border: none; padding: 0; margin-right: 0.5rem;"
-    )]
+    #[prop_or(style::CONTROL_BUTTON_STYLE)]
     pub style: &'static str,
     #[prop_or_default]
     pub class: &'static str,
@@ -237,6 +648,16 @@ pub struct ControlButtonProps {
     pub title: &'static str,
     #[prop_or("0")]
     pub tabindex: &'static str,
+
+    /// Reveals the ×, − or ⤢ glyph, as when the pointer enters the controls.
+    #[prop_or_default]
+    pub show_glyph: bool,
+    /// Draws the control grey, as in an inactive window.
+    #[prop_or_default]
+    pub inactive: bool,
+
+    #[prop_or_default]
+    pub style_mode: StyleMode,
 }
 
 #[function_component(ControlButton)]
@@ -256,6 +677,9 @@ pub fn control_button(props: &ControlButtonProps) -> Html {
         aria_label,
         title,
         tabindex,
+        show_glyph,
+        inactive,
+        style_mode,
     } = props.clone();
 
     let aria_label = if aria_label.is_empty() {
@@ -270,20 +694,32 @@ pub fn control_button(props: &ControlButtonProps) -> Html {
         title
     };
 
-    let (fill, stroke) = match r#type {
-        ButtonType::Close => ("#FF5F57", "#E14640"),
-        ButtonType::Minimize => ("#FFBD2E", "#DFA123"),
-        ButtonType::Maximize => ("#28CA42", "#1DAD2C"),
-    };
+    let hovered = use_state(|| false);
+    let forced_colors = use_forced_colors();
+    let colors = r#type.control_colors(ControlState {
+        hovered: *hovered,
+        show_glyph,
+        inactive,
+        forced_colors,
+    });
     let onclick = Callback::from(move |_| on_click.emit(()));
-    let onmouseover = Callback::from(move |_| on_mouse_over.emit(()));
-    let onmouseout = Callback::from(move |_| on_mouse_out.emit(()));
+    let onmouseover = {
+        let hovered = hovered.clone();
+        Callback::from(move |_| {
+            hovered.set(true);
+            on_mouse_over.emit(());
+        })
+    };
+    let onmouseout = Callback::from(move |_| {
+        hovered.set(false);
+        on_mouse_out.emit(());
+    });
 
     html! {
         <button
             type={button_type}
-            class={class}
-            style={style}
+            class={style_mode.class(css::control_class(&r#type), class)}
+            style={style_mode.inline(style)}
             onclick={onclick}
             onmouseover={onmouseover}
             onmouseout={onmouseout}
@@ -303,10 +739,19 @@ pub fn control_button(props: &ControlButtonProps) -> Html {
             >
                 <path
                     class={path_class}
-                    d="M6 0.5C9.03757 0.5 11.5 2.96243 11.5 6C11.5 9.03757 9.03757 11.5 6 11.5C2.96243 11.5 0.5 9.03757 0.5 6C0.5 2.96243 2.96243 0.5 6 0.5Z"
-                    fill={fill}
-                    stroke={stroke}
+                    d={icons::CONTROL_BUTTON}
+                    fill={colors.fill}
+                    stroke={colors.stroke}
                 />
+                if let Some(glyph) = colors.glyph {
+                    <path
+                        class={style_mode.name(css::CONTROL_GLYPH)}
+                        d={r#type.glyph(forced_colors)}
+                        stroke={glyph}
+                        stroke-width="1.25"
+                        stroke-linecap="round"
+                    />
+                }
             </svg>
         </button>
     }
@@ -317,8 +762,10 @@ pub struct BrowserControlsProps {
     #[prop_or_default]
     pub show_controls: bool,
     #[prop_or_default]
+    pub on_error: Callback<DomError>,
+    #[prop_or_default]
     pub class: &'static str,
-    #[prop_or("display: flex; align-items: center; background: none; padding-left: 10px;")]
+    #[prop_or(style::CONTROLS_STYLE)]
     pub style: &'static str,
 
     #[prop_or_default]
@@ -395,20 +842,61 @@ pub struct BrowserControlsProps {
     pub maximize_title: &'static str,
     #[prop_or("0")]
     pub maximize_tabindex: &'static str,
+
+    /// Draws the controls grey until the pointer or focus enters them, as in
+    /// an inactive window.
+    #[prop_or_default]
+    pub inactive: bool,
+
+    #[prop_or_default]
+    pub style_mode: StyleMode,
 }
 
 #[function_component(BrowserControls)]
 pub fn browser_controls(props: &BrowserControlsProps) -> Html {
+    let roving = use_roving_tabindex(props.on_error.clone());
+    let hovered = use_state(|| false);
+    let focused = use_state(|| false);
+
     if !props.show_controls {
         return html! {};
     }
 
+    let show_glyph = *hovered || *focused;
+    let onmouseenter = {
+        let hovered = hovered.clone();
+        Callback::from(move |_: MouseEvent| hovered.set(true))
+    };
+    let onmouseleave = Callback::from(move |_: MouseEvent| hovered.set(false));
+    let onfocusin = {
+        let focused = focused.clone();
+        let onfocusin = roving.onfocusin;
+        Callback::from(move |e: FocusEvent| {
+            focused.set(true);
+            onfocusin.emit(e);
+        })
+    };
+    // Moving focus between the buttons fires `focusout` right before
+    // `focusin`, so the glyphs stay while focus remains in the group.
+    let onfocusout = Callback::from(move |_: FocusEvent| focused.set(false));
+    let class = if props.inactive {
+        css::CONTROLS_INACTIVE
+    } else {
+        css::CONTROLS
+    };
+
     html! {
         <nav
-            class={props.class}
-            style={props.style}
+            ref={roving.node_ref}
+            class={props.style_mode.class(class, props.class)}
+            style={props.style_mode.inline(props.style)}
             role="toolbar"
             aria-label="Browser window controls"
+            onkeydown={roving.onkeydown}
+            onfocusin={onfocusin}
+            onfocusout={onfocusout}
+            onmouseenter={onmouseenter}
+            onmouseleave={onmouseleave}
         >
             <ControlButton
                 r#type={ButtonType::Close}
@@ -424,6 +912,9 @@ pub fn browser_controls(props: &BrowserControlsProps) -> Html {
                 aria_label={props.close_aria_label}
                 title={props.close_title}
                 tabindex={props.close_tabindex}
+                show_glyph={show_glyph}
+                inactive={props.inactive}
+                style_mode={props.style_mode}
             />
             <ControlButton
                 r#type={ButtonType::Minimize}
@@ -439,6 +930,9 @@ pub fn browser_controls(props: &BrowserControlsProps) -> Html {
                 aria_label={props.minimize_aria_label}
                 title={props.minimize_title}
                 tabindex={props.minimize_tabindex}
+                show_glyph={show_glyph}
+                inactive={props.inactive}
+                style_mode={props.style_mode}
             />
             <ControlButton
                 r#type={ButtonType::Maximize}
@@ -454,124 +948,1086 @@ pub fn browser_controls(props: &BrowserControlsProps) -> Html {
                 aria_label={props.maximize_aria_label}
                 title={props.maximize_title}
                 tabindex={props.maximize_tabindex}
+                show_glyph={show_glyph}
+                inactive={props.inactive}
+                style_mode={props.style_mode}
             />
         </nav>
     }
 }
 
 #[derive(Properties, PartialEq, Clone)]
-pub struct BrowserHeaderProps {
-    #[prop_or_default]
-    pub url: String,
+pub struct TitleBarProps {
     #[prop_or_default]
-    pub placeholder: &'static str,
+    pub title: String,
     #[prop_or_default]
-    pub on_url_change: Option<Callback<InputEvent>>,
-    #[prop_or(true)]
-    pub show_controls: bool,
-    #[prop_or(true)]
-    pub show_address_bar: bool,
-    #[prop_or(false)]
-    pub read_only: bool,
+    pub favicon: String,
     #[prop_or_default]
     pub variant: Variant,
-    #[prop_or_default]
-    pub size: Size,
-    #[prop_or_default]
-    pub custom_buttons: Vec<Html>,
+    #[prop_or("New Tab")]
+    pub fallback_title: &'static str,
     #[prop_or_default]
     pub class: &'static str,
-
-    #[prop_or_default]
-    pub container_class: &'static str,
-    #[prop_or("text-black dark:text-white")]
-    pub input_class: &'static str,
+    #[prop_or(style::TITLE_BAR_STYLE)]
+    pub style: &'static str,
+    #[prop_or(style::TAB_LIST_STYLE)]
+    pub tablist_style: &'static str,
+    #[prop_or(style::TAB_STYLE)]
+    pub tab_style: &'static str,
     #[prop_or_default]
-    pub refresh_button_style: &'static str,
-    #[prop_or("Refresh")]
-    pub refresh_button_aria_label: &'static str,
+    pub style_mode: StyleMode,
+}
 
-    #[prop_or(
-        "padding: 4px; cursor: pointer; background: none; border: none; box-shadow: none; outline: none;"
-    )]
-    pub icon_button_style: &'static str,
+#[function_component(TitleBar)]
+pub fn title_bar(props: &TitleBarProps) -> Html {
+    let favicon_failed = use_state(|| false);
 
-    #[prop_or("flex: 1; display: flex; justify-content: center; padding-right: 8px;")]
-    pub address_wrapper_base_style: &'static str,
+    {
+        let favicon_failed = favicon_failed.clone();
+        use_effect_with(props.favicon.clone(), move |_| {
+            favicon_failed.set(false);
+        });
+    }
 
-    #[prop_or("display: flex; align-items: center; position: relative;")]
-    pub header_base_style: &'static str,
+    let on_favicon_error = {
+        let favicon_failed = favicon_failed.clone();
+        Callback::from(move |_: Event| favicon_failed.set(true))
+    };
 
-    #[prop_or_default]
-    pub on_close: Callback<()>,
-    #[prop_or_default]
-    pub on_close_mouse_over: Callback<()>,
-    #[prop_or_default]
-    pub on_close_mouse_out: Callback<()>,
-    #[prop_or_default]
-    pub on_close_focus: Callback<FocusEvent>,
-    #[prop_or_default]
-    pub on_close_blur: Callback<FocusEvent>,
-    #[prop_or_default]
-    pub close_class: &'static str,
-    #[prop_or_default]
-    pub close_svg_class: &'static str,
-    #[prop_or_default]
-    pub close_path_class: &'static str,
-    #[prop_or("button")]
-    pub close_button_type: &'static str,
-    #[prop_or_default]
-    pub close_aria_label: &'static str,
-    #[prop_or_default]
-    pub close_title: &'static str,
-    #[prop_or("0")]
-    pub close_tabindex: &'static str,
+    let mode = props.style_mode;
+    let icon = if props.favicon.is_empty() || *favicon_failed {
+        html! {
+            <svg
+                width="16"
+                height="16"
+                viewBox="0 0 16 16"
+                fill="none"
+                xmlns="http://www.w3.org/2000/svg"
+                aria-hidden="true"
+                class={mode.name(css::FAVICON)}
+                style={mode.inline("flex-shrink: 0;")}
+            >
+                <circle cx="8" cy="8" r="6.5" stroke="#767676" />
+                <path
+                    d="M1.5 8H14.5M8 1.5C9.7 3.3 10.5 5.5 10.5 8C10.5 10.5 9.7 12.7 8 14.5C6.3 12.7 5.5 10.5 5.5 8C5.5 5.5 6.3 3.3 8 1.5Z"
+                    stroke="#767676"
+                />
+            </svg>
+        }
+    } else {
+        html! {
+            <img
+                src={props.favicon.clone()}
+                alt=""
+                width="16"
+                height="16"
+                class={mode.name(css::FAVICON)}
+                style={mode.inline("flex-shrink: 0;")}
+                onerror={on_favicon_error}
+            />
+        }
+    };
 
+    let title = if props.title.is_empty() {
+        props.fallback_title.to_string()
+    } else {
+        props.title.clone()
+    };
+    let label = html! {
+        <span
+            class={mode.name(css::TITLE)}
+            style={mode.inline("overflow: hidden; text-overflow: ellipsis; white-space: nowrap;")}
+        >
+            { title.clone() }
+        </span>
+    };
+
+    match props.variant {
+        Variant::Tabs => html! {
+            <div
+                class={mode.class(css::TAB_LIST, props.class)}
+                style={mode.inline(props.tablist_style)}
+                role="tablist"
+                aria-label="Browser tabs"
+            >
+                <div
+                    class={mode.name(css::TAB)}
+                    style={mode.inline(props.tab_style)}
+                    role="tab"
+                    aria-selected="true"
+                    title={title}
+                >
+                    { icon }
+                    { label }
+                </div>
+            </div>
+        },
+        Variant::Default => html! {
+            <div
+                class={mode.class(css::TITLE_BAR, props.class)}
+                style={mode.inline(props.style)}
+                title={title}
+            >
+                { icon }
+                { label }
+            </div>
+        },
+        Variant::Ios => html! {},
+    }
+}
+
+#[derive(Properties, PartialEq, Clone)]
+pub struct ShareMenuProps {
     #[prop_or_default]
-    pub on_minimize: Callback<()>,
-    #[prop_or_default]
-    pub on_minimize_mouse_over: Callback<()>,
-    #[prop_or_default]
-    pub on_minimize_mouse_out: Callback<()>,
-    #[prop_or_default]
-    pub on_minimize_focus: Callback<FocusEvent>,
-    #[prop_or_default]
-    pub on_minimize_blur: Callback<FocusEvent>,
-    #[prop_or_default]
-    pub minimize_class: &'static str,
-    #[prop_or_default]
-    pub minimize_svg_class: &'static str,
-    #[prop_or_default]
-    pub minimize_path_class: &'static str,
-    #[prop_or("button")]
-    pub minimize_button_type: &'static str,
+    pub url: String,
     #[prop_or_default]
-    pub minimize_aria_label: &'static str,
+    pub title: String,
+    #[prop_or(true)]
+    pub enabled: bool,
     #[prop_or_default]
-    pub minimize_title: &'static str,
-    #[prop_or("0")]
-    pub minimize_tabindex: &'static str,
-
+    pub on_share: Callback<ShareTarget>,
     #[prop_or_default]
-    pub on_maximize: Callback<()>,
+    pub onclick: Callback<()>,
     #[prop_or_default]
-    pub on_maximize_mouse_over: Callback<()>,
+    pub onmouseover: Callback<()>,
     #[prop_or_default]
-    pub on_maximize_mouse_out: Callback<()>,
+    pub onmouseout: Callback<()>,
     #[prop_or_default]
-    pub on_maximize_focus: Callback<FocusEvent>,
+    pub onfocus: Callback<FocusEvent>,
     #[prop_or_default]
-    pub on_maximize_blur: Callback<FocusEvent>,
+    pub onblur: Callback<FocusEvent>,
     #[prop_or_default]
-    pub maximize_class: &'static str,
+    pub tabindex: &'static str,
     #[prop_or_default]
-    pub maximize_svg_class: &'static str,
+    pub button_style: &'static str,
     #[prop_or_default]
-    pub maximize_path_class: &'static str,
-    #[prop_or("button")]
-    pub maximize_button_type: &'static str,
+    pub button_class: &'static str,
+    #[prop_or(style::SHARE_POPOVER_STYLE)]
+    pub popover_style: &'static str,
+    #[prop_or(style::SHARE_ITEM_STYLE)]
+    pub item_style: &'static str,
+    #[prop_or(style::SHARE_QR_STYLE)]
+    pub qr_style: &'static str,
     #[prop_or_default]
-    pub maximize_aria_label: &'static str,
+    pub style_mode: StyleMode,
+}
+
+#[function_component(ShareMenu)]
+pub fn share_menu(props: &ShareMenuProps) -> Html {
+    let share = use_mut_ref(ShareState::default);
+    let rerender = use_force_update();
+    let button_ref = use_node_ref();
+    let copy_ref = use_node_ref();
+    let state = *share.borrow();
+
+    {
+        let copy_ref = copy_ref.clone();
+        use_effect_with(state.open, move |open| {
+            if *open {
+                if let Some(copy) = copy_ref.cast::<web_sys::HtmlElement>() {
+                    let _ = copy.focus();
+                }
+            }
+        });
+    }
+
+    let close = {
+        let share = share.clone();
+        let rerender = rerender.clone();
+        let button_ref = button_ref.clone();
+        Callback::from(move |_: ()| {
+            share.borrow_mut().close();
+            rerender.force_update();
+            if let Some(button) = button_ref.cast::<web_sys::HtmlElement>() {
+                let _ = button.focus();
+            }
+        })
+    };
+
+    let onclick = {
+        let share = share.clone();
+        let rerender = rerender.clone();
+        let props = props.clone();
+        Callback::from(move |_: MouseEvent| {
+            props.onclick.emit(());
+            if !props.enabled {
+                return;
+            }
+            if share.borrow().open {
+                share.borrow_mut().close();
+                rerender.force_update();
+                return;
+            }
+
+            let share = share.clone();
+            let rerender = rerender.clone();
+            let on_share = props.on_share.clone();
+            let (url, title) = (props.url.clone(), props.title.clone());
+            spawn_local(async move {
+                match page::share(url, title).await {
+                    Some(true) => on_share.emit(ShareTarget::Native),
+                    Some(false) | None => {
+                        share.borrow_mut().open_fallback();
+                        rerender.force_update();
+                    }
+                }
+            });
+        })
+    };
+
+    let on_copy = {
+        let share = share.clone();
+        let rerender = rerender.clone();
+        let url = props.url.clone();
+        let on_share = props.on_share.clone();
+        Callback::from(move |_: MouseEvent| {
+            let share = share.clone();
+            let rerender = rerender.clone();
+            let on_share = on_share.clone();
+            let url = url.clone();
+            spawn_local(async move {
+                if page::copy_text(url).await {
+                    share.borrow_mut().mark_copied();
+                    rerender.force_update();
+                    on_share.emit(ShareTarget::Clipboard);
+                }
+            });
+        })
+    };
+
+    let on_email = {
+        let on_share = props.on_share.clone();
+        let close = close.clone();
+        Callback::from(move |_: MouseEvent| {
+            on_share.emit(ShareTarget::Email);
+            close.emit(());
+        })
+    };
+
+    let on_toggle_qr = {
+        let share = share.clone();
+        let rerender = rerender.clone();
+        let on_share = props.on_share.clone();
+        Callback::from(move |_: MouseEvent| {
+            let shown = share.borrow_mut().toggle_qr();
+            rerender.force_update();
+            if shown {
+                on_share.emit(ShareTarget::QrCode);
+            }
+        })
+    };
+
+    // Handled on the wrapper so Escape on the trigger closes the popover
+    // instead of reaching the frame's window-level shortcuts.
+    let onkeydown = {
+        let share = share.clone();
+        let close = close.clone();
+        Callback::from(move |e: KeyboardEvent| {
+            if share.borrow().open && e.key() == "Escape" {
+                e.prevent_default();
+                e.stop_propagation();
+                close.emit(());
+            }
+        })
+    };
+
+    let on_backdrop_click = Callback::from(move |_: MouseEvent| {
+        share.borrow_mut().close();
+        rerender.force_update();
+    });
+
+    let mode = props.style_mode;
+    let qr_code = if state.show_qr {
+        QrCode::encode(props.url.as_bytes()).map(|qr| {
+            let extent = qr.size() + 8;
+            let view_box = format!("0 0 {extent} {extent}");
+            html! {
+                <svg
+                    class={mode.name(css::SHARE_QR)}
+                    style={mode.inline(props.qr_style)}
+                    viewBox={view_box}
+                    role="img"
+                    aria-label={format!("QR code for {}", props.url)}
+                    shape-rendering="crispEdges"
+                    xmlns="http://www.w3.org/2000/svg"
+                >
+                    <rect width="100%" height="100%" fill="#ffffff" />
+                    <path d={qr.to_svg_path(4)} fill="#000000" />
+                </svg>
+            }
+        })
+    } else {
+        None
+    };
+
+    html! {
+        <div
+            class={mode.name(css::POPUP)}
+            style={mode.inline("position: relative; display: flex;")}
+            onkeydown={onkeydown}
+        >
+            <button
+                ref={button_ref}
+                class={props.button_class}
+                style={props.button_style}
+                onclick={onclick}
+                onmouseover={props.onmouseover.reform(|_| ())}
+                onmouseout={props.onmouseout.reform(|_| ())}
+                onfocus={props.onfocus.clone()}
+                onblur={props.onblur.clone()}
+                aria-label="Share"
+                aria-haspopup={props.enabled.then_some("dialog")}
+                aria-expanded={props.enabled.then(|| state.open.to_string())}
+                title="Share"
+                tabindex={props.tabindex}
+            >
+                <svg
+                    width="15"
+                    height="19"
+                    viewBox="0 0 15 19"
+                    fill="none"
+                    xmlns="http://www.w3.org/2000/svg"
+                >
+                    <path
+                        d={icons::SHARE}
+                        fill="#767676"
+                    />
+                </svg>
+            </button>
+            if state.open {
+                <div
+                    class={mode.name(css::BACKDROP)}
+                    style={mode.inline("position: fixed; inset: 0; z-index: 40;")}
+                    onclick={on_backdrop_click}
+                />
+                <div
+                    class={mode.name(css::SHARE)}
+                    style={mode.inline(props.popover_style)}
+                    role="dialog"
+                    aria-label="Share"
+                >
+                    <button
+                        ref={copy_ref}
+                        class={mode.name(css::SHARE_ITEM)}
+                        style={mode.inline(props.item_style)}
+                        onclick={on_copy}
+                        aria-live="polite"
+                    >
+                        { state.copy_label() }
+                    </button>
+                    <a
+                        class={mode.name(css::SHARE_ITEM)}
+                        style={mode.inline(props.item_style)}
+                        href={share_mailto(&props.url, &props.title)}
+                        onclick={on_email}
+                    >
+                        { "Email" }
+                    </a>
+                    <button
+                        class={mode.name(css::SHARE_ITEM)}
+                        style={mode.inline(props.item_style)}
+                        onclick={on_toggle_qr}
+                        aria-expanded={state.show_qr.to_string()}
+                    >
+                        { state.qr_label() }
+                    </button>
+                    { qr_code }
+                </div>
+            }
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq, Clone)]
+pub struct MenuListProps {
+    pub items: Vec<MenuItem>,
+    pub id: String,
+    #[prop_or_default]
+    pub label: String,
+    #[prop_or_default]
+    pub on_select: Callback<String>,
+    #[prop_or_default]
+    pub on_close: Callback<()>,
+    /// Set on submenus: returns focus to the parent menu on `ArrowLeft`/`Escape`.
+    #[prop_or_default]
+    pub on_collapse: Option<Callback<()>>,
+    #[prop_or(style::MENU_STYLE)]
+    pub menu_style: &'static str,
+    #[prop_or(style::SUBMENU_STYLE)]
+    pub submenu_style: &'static str,
+    #[prop_or(style::MENU_ITEM_STYLE)]
+    pub item_style: &'static str,
+    #[prop_or(style::ACTIVE_ITEM_STYLE)]
+    pub item_active_style: &'static str,
+    #[prop_or(style::MENU_ITEM_DISABLED_STYLE)]
+    pub item_disabled_style: &'static str,
+    #[prop_or(style::MENU_SEPARATOR_STYLE)]
+    pub separator_style: &'static str,
+    #[prop_or(style::MENU_SHORTCUT_STYLE)]
+    pub shortcut_style: &'static str,
+    #[prop_or_default]
+    pub style_mode: StyleMode,
+}
+
+#[function_component(MenuList)]
+pub fn menu_list(props: &MenuListProps) -> Html {
+    let menu = use_mut_ref(|| MenuState::new(&props.items));
+    let rerender = use_force_update();
+    let typeahead_reset = use_mut_ref(|| None::<Timeout>);
+    let list_ref = use_node_ref();
+    let MenuState {
+        active, expanded, ..
+    } = menu.borrow().clone();
+
+    {
+        let list_ref = list_ref.clone();
+        use_effect_with((), move |_| {
+            if let Some(list) = list_ref.cast::<web_sys::HtmlElement>() {
+                let _ = list.focus();
+            }
+        });
+    }
+
+    // Carries out what the menu state asks for after an event.
+    let apply = {
+        let props = props.clone();
+        let menu = menu.clone();
+        let rerender = rerender.clone();
+        Callback::from(move |action: MenuAction| {
+            match action {
+                MenuAction::Update => {}
+                MenuAction::Typeahead => {
+                    let menu = menu.clone();
+                    *typeahead_reset.borrow_mut() =
+                        Some(Timeout::new(TYPEAHEAD_TIMEOUT, move || {
+                            menu.borrow_mut().reset_typeahead()
+                        }));
+                }
+                MenuAction::Select(id) => {
+                    props.on_select.emit(id);
+                    props.on_close.emit(());
+                }
+                MenuAction::Collapse => {
+                    if let Some(on_collapse) = &props.on_collapse {
+                        on_collapse.emit(());
+                    }
+                }
+                MenuAction::Close | MenuAction::Leave => props.on_close.emit(()),
+            }
+            rerender.force_update();
+        })
+    };
+
+    let activate = {
+        let items = props.items.clone();
+        let menu = menu.clone();
+        let apply = apply.clone();
+        Callback::from(move |index: usize| {
+            let action = menu.borrow_mut().activate(&items, index);
+            if let Some(action) = action {
+                apply.emit(action);
+            }
+        })
+    };
+
+    let hover = {
+        let items = props.items.clone();
+        let menu = menu.clone();
+        let rerender = rerender.clone();
+        Callback::from(move |index: usize| {
+            menu.borrow_mut().hover(&items, index);
+            rerender.force_update();
+        })
+    };
+
+    let collapse_submenu = {
+        let menu = menu.clone();
+        let rerender = rerender.clone();
+        let list_ref = list_ref.clone();
+        Callback::from(move |_: ()| {
+            menu.borrow_mut().collapse();
+            rerender.force_update();
+            if let Some(list) = list_ref.cast::<web_sys::HtmlElement>() {
+                let _ = list.focus();
+            }
+        })
+    };
+
+    let onkeydown = {
+        let props = props.clone();
+        let menu = menu.clone();
+        let apply = apply.clone();
+        Callback::from(move |e: KeyboardEvent| {
+            let key = e.key();
+            let input = KeyInput::new(&key)
+                .with_ctrl(e.ctrl_key())
+                .with_meta(e.meta_key())
+                .with_alt(e.alt_key());
+            let nested = props.on_collapse.is_some();
+            let Some(action) = menu.borrow_mut().key(&input, &props.items, nested) else {
+                return;
+            };
+            if action.prevents_default() {
+                e.prevent_default();
+                e.stop_propagation();
+            }
+            apply.emit(action);
+        })
+    };
+
+    let mode = props.style_mode;
+    let (class, style) = if props.on_collapse.is_some() {
+        (
+            css::SUBMENU,
+            format!("{} {}", props.menu_style, props.submenu_style),
+        )
+    } else {
+        (css::MENU, props.menu_style.to_string())
+    };
+
+    html! {
+        <ul
+            ref={list_ref}
+            id={props.id.clone()}
+            role="menu"
+            tabindex="-1"
+            aria-label={(!props.label.is_empty()).then(|| props.label.clone())}
+            aria-orientation="vertical"
+            aria-activedescendant={active.map(|index| menu_item_id(&props.id, index))}
+            class={mode.name(class)}
+            style={mode.inline(&style).to_string()}
+            onkeydown={onkeydown}
+        >
+            { for props.items.iter().enumerate().map(|(index, item)| {
+                if item.separator {
+                    return html! {
+                        <li
+                            role="separator"
+                            class={mode.name(css::MENU_SEPARATOR)}
+                            style={mode.inline(props.separator_style)}
+                        />
+                    };
+                }
+
+                let is_active = active == Some(index);
+                let is_expanded = expanded == Some(index);
+                let mut item_style = props.item_style.to_string();
+                if is_active {
+                    item_style = format!("{} {}", item_style, props.item_active_style);
+                }
+                if item.disabled {
+                    item_style = format!("{} {}", item_style, props.item_disabled_style);
+                }
+
+                let onclick = {
+                    let activate = activate.clone();
+                    Callback::from(move |e: MouseEvent| {
+                        e.stop_propagation();
+                        activate.emit(index);
+                    })
+                };
+                let onmouseenter = hover.reform(move |_: MouseEvent| index);
+
+                // The children of a menu item are presentational, so a submenu
+                // sits next to its trigger instead of inside it.
+                html! {
+                    <li
+                        role="none"
+                        class={mode.name(css::MENU_ENTRY)}
+                        style={mode.inline("position: relative;")}
+                    >
+                        <div
+                            id={menu_item_id(&props.id, index)}
+                            role={if item.checked.is_some() { "menuitemcheckbox" } else { "menuitem" }}
+                            aria-checked={item.checked.map(|checked| checked.to_string())}
+                            aria-disabled={item.disabled.then_some("true")}
+                            aria-haspopup={item.has_submenu().then_some("menu")}
+                            aria-expanded={item.has_submenu().then(|| is_expanded.to_string())}
+                            aria-keyshortcuts={(!item.shortcut.is_empty()).then(|| item.shortcut.clone())}
+                            class={mode.class(&css::menu_item_class(is_active, item.disabled), "")}
+                            style={mode.inline(&item_style).to_string()}
+                            onclick={onclick}
+                            onmouseenter={onmouseenter}
+                        >
+                            if let Some(checked) = item.checked {
+                                <span
+                                    class={mode.name(css::MENU_CHECK)}
+                                    style={mode.inline("width: 16px; text-align: center;")}
+                                    aria-hidden="true"
+                                >
+                                    { if checked { "✓" } else { "" } }
+                                </span>
+                            }
+                            if !item.icon.is_empty() {
+                                <img src={item.icon.clone()} alt="" width="16" height="16" />
+                            }
+                            <span>{ item.label.clone() }</span>
+                            if !item.shortcut.is_empty() {
+                                <span
+                                    class={mode.name(css::MENU_SHORTCUT)}
+                                    style={mode.inline(props.shortcut_style)}
+                                    aria-hidden="true"
+                                >
+                                    { item.shortcut.clone() }
+                                </span>
+                            }
+                            if item.has_submenu() {
+                                <span
+                                    class={mode.name(css::MENU_ARROW)}
+                                    style={mode.inline("margin-left: auto; padding-left: 16px;")}
+                                    aria-hidden="true"
+                                >
+                                    { "›" }
+                                </span>
+                            }
+                        </div>
+                        if is_expanded {
+                            <MenuList
+                                items={item.submenu.clone()}
+                                id={submenu_id(&props.id, index)}
+                                label={item.label.clone()}
+                                on_select={props.on_select.clone()}
+                                on_close={props.on_close.clone()}
+                                on_collapse={Some(collapse_submenu.clone())}
+                                menu_style={props.menu_style}
+                                submenu_style={props.submenu_style}
+                                item_style={props.item_style}
+                                item_active_style={props.item_active_style}
+                                item_disabled_style={props.item_disabled_style}
+                                separator_style={props.separator_style}
+                                shortcut_style={props.shortcut_style}
+                                style_mode={mode}
+                            />
+                        }
+                    </li>
+                }
+            }) }
+        </ul>
+    }
+}
+
+#[derive(Properties, PartialEq, Clone)]
+pub struct MoreMenuProps {
+    #[prop_or_default]
+    pub items: Vec<MenuItem>,
+    /// Id of the menu; generated per instance when empty.
+    #[prop_or_default]
+    pub id: String,
+    #[prop_or_default]
+    pub on_select: Callback<String>,
+    #[prop_or_default]
+    pub onclick: Callback<()>,
+    #[prop_or_default]
+    pub onmouseover: Callback<()>,
+    #[prop_or_default]
+    pub onmouseout: Callback<()>,
+    #[prop_or_default]
+    pub onfocus: Callback<FocusEvent>,
+    #[prop_or_default]
+    pub onblur: Callback<FocusEvent>,
+    #[prop_or_default]
+    pub tabindex: &'static str,
+    #[prop_or_default]
+    pub button_style: &'static str,
+    #[prop_or_default]
+    pub button_class: &'static str,
+    #[prop_or_default]
+    pub style_mode: StyleMode,
+}
+
+#[function_component(MoreMenu)]
+pub fn more_menu(props: &MoreMenuProps) -> Html {
+    let id = use_element_id(&props.id, "browser-more-menu");
+    let open = use_state(|| false);
+    let button_ref = use_node_ref();
+    let has_menu = !props.items.is_empty();
+
+    let close = {
+        let open = open.clone();
+        let button_ref = button_ref.clone();
+        Callback::from(move |_: ()| {
+            open.set(false);
+            if let Some(button) = button_ref.cast::<web_sys::HtmlElement>() {
+                let _ = button.focus();
+            }
+        })
+    };
+
+    let onclick = {
+        let open = open.clone();
+        let on_click = props.onclick.clone();
+        Callback::from(move |_: MouseEvent| {
+            on_click.emit(());
+            if has_menu {
+                open.set(!*open);
+            }
+        })
+    };
+
+    let onkeydown = {
+        let open = open.clone();
+        Callback::from(move |e: KeyboardEvent| {
+            if has_menu && e.key() == "ArrowDown" {
+                e.prevent_default();
+                open.set(true);
+            }
+        })
+    };
+
+    let on_backdrop_click = {
+        let open = open.clone();
+        Callback::from(move |_: MouseEvent| open.set(false))
+    };
+
+    html! {
+        <div
+            class={props.style_mode.name(css::POPUP)}
+            style={props.style_mode.inline("position: relative; display: flex;")}
+        >
+            <button
+                ref={button_ref}
+                class={props.button_class}
+                style={props.button_style}
+                onclick={onclick}
+                onkeydown={onkeydown}
+                onmouseover={props.onmouseover.reform(|_| ())}
+                onmouseout={props.onmouseout.reform(|_| ())}
+                onfocus={props.onfocus.clone()}
+                onblur={props.onblur.clone()}
+                aria-label="More options"
+                aria-haspopup={has_menu.then_some("menu")}
+                aria-expanded={has_menu.then(|| open.to_string())}
+                aria-controls={(has_menu && *open).then(|| id.clone())}
+                title="More options"
+                tabindex={props.tabindex}
+            >
+                <svg
+                    width="18"
+                    height="19"
+                    viewBox="0 0 18 19"
+                    fill="none"
+                    xmlns="http://www.w3.org/2000/svg"
+                >
+                    <path
+                        d={icons::MORE}
+                        fill="#767676"
+                    />
+                </svg>
+            </button>
+            if *open {
+                <div
+                    class={props.style_mode.name(css::BACKDROP)}
+                    style={props.style_mode.inline("position: fixed; inset: 0; z-index: 40;")}
+                    onclick={on_backdrop_click}
+                />
+                <MenuList
+                    items={props.items.clone()}
+                    id={id}
+                    label="More options"
+                    on_select={props.on_select.clone()}
+                    on_close={close}
+                    style_mode={props.style_mode}
+                />
+            }
+        </div>
+    }
+}
+
+/// A button rendered in the header toolbar.
+///
+/// Build one with [`ToolbarItem::new`] and the `with_*` methods. Within a
+/// placement, items are shown by ascending `order`; when the header is too
+/// narrow the last ones move into the "More options" menu.
+#[derive(PartialEq, Clone, Default)]
+pub struct ToolbarItem {
+    pub id: String,
+    pub icon: Html,
+    pub label: String,
+    /// Tooltip shown on hover; falls back to `label`.
+    pub tooltip: String,
+    /// Short text drawn over the icon, such as an unread count.
+    pub badge: Option<String>,
+    /// Makes the item a toggle button in the given state.
+    pub toggled: Option<bool>,
+    pub placement: ToolbarPlacement,
+    pub order: i32,
+    pub on_click: Callback<()>,
+}
+
+impl ToolbarItem {
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_icon(mut self, icon: Html) -> Self {
+        self.icon = icon;
+        self
+    }
+
+    pub fn with_tooltip(mut self, tooltip: impl Into<String>) -> Self {
+        self.tooltip = tooltip.into();
+        self
+    }
+
+    pub fn with_badge(mut self, badge: impl Into<String>) -> Self {
+        self.badge = Some(badge.into());
+        self
+    }
+
+    pub fn with_toggled(mut self, toggled: bool) -> Self {
+        self.toggled = Some(toggled);
+        self
+    }
+
+    pub fn with_placement(mut self, placement: ToolbarPlacement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    pub fn with_order(mut self, order: i32) -> Self {
+        self.order = order;
+        self
+    }
+
+    pub fn with_on_click(mut self, on_click: Callback<()>) -> Self {
+        self.on_click = on_click;
+        self
+    }
+
+    /// The entry representing this item once it overflows into the menu.
+    pub fn to_menu_item(&self) -> MenuItem {
+        let label = match &self.badge {
+            Some(badge) => format!("{} ({})", self.label, badge),
+            None => self.label.clone(),
+        };
+        MenuItem {
+            checked: self.toggled,
+            ..MenuItem::new(self.id.clone(), label)
+        }
+    }
+}
+
+#[derive(Properties, PartialEq, Clone)]
+pub struct ToolbarButtonProps {
+    pub item: ToolbarItem,
+    #[prop_or_default]
+    pub button_style: &'static str,
+    #[prop_or_default]
+    pub button_class: &'static str,
+    #[prop_or(style::TOOLBAR_TOGGLED_STYLE)]
+    pub toggled_style: &'static str,
+    #[prop_or(style::TOOLBAR_BADGE_STYLE)]
+    pub badge_style: &'static str,
+    #[prop_or_default]
+    pub style_mode: StyleMode,
+}
+
+#[function_component(ToolbarButton)]
+pub fn toolbar_button(props: &ToolbarButtonProps) -> Html {
+    let item = &props.item;
+    let mode = props.style_mode;
+    let toggled = item.toggled == Some(true);
+    let style = if toggled {
+        format!(
+            "{} position: relative; {}",
+            props.button_style, props.toggled_style
+        )
+    } else {
+        format!("{} position: relative;", props.button_style)
+    };
+    let class = if toggled {
+        mode.class(css::TOOLBAR_BUTTON_TOGGLED, props.button_class)
+    } else {
+        mode.class(css::TOOLBAR_BUTTON, props.button_class)
+    };
+    let aria_label = match &item.badge {
+        Some(badge) => format!("{}, {}", item.label, badge),
+        None => item.label.clone(),
+    };
+    let title = if item.tooltip.is_empty() {
+        item.label.clone()
+    } else {
+        item.tooltip.clone()
+    };
+
+    html! {
+        <button
+            type="button"
+            class={class}
+            style={mode.inline(&style).to_string()}
+            onclick={item.on_click.reform(|_| ())}
+            aria-label={aria_label}
+            aria-pressed={item.toggled.map(|toggled| toggled.to_string())}
+            title={title}
+        >
+            { item.icon.clone() }
+            if let Some(badge) = &item.badge {
+                <span
+                    class={mode.name(css::TOOLBAR_BADGE)}
+                    style={mode.inline(props.badge_style)}
+                    aria-hidden="true"
+                >
+                    { badge.clone() }
+                </span>
+            }
+        </button>
+    }
+}
+
+#[derive(Properties, PartialEq, Clone)]
+pub struct BrowserHeaderProps {
+    /// Prefix of the ids of the address bar and the "More options" menu;
+    /// each generates its own when empty.
+    #[prop_or_default]
+    pub id_prefix: String,
+    #[prop_or_default]
+    pub url: String,
+    #[prop_or_default]
+    pub placeholder: &'static str,
+    #[prop_or_default]
+    pub on_url_change: Option<Callback<InputEvent>>,
+    #[prop_or_default]
+    pub suggestions: SuggestionProvider,
+    #[prop_or_default]
+    pub on_suggestion_select: Callback<Suggestion>,
+    #[prop_or(true)]
+    pub smart_display: bool,
+    #[prop_or_default]
+    pub title: String,
+    #[prop_or_default]
+    pub favicon: String,
+    #[prop_or(true)]
+    pub show_controls: bool,
+    #[prop_or_default]
+    pub inactive: bool,
+    #[prop_or(true)]
+    pub show_address_bar: bool,
+    #[prop_or(false)]
+    pub read_only: bool,
+    #[prop_or_default]
+    pub variant: Variant,
+    #[prop_or_default]
+    pub size: Size,
+    #[prop_or_default]
+    pub style_mode: StyleMode,
+    #[prop_or_default]
+    pub toolbar_items: Vec<ToolbarItem>,
+    #[prop_or(32.0)]
+    pub toolbar_item_width: f64,
+    #[prop_or(420.0)]
+    pub toolbar_reserved_width: f64,
+    #[prop_or_default]
+    pub class: &'static str,
+
+    #[prop_or_default]
+    pub container_class: &'static str,
+    #[prop_or_default]
+    pub input_class: &'static str,
+    #[prop_or_default]
+    pub refresh_button_style: &'static str,
+    #[prop_or(REFRESH_LABEL)]
+    pub refresh_button_aria_label: &'static str,
+
+    #[prop_or_default]
+    pub load_state: LoadState,
+    #[prop_or_default]
+    pub on_refresh: Option<Callback<()>>,
+    #[prop_or_default]
+    pub on_stop: Callback<()>,
+    #[prop_or(1.0)]
+    pub zoom: f64,
+    #[prop_or_default]
+    pub on_zoom_reset: Callback<()>,
+    #[prop_or_default]
+    pub on_submit: Callback<String>,
+    #[prop_or_default]
+    pub on_error: Callback<DomError>,
+    #[prop_or(style::PROGRESS_BAR_STYLE)]
+    pub progress_bar_style: &'static str,
+
+    #[prop_or(style::ICON_BUTTON_STYLE)]
+    pub icon_button_style: &'static str,
+
+    #[prop_or(style::ADDRESS_WRAPPER_BASE_STYLE)]
+    pub address_wrapper_base_style: &'static str,
+
+    #[prop_or(style::HEADER_BASE_STYLE)]
+    pub header_base_style: &'static str,
+
+    #[prop_or_default]
+    pub on_close: Callback<()>,
+    #[prop_or_default]
+    pub on_close_mouse_over: Callback<()>,
+    #[prop_or_default]
+    pub on_close_mouse_out: Callback<()>,
+    #[prop_or_default]
+    pub on_close_focus: Callback<FocusEvent>,
+    #[prop_or_default]
+    pub on_close_blur: Callback<FocusEvent>,
+    #[prop_or_default]
+    pub close_class: &'static str,
+    #[prop_or_default]
+    pub close_svg_class: &'static str,
+    #[prop_or_default]
+    pub close_path_class: &'static str,
+    #[prop_or("button")]
+    pub close_button_type: &'static str,
+    #[prop_or_default]
+    pub close_aria_label: &'static str,
+    #[prop_or_default]
+    pub close_title: &'static str,
+    #[prop_or("0")]
+    pub close_tabindex: &'static str,
+
+    #[prop_or_default]
+    pub on_minimize: Callback<()>,
+    #[prop_or_default]
+    pub on_minimize_mouse_over: Callback<()>,
+    #[prop_or_default]
+    pub on_minimize_mouse_out: Callback<()>,
+    #[prop_or_default]
+    pub on_minimize_focus: Callback<FocusEvent>,
+    #[prop_or_default]
+    pub on_minimize_blur: Callback<FocusEvent>,
+    #[prop_or_default]
+    pub minimize_class: &'static str,
+    #[prop_or_default]
+    pub minimize_svg_class: &'static str,
+    #[prop_or_default]
+    pub minimize_path_class: &'static str,
+    #[prop_or("button")]
+    pub minimize_button_type: &'static str,
+    #[prop_or_default]
+    pub minimize_aria_label: &'static str,
+    #[prop_or_default]
+    pub minimize_title: &'static str,
+    #[prop_or("0")]
+    pub minimize_tabindex: &'static str,
+
+    #[prop_or_default]
+    pub on_maximize: Callback<()>,
+    #[prop_or_default]
+    pub on_maximize_mouse_over: Callback<()>,
+    #[prop_or_default]
+    pub on_maximize_mouse_out: Callback<()>,
+    #[prop_or_default]
+    pub on_maximize_focus: Callback<FocusEvent>,
+    #[prop_or_default]
+    pub on_maximize_blur: Callback<FocusEvent>,
+    #[prop_or_default]
+    pub maximize_class: &'static str,
+    #[prop_or_default]
+    pub maximize_svg_class: &'static str,
+    #[prop_or_default]
+    pub maximize_path_class: &'static str,
+    #[prop_or("button")]
+    pub maximize_button_type: &'static str,
+    #[prop_or_default]
+    pub maximize_aria_label: &'static str,
     #[prop_or_default]
     pub maximize_title: &'static str,
     #[prop_or("0")]
@@ -579,6 +2035,10 @@ pub struct BrowserHeaderProps {
 
     #[prop_or_default]
     pub share_button_style: &'static str,
+    #[prop_or(true)]
+    pub share_menu: bool,
+    #[prop_or_default]
+    pub on_share: Callback<ShareTarget>,
     #[prop_or_default]
     pub share_onclick: Callback<()>,
     #[prop_or_default]
@@ -610,6 +2070,10 @@ pub struct BrowserHeaderProps {
     #[prop_or_default]
     pub more_button_style: &'static str,
     #[prop_or_default]
+    pub menu_items: Vec<MenuItem>,
+    #[prop_or_default]
+    pub on_menu_select: Callback<String>,
+    #[prop_or_default]
     pub more_onclick: Callback<()>,
     #[prop_or_default]
     pub more_onmouseover: Callback<()>,
@@ -625,90 +2089,96 @@ pub struct BrowserHeaderProps {
 
 #[function_component(BrowserHeader)]
 pub fn browser_header(props: &BrowserHeaderProps) -> Html {
+    let actions = use_roving_tabindex(props.on_error.clone());
     let is_ios = props.variant == Variant::Ios;
-    let is_tabs = props.variant == Variant::Tabs;
-
-    let base_style = {
-        let padding = match props.size {
-            Size::Small => "4px 6px",
-            Size::Large => "10px 16px",
-            _ => "6px 12px",
-        };
-        let height = match (props.variant.clone(), props.size.clone()) {
-            (Variant::Tabs, _) => "40px",
-            (Variant::Ios, _) => "56px",
-            (_, Size::Large) => "60px",
-            (_, Size::Small) => "38px",
-            _ => "48px",
-        };
-        let border_radius = if is_tabs {
-            "6px"
-        } else if props.variant == Variant::Default {
-            "8px 8px 0 0"
-        } else {
-            "0"
-        };
-        let border = if is_tabs { "1px solid #d1d5db" } else { "none" };
-        let box_shadow = if props.variant == Variant::Default {
-            "0 2px 6px rgba(0,0,0,0.1)"
-        } else {
-            "none"
-        };
-
-        format!(
-            "{} justify-content: {}; padding: {}; height: {}; border-radius: {}; border: {}; box-shadow: {};",
-            props.header_base_style,
-            if is_ios {
-                "space-between"
-            } else {
-                "flex-start"
-            },
-            padding,
-            height,
-            border_radius,
-            border,
-            box_shadow
-        )
-    };
-
-    let address_wrapper_style = format!(
-        "{} padding-left: {};",
-        props.address_wrapper_base_style,
-        if props.show_controls { "8px" } else { "0" }
-    );
-    let share_onclick = props.share_onclick.clone();
-    let share_onmouseover = props.share_onmouseover.clone();
-    let share_onmouseout = props.share_onmouseout.clone();
 
+    let base_style = header_style(props.header_base_style, &props.variant, &props.size);
+    let address_wrapper_style =
+        address_wrapper_style(props.address_wrapper_base_style, props.show_controls);
     let tabs_onclick = props.tabs_onclick.clone();
     let tabs_onmouseover = props.tabs_onmouseover.clone();
     let tabs_onmouseout = props.tabs_onmouseout.clone();
 
-    let more_onclick = props.more_onclick.clone();
-    let more_onmouseover = props.more_onmouseover.clone();
-    let more_onmouseout = props.more_onmouseout.clone();
-
-    let share_onclick = Callback::from(move |_| share_onclick.emit(()));
-    let share_onmouseover = Callback::from(move |_| share_onmouseover.emit(()));
-    let share_onmouseout = Callback::from(move |_| share_onmouseout.emit(()));
-
     let tabs_onclick = Callback::from(move |_| tabs_onclick.emit(()));
     let tabs_onmouseover = Callback::from(move |_| tabs_onmouseover.emit(()));
     let tabs_onmouseout = Callback::from(move |_| tabs_onmouseout.emit(()));
 
-    let more_onclick = Callback::from(move |_| more_onclick.emit(()));
-    let more_onmouseover = Callback::from(move |_| more_onmouseover.emit(()));
-    let more_onmouseout = Callback::from(move |_| more_onmouseout.emit(()));
+    let header_ref = use_node_ref();
+    let header_width = use_element_width(header_ref.clone(), !props.toolbar_items.is_empty());
+
+    let toolbar = layout_toolbar(
+        &props
+            .toolbar_items
+            .iter()
+            .map(|item| (item.placement, item.order))
+            .collect::<Vec<_>>(),
+        header_width.map(|width| width - props.toolbar_reserved_width),
+        props.toolbar_item_width,
+    );
+    let mode = props.style_mode;
+    let icon_button_style = mode.inline(props.icon_button_style);
+    let icon_button_class = mode.name(css::ICON_BUTTON);
+    let toolbar_button = |index: &usize| {
+        html! {
+            <ToolbarButton
+                item={props.toolbar_items[*index].clone()}
+                button_style={icon_button_style}
+                button_class={icon_button_class}
+                style_mode={mode}
+            />
+        }
+    };
+
+    let mut menu_items: Vec<MenuItem> = toolbar
+        .overflow
+        .iter()
+        .map(|&index| props.toolbar_items[index].to_menu_item())
+        .collect();
+    if !menu_items.is_empty() && !props.menu_items.is_empty() {
+        menu_items.push(MenuItem::separator());
+    }
+    menu_items.extend(props.menu_items.iter().cloned());
+
+    let on_menu_select = {
+        let toolbar_items = props.toolbar_items.clone();
+        let on_menu_select = props.on_menu_select.clone();
+        Callback::from(
+            move |id: String| match toolbar_items.iter().find(|item| item.id == id) {
+                Some(item) => item.on_click.emit(()),
+                None => on_menu_select.emit(id),
+            },
+        )
+    };
+
+    let show_title_bar = !is_ios && (!props.title.is_empty() || !props.favicon.is_empty());
 
     html! {
-        <header style={base_style} class={props.class} aria-label="Browser window header">
-            <div style="display: flex; align-items: center; gap: 6px;">
+        <>
+        if show_title_bar {
+            <TitleBar
+                title={props.title.clone()}
+                favicon={props.favicon.clone()}
+                variant={props.variant.clone()}
+                style_mode={mode}
+            />
+        }
+        <header
+            ref={header_ref}
+            style={mode.inline(&base_style).to_string()}
+            class={mode.class(css::HEADER, props.class)}
+            aria-label="Browser window header"
+        >
+            <div
+                class={mode.name(css::NAV)}
+                style={mode.inline("display: flex; align-items: center; gap: 6px;")}
+            >
                 if props.show_controls {
                     <BrowserControls
                         on_close={props.on_close.clone()}
                         on_minimize={props.on_minimize.clone()}
                         on_maximize={props.on_maximize.clone()}
                         show_controls={props.show_controls}
+                        inactive={props.inactive}
                         on_close={props.on_close.clone()}
                         on_close_mouse_over={props.on_close_mouse_over.clone()}
                         on_close_mouse_out={props.on_close_mouse_out.clone()}
@@ -745,11 +2215,13 @@ pub fn browser_header(props: &BrowserHeaderProps) -> Html {
                         maximize_aria_label={props.maximize_aria_label}
                         maximize_title={props.maximize_title}
                         maximize_tabindex={props.maximize_tabindex}
+                        style_mode={mode}
+                        on_error={props.on_error.clone()}
                     />
                 }
                 if props.show_controls {
                     if !is_ios {
-                        <button style={props.icon_button_style} aria-label="Sidebar">
+                        <button class={icon_button_class} style={icon_button_style} aria-label="Sidebar">
                             <svg
                                 width="20"
                                 height="15"
@@ -758,12 +2230,12 @@ pub fn browser_header(props: &BrowserHeaderProps) -> Html {
                                 xmlns="http://www.w3.org/2000/svg"
                             >
                                 <path
-                                    d="M2.62346 15H16.4609C18.2202 15 19.0844 14.1358 19.0844 12.4074V2.59259C19.0844 0.864204 18.2202 0 16.4609 0H2.62346C0.874483 0 0 0.864204 0 2.59259V12.4074C0 14.1358 0.874483 15 2.62346 15ZM2.64404 13.5082C1.90329 13.5082 1.48149 13.1173 1.48149 12.3354V2.66461C1.48149 1.89301 1.90329 1.49177 2.64404 1.49177H6.22427V13.5082H2.64404ZM16.4403 1.49177C17.1811 1.49177 17.6029 1.89301 17.6029 2.66461V12.3354C17.6029 13.1173 17.1811 13.5082 16.4403 13.5082H7.67489V1.49177H16.4403ZM4.67078 4.47532C4.94857 4.47532 5.18518 4.2284 5.18518 3.9609C5.18518 3.69341 4.94857 3.46708 4.67078 3.46708H3.05556C2.78806 3.46708 2.55144 3.69341 2.55144 3.9609C2.55144 4.2284 2.78806 4.47532 3.05556 4.47532H4.67078ZM4.67078 6.53293C4.94857 6.53293 5.18518 6.29629 5.18518 6.01853C5.18518 5.75102 4.94857 5.52469 4.67078 5.52469H3.05556C2.78806 5.52469 2.55144 5.75102 2.55144 6.01853C2.55144 6.29629 2.78806 6.53293 3.05556 6.53293H4.67078ZM4.67078 8.59054C4.94857 8.59054 5.18518 8.35392 5.18518 8.08642C5.18518 7.81893 4.94857 7.5926 4.67078 7.5926H3.05556C2.78806 7.5926 2.55144 7.81893 2.55144 8.08642C2.55144 8.35392 2.78806 8.59054 3.05556 8.59054H4.67078Z"
+                                    d={icons::SIDEBAR}
                                     fill="#767676"
                                 />
                             </svg>
                         </button>
-                        <button style={props.icon_button_style} aria-label="Back">
+                        <button class={icon_button_class} style={icon_button_style} aria-label="Back">
                             <svg
                                 width="9"
                                 height="16"
@@ -772,7 +2244,7 @@ pub fn browser_header(props: &BrowserHeaderProps) -> Html {
                                 xmlns="http://www.w3.org/2000/svg"
                             >
                                 <path
-                                    d="M7.5 1.5L1 8L7.5 14.5"
+                                    d={icons::BACK}
                                     stroke="#737373"
                                     stroke-width="1.5"
                                     stroke-linecap="round"
@@ -780,7 +2252,11 @@ pub fn browser_header(props: &BrowserHeaderProps) -> Html {
                                 />
                             </svg>
                         </button>
-                        <button style={props.icon_button_style} aria-label="Forward">
+                        <button
+                            class={mode.name(css::ICON_BUTTON_MUTED)}
+                            style={icon_button_style}
+                            aria-label="Forward"
+                        >
                             <svg
                                 width="9"
                                 height="16"
@@ -789,7 +2265,7 @@ pub fn browser_header(props: &BrowserHeaderProps) -> Html {
                                 xmlns="http://www.w3.org/2000/svg"
                             >
                                 <path
-                                    d="M1 14.5L7.5 8L1 1.5"
+                                    d={icons::FORWARD}
                                     stroke="#BFBFBF"
                                     stroke-width="1.5"
                                     stroke-linecap="round"
@@ -799,50 +2275,70 @@ pub fn browser_header(props: &BrowserHeaderProps) -> Html {
                         </button>
                     }
                 }
+                { for toolbar.left.iter().map(toolbar_button) }
             </div>
             if props.show_address_bar {
-                <div style={address_wrapper_style}>
+                <div
+                    class={mode.name(if props.show_controls {
+                        css::ADDRESS_WITH_CONTROLS
+                    } else {
+                        css::ADDRESS
+                    })}
+                    style={mode.inline(&address_wrapper_style).to_string()}
+                >
                     <AddressBar
+                        input_id={part_id(&props.id_prefix, "address")}
                         url={props.url.clone()}
                         placeholder={props.placeholder}
                         on_url_change={props.on_url_change.clone().unwrap_or_default()}
+                        suggestions={props.suggestions.clone()}
+                        on_suggestion_select={props.on_suggestion_select.clone()}
+                        smart_display={props.smart_display}
                         read_only={props.read_only}
                         input_class={props.input_class}
                         container_class={props.container_class}
                         refresh_button_style={props.refresh_button_style}
                         refresh_button_aria_label={props.refresh_button_aria_label}
+                        load_state={props.load_state.clone()}
+                        on_refresh={props.on_refresh.clone()}
+                        on_stop={props.on_stop.clone()}
+                        zoom={props.zoom}
+                        on_zoom_reset={props.on_zoom_reset.clone()}
+                        on_submit={props.on_submit.clone()}
+                        on_error={props.on_error.clone()}
+                        style_mode={mode}
                     />
                 </div>
             }
-            <div style="display: flex; align-items: center; gap: 6px; margin-left: auto;">
+            <div
+                ref={actions.node_ref}
+                class={mode.name(css::ACTIONS)}
+                style={mode.inline("display: flex; align-items: center; gap: 6px; margin-left: auto;")}
+                role="toolbar"
+                aria-label="Page actions"
+                onkeydown={actions.onkeydown}
+                onfocusin={actions.onfocusin}
+            >
+                { for toolbar.right.iter().map(toolbar_button) }
                 if props.show_controls {
-                    { for props.custom_buttons.iter().cloned() }
-                    <button
-                        style={props.icon_button_style}
-                        onclick={share_onclick.clone()}
-                        onmouseover={share_onmouseover.clone()}
-                        onmouseout={share_onmouseout.clone()}
+                    <ShareMenu
+                        url={props.url.clone()}
+                        title={props.title.clone()}
+                        enabled={props.share_menu}
+                        on_share={props.on_share.clone()}
+                        onclick={props.share_onclick.clone()}
+                        onmouseover={props.share_onmouseover.clone()}
+                        onmouseout={props.share_onmouseout.clone()}
                         onfocus={props.share_onfocus.clone()}
                         onblur={props.share_onblur.clone()}
-                        aria-label="Share"
-                        title="Share"
                         tabindex={props.share_tabindex}
-                    >
-                        <svg
-                            width="15"
-                            height="19"
-                            viewBox="0 0 15 19"
-                            fill="none"
-                            xmlns="http://www.w3.org/2000/svg"
-                        >
-                            <path
-                                d="M7.49467 12.3969C7.91045 12.3969 8.26225 12.056 8.26225 11.6513V3.34416L8.1983 2.06613L8.64605 2.55604L9.81876 3.82343C9.95736 3.97254 10.1493 4.04709 10.3305 4.04709C10.7356 4.04709 11.0341 3.77017 11.0341 3.38676C11.0341 3.17377 10.9488 3.02467 10.7996 2.88621L8.04905 0.255589C7.85715 0.0638861 7.69722 0 7.49467 0C7.30277 0 7.14286 0.0638861 6.94029 0.255589L4.18977 2.88621C4.05117 3.02467 3.96589 3.17377 3.96589 3.38676C3.96589 3.77017 4.25372 4.04709 4.65885 4.04709C4.84009 4.04709 5.04264 3.97254 5.18124 3.82343L6.35395 2.55604L6.80171 2.06613L6.73774 3.34416V11.6513C6.73774 12.056 7.08955 12.3969 7.49467 12.3969ZM2.71855 19H12.2814C14.1045 19 15 18.1054 15 16.3161V8.12611C15 6.33688 14.1045 5.44225 12.2814 5.44225H9.98934V6.98654H12.2601C13.0171 6.98654 13.4648 7.4019 13.4648 8.20066V16.2416C13.4648 17.051 13.0171 17.4557 12.2601 17.4557H2.73988C1.97228 17.4557 1.53519 17.051 1.53519 16.2416V8.20066C1.53519 7.4019 1.97228 6.98654 2.73988 6.98654H5.01065V5.44225H2.71855C0.906181 5.44225 0 6.33688 0 8.12611V16.3161C0 18.1054 0.906181 19 2.71855 19Z"
-                                fill="#767676"
-                            />
-                        </svg>
-                    </button>
+                        button_style={icon_button_style}
+                        button_class={icon_button_class}
+                        style_mode={mode}
+                    />
                     <button
-                        style={props.icon_button_style}
+                        class={icon_button_class}
+                        style={icon_button_style}
                         onclick={tabs_onclick.clone()}
                         onmouseover={tabs_onmouseover.clone()}
                         onmouseout={tabs_onmouseout.clone()}
@@ -860,46 +2356,629 @@ pub fn browser_header(props: &BrowserHeaderProps) -> Html {
                             xmlns="http://www.w3.org/2000/svg"
                         >
                             <path
-                                d="M7.01662 14.6401C7.4887 14.6401 7.87493 14.2646 7.87493 13.7925V8.3745H13.1642C13.6255 8.3745 14.0225 7.97755 14.0225 7.50547C14.0225 7.03341 13.6255 6.63643 13.1642 6.63643H7.87493V1.20768C7.87493 0.735619 7.4887 0.360107 7.01662 0.360107C6.54456 0.360107 6.14758 0.735619 6.14758 1.20768V6.63643H0.869031C0.396973 6.63643 0 7.03341 0 7.50547C0 7.97755 0.396973 8.3745 0.869031 8.3745H6.14758V13.7925C6.14758 14.2646 6.54456 14.6401 7.01662 14.6401Z"
+                                d={icons::TABS}
                                 fill="#767676"
                             />
                         </svg>
                     </button>
-                    <button
-                        style={props.icon_button_style}
-                        onclick={more_onclick.clone()}
-                        onmouseover={more_onmouseover.clone()}
-                        onmouseout={more_onmouseout.clone()}
+                }
+                if props.show_controls || !toolbar.overflow.is_empty() {
+                    <MoreMenu
+                        id={part_id(&props.id_prefix, "more-menu")}
+                        items={menu_items}
+                        on_select={on_menu_select}
+                        onclick={props.more_onclick.clone()}
+                        onmouseover={props.more_onmouseover.clone()}
+                        onmouseout={props.more_onmouseout.clone()}
                         onfocus={props.more_onfocus.clone()}
                         onblur={props.more_onblur.clone()}
-                        aria-label="More options"
-                        title="More options"
                         tabindex={props.more_tabindex}
-                    >
-                        <svg
-                            width="18"
-                            height="19"
-                            viewBox="0 0 18 19"
-                            fill="none"
-                            xmlns="http://www.w3.org/2000/svg"
+                        button_style={icon_button_style}
+                        button_class={icon_button_class}
+                        style_mode={mode}
+                    />
+                }
+            </div>
+            if let Some(progress) = props.load_state.progress() {
+                <div
+                    role="progressbar"
+                    aria-label="Page load progress"
+                    aria-valuemin="0"
+                    aria-valuemax="100"
+                    aria-valuenow={progress.to_string()}
+                    aria-hidden={(!props.load_state.is_loading()).then_some("true")}
+                    class={mode.name(css::PROGRESS)}
+                    style={format!("{} {}", mode.inline(props.progress_bar_style), props.load_state.progress_style())}
+                />
+            }
+        </header>
+        </>
+    }
+}
+
+#[derive(Properties, PartialEq, Clone)]
+pub struct BookmarksBarProps {
+    #[prop_or_default]
+    pub bookmarks: Vec<Bookmark>,
+    /// Called with the URL of the bookmark that was clicked.
+    #[prop_or_default]
+    pub on_select: Callback<String>,
+    /// Prefix of the bar's element ids; generated per instance when empty.
+    #[prop_or_default]
+    pub id: String,
+    #[prop_or_default]
+    pub class: &'static str,
+    #[prop_or(style::BOOKMARKS_BAR_STYLE)]
+    pub style: &'static str,
+    #[prop_or(style::BOOKMARK_ITEM_STYLE)]
+    pub item_style: &'static str,
+    #[prop_or(style::BOOKMARK_MENU_STYLE)]
+    pub menu_style: &'static str,
+    #[prop_or(style::BOOKMARK_OVERFLOW_MENU_STYLE)]
+    pub overflow_menu_style: &'static str,
+    #[prop_or_default]
+    pub style_mode: StyleMode,
+}
+
+#[function_component(BookmarksBar)]
+pub fn bookmarks_bar(props: &BookmarksBarProps) -> Html {
+    let mode = props.style_mode;
+    let bar_id = use_element_id(&props.id, "browser-bookmarks");
+    let list_ref = use_node_ref();
+    let list_width = use_element_width(list_ref.clone(), true);
+    let visible_count = use_state(|| props.bookmarks.len());
+    let open_menu = use_state(|| None::<(BookmarkMenu, i32)>);
+
+    {
+        let list_ref = list_ref.clone();
+        let visible_count = visible_count.clone();
+        use_effect_with(
+            (props.bookmarks.clone(), list_width),
+            move |(bookmarks, _)| {
+                // Every entry stays rendered so it can be measured; the ones
+                // that do not fit are hidden and listed behind the chevron.
+                if let Some(list) = list_ref.cast::<Element>() {
+                    visible_count.set(page::fitting_children(&list).min(bookmarks.len()));
+                }
+            },
+        );
+    }
+
+    let close = {
+        let open_menu = open_menu.clone();
+        let id = bar_id.clone();
+        Callback::from(move |_: ()| {
+            if let Some((menu, _)) = *open_menu {
+                page::focus_by_id(&menu.trigger_id(&id));
+            }
+            open_menu.set(None);
+        })
+    };
+
+    let toggle_menu = |menu: BookmarkMenu| {
+        let open_menu = open_menu.clone();
+        Callback::from(move |e: MouseEvent| {
+            if bookmarks::is_open(*open_menu, menu) {
+                open_menu.set(None);
+                return;
+            }
+            let left = e
+                .current_target()
+                .and_then(|target| target.dyn_into::<web_sys::HtmlElement>().ok())
+                .map_or(0, |trigger| trigger.offset_left());
+            open_menu.set(Some((menu, left)));
+        })
+    };
+
+    let menu = open_menu.map(|(menu, left)| {
+        let (items, label) = menu.entries(&props.bookmarks, *visible_count);
+        let menu_style = match menu {
+            BookmarkMenu::Folder(_) => props.menu_style,
+            BookmarkMenu::Overflow => props.overflow_menu_style,
+        };
+        let on_backdrop_click = {
+            let open_menu = open_menu.clone();
+            Callback::from(move |_: MouseEvent| open_menu.set(None))
+        };
+
+        html! {
+            <>
+                <div
+                    class={mode.name(css::BACKDROP)}
+                    style={mode.inline("position: fixed; inset: 0; z-index: 40;")}
+                    onclick={on_backdrop_click}
+                />
+                <div class={mode.name(menu.class())} style={menu.position(left, mode)}>
+                    <MenuList
+                        items={items}
+                        id={menu.menu_id(&bar_id)}
+                        label={label}
+                        on_select={props.on_select.clone()}
+                        on_close={close.clone()}
+                        menu_style={menu_style}
+                        style_mode={mode}
+                    />
+                </div>
+            </>
+        }
+    });
+
+    let overflowing = *visible_count < props.bookmarks.len();
+
+    html! {
+        <nav
+            class={mode.class(css::BOOKMARKS, props.class)}
+            style={mode.inline(props.style)}
+            aria-label="Bookmarks"
+        >
+            <ul
+                ref={list_ref}
+                class={mode.name(css::BOOKMARK_LIST)}
+                style={mode.inline("display: flex; flex: 1; min-width: 0; gap: 2px; margin: 0; padding: 0; list-style: none; overflow: hidden;")}
+            >
+                { for props.bookmarks.iter().enumerate().map(|(index, bookmark)| {
+                    let hidden = index >= *visible_count;
+                    let icon = if !bookmark.icon.is_empty() {
+                        html! { <img src={bookmark.icon.clone()} alt="" width="14" height="14" /> }
+                    } else if bookmark.is_folder() {
+                        html! {
+                            <svg width="14" height="14" viewBox="0 0 16 16" fill="none" aria-hidden="true">
+                                <path
+                                    d="M1.5 3.5C1.5 2.95 1.95 2.5 2.5 2.5H6L7.5 4H13.5C14.05 4 14.5 4.45 14.5 5V12.5C14.5 13.05 14.05 13.5 13.5 13.5H2.5C1.95 13.5 1.5 13.05 1.5 12.5V3.5Z"
+                                    stroke="#767676"
+                                />
+                            </svg>
+                        }
+                    } else {
+                        html! {
+                            <svg width="14" height="14" viewBox="0 0 16 16" fill="none" aria-hidden="true">
+                                <circle cx="8" cy="8" r="6.5" stroke="#767676" />
+                                <path
+                                    d="M1.5 8H14.5M8 1.5C9.7 3.3 10.5 5.5 10.5 8C10.5 10.5 9.7 12.7 8 14.5C6.3 12.7 5.5 10.5 5.5 8C5.5 5.5 6.3 3.3 8 1.5Z"
+                                    stroke="#767676"
+                                />
+                            </svg>
+                        }
+                    };
+                    let title = html! {
+                        <span
+                            class={mode.name(css::BOOKMARK_TITLE)}
+                            style={mode.inline("overflow: hidden; text-overflow: ellipsis;")}
                         >
-                            <path
-                                d="M2.67776 14.2898H3.97934V15.5914C3.97934 17.3407 4.85401 18.205 6.63458 18.205H14.8189C16.5891 18.205 17.4742 17.3407 17.4742 15.5914V7.32373C17.4742 5.5744 16.5891 4.71016 14.8189 4.71016H13.5174V3.40857C13.5174 1.65923 12.6323 0.794983 10.8621 0.794983H2.67776C0.897191 0.794983 0.022522 1.65923 0.022522 3.40857V11.6762C0.022522 13.4256 0.897191 14.2898 2.67776 14.2898ZM2.69859 12.7904C1.94886 12.7904 1.52195 12.3843 1.52195 11.5929V3.49187C1.52195 2.70051 1.94886 2.29442 2.69859 2.29442H10.8413C11.591 2.29442 12.0179 2.70051 12.0179 3.49187V4.71016H6.63458C4.85401 4.71016 3.97934 5.5744 3.97934 7.32373V12.7904H2.69859ZM6.65539 16.7056C5.90568 16.7056 5.47878 16.2995 5.47878 15.5081V7.40704C5.47878 6.61567 5.90568 6.20957 6.65539 6.20957H14.7981C15.5478 6.20957 15.9747 6.61567 15.9747 7.40704V15.5081C15.9747 16.2995 15.5478 16.7056 14.7981 16.7056H6.65539Z"
-                                fill="#767676"
-                            />
-                        </svg>
-                    </button>
+                            { bookmark.title.clone() }
+                        </span>
+                    };
+
+                    let entry = if bookmark.is_folder() {
+                        let folder = BookmarkMenu::Folder(index);
+                        html! {
+                            <button
+                                id={folder.trigger_id(&bar_id)}
+                                type="button"
+                                class={mode.name(css::BOOKMARK)}
+                                style={mode.inline(props.item_style)}
+                                aria-haspopup="menu"
+                                aria-expanded={bookmarks::is_open(*open_menu, folder).to_string()}
+                                onclick={toggle_menu(folder)}
+                            >
+                                { icon }
+                                { title }
+                            </button>
+                        }
+                    } else {
+                        let onclick = {
+                            let on_select = props.on_select.clone();
+                            let url = bookmark.url.clone();
+                            Callback::from(move |e: MouseEvent| {
+                                e.prevent_default();
+                                on_select.emit(url.clone());
+                            })
+                        };
+                        html! {
+                            <a
+                                href={bookmark.url.clone()}
+                                class={mode.name(css::BOOKMARK)}
+                                style={mode.inline(props.item_style)}
+                                title={bookmark.url.clone()}
+                                onclick={onclick}
+                            >
+                                { icon }
+                                { title }
+                            </a>
+                        }
+                    };
+
+                    html! {
+                        <li
+                            class={mode.name(if hidden { css::BOOKMARK_ENTRY_HIDDEN } else { css::BOOKMARK_ENTRY })}
+                            style={mode.inline(if hidden { "flex-shrink: 0; visibility: hidden;" } else { "flex-shrink: 0;" })}
+                        >
+                            { entry }
+                        </li>
+                    }
+                }) }
+            </ul>
+            if overflowing {
+                <button
+                    id={BookmarkMenu::Overflow.trigger_id(&bar_id)}
+                    type="button"
+                    class={mode.name(css::BOOKMARK)}
+                    style={mode.inline(props.item_style)}
+                    aria-label={MORE_BOOKMARKS_LABEL}
+                    aria-haspopup="menu"
+                    aria-expanded={bookmarks::is_open(*open_menu, BookmarkMenu::Overflow).to_string()}
+                    onclick={toggle_menu(BookmarkMenu::Overflow)}
+                >
+                    <svg width="12" height="12" viewBox="0 0 12 12" fill="none" aria-hidden="true">
+                        <path
+                            d="M2.5 2.5L6 6L2.5 9.5M6.5 2.5L10 6L6.5 9.5"
+                            stroke="#767676"
+                            stroke-width="1.5"
+                            stroke-linecap="round"
+                            stroke-linejoin="round"
+                        />
+                    </svg>
+                </button>
+            }
+            { menu }
+        </nav>
+    }
+}
+
+#[derive(Properties, PartialEq, Clone)]
+pub struct FindBarProps {
+    /// Element whose rendered text is searched.
+    pub target: NodeRef,
+    #[prop_or_default]
+    pub on_close: Callback<()>,
+    #[prop_or_default]
+    pub on_error: Callback<DomError>,
+    /// Id of the bar; generated per instance when empty.
+    #[prop_or_default]
+    pub id: String,
+    #[prop_or_default]
+    pub class: &'static str,
+    #[prop_or(style::FIND_BAR_STYLE)]
+    pub style: &'static str,
+    #[prop_or(style::FIND_INPUT_STYLE)]
+    pub input_style: &'static str,
+    #[prop_or(style::FIND_STATUS_STYLE)]
+    pub status_style: &'static str,
+    #[prop_or(style::FIND_BUTTON_STYLE)]
+    pub button_style: &'static str,
+    #[prop_or(style::FIND_HIGHLIGHT_COLOR)]
+    pub highlight_color: &'static str,
+    #[prop_or(style::FIND_CURRENT_HIGHLIGHT_COLOR)]
+    pub current_highlight_color: &'static str,
+    #[prop_or_default]
+    pub style_mode: StyleMode,
+}
+
+#[function_component(FindBar)]
+pub fn find_bar(props: &FindBarProps) -> Html {
+    let announcer = use_announcer();
+    let id = use_element_id(&props.id, "browser-find");
+    let input_ref = use_node_ref();
+    let find = use_mut_ref(FindState::default);
+    let rerender = use_force_update();
+    let ranges = use_mut_ref(Vec::<Range>::new);
+
+    {
+        let input_ref = input_ref.clone();
+        use_effect_with((), move |_| {
+            if let Some(input) = input_ref.cast::<HtmlInputElement>() {
+                input.focus().ok();
+            }
+        });
+    }
+
+    // Highlights are registered under the bar's id, so only this bar's are
+    // removed when it unmounts or its id changes.
+    use_effect_with(id.clone(), |id| {
+        let id = id.clone();
+        move || page::clear_find_highlights(&id)
+    });
+
+    let on_input = {
+        let find = find.clone();
+        let rerender = rerender.clone();
+        let ranges = ranges.clone();
+        let id = id.clone();
+        let target = props.target.clone();
+        let on_error = props.on_error.clone();
+        let announcer = announcer.clone();
+        Callback::from(move |e: InputEvent| {
+            let value = e.target_unchecked_into::<HtmlInputElement>().value();
+            let found = match target.cast::<web_sys::Node>() {
+                Some(root) if !value.is_empty() => page::find_ranges(&root, &value),
+                _ => Vec::new(),
+            };
+            let mut find = find.borrow_mut();
+            let first = find.search(value, found.len());
+            page::show_find_matches(&id, &found, first);
+            if !find.query.is_empty() {
+                if let Err(error) = announcer.say(&find.status(), Politeness::Polite) {
+                    on_error.emit(error);
+                }
+            }
+            *ranges.borrow_mut() = found;
+            rerender.force_update();
+        })
+    };
+
+    let step = {
+        let find = find.clone();
+        let id = id.clone();
+        let on_error = props.on_error.clone();
+        Callback::from(move |forward: bool| {
+            let mut find = find.borrow_mut();
+            let Some(next) = find.step(forward) else {
+                return;
+            };
+            page::show_find_matches(&id, &ranges.borrow(), Some(next));
+            if let Err(error) = announcer.say(&find.status(), Politeness::Polite) {
+                on_error.emit(error);
+            }
+            rerender.force_update();
+        })
+    };
+
+    let on_key_down = {
+        let step = step.clone();
+        let on_close = props.on_close.clone();
+        Callback::from(move |e: KeyboardEvent| {
+            let key = e.key();
+            let input = KeyInput::new(&key).with_shift(e.shift_key());
+            match find_command(&input) {
+                Some(FindCommand::Next) => step.emit(true),
+                Some(FindCommand::Previous) => step.emit(false),
+                Some(FindCommand::Close) => {
+                    e.stop_propagation();
+                    on_close.emit(());
+                }
+                None => return,
+            }
+            e.prevent_default();
+        })
+    };
+
+    let highlight_css = highlight_css(&id, props.highlight_color, props.current_highlight_color);
+    let FindState { query, total, .. } = find.borrow().clone();
+    let status = find.borrow().visible_status();
+    let mode = props.style_mode;
+    let button_class = mode.name(css::FIND_BUTTON);
+    let button_style = mode.inline(props.button_style);
+
+    html! {
+        <div
+            id={id.clone()}
+            class={mode.class(css::FIND, props.class)}
+            style={mode.inline(props.style)}
+            role="search"
+            aria-label="Find in page"
+        >
+            <style>{ highlight_css }</style>
+            <input
+                ref={input_ref}
+                id={format!("{id}-input")}
+                type="search"
+                class={mode.name(css::FIND_INPUT)}
+                style={mode.inline(props.input_style)}
+                placeholder="Find in page"
+                aria-label="Find in page"
+                aria-describedby={format!("{id}-status")}
+                value={query}
+                oninput={on_input}
+                onkeydown={on_key_down}
+            />
+            <span
+                id={format!("{id}-status")}
+                class={mode.name(css::FIND_STATUS)}
+                style={mode.inline(props.status_style)}
+            >
+                { status }
+            </span>
+            <button
+                type="button"
+                class={button_class}
+                style={button_style}
+                aria-label="Previous match"
+                disabled={total == 0}
+                onclick={let step = step.clone(); Callback::from(move |_| step.emit(false))}
+            >
+                <svg width="14" height="14" viewBox="0 0 16 16" fill="none" aria-hidden="true">
+                    <path d="M4 10L8 6L12 10" stroke="#767676" stroke-width="1.5" stroke-linecap="round" stroke-linejoin="round" />
+                </svg>
+            </button>
+            <button
+                type="button"
+                class={button_class}
+                style={button_style}
+                aria-label="Next match"
+                disabled={total == 0}
+                onclick={Callback::from(move |_| step.emit(true))}
+            >
+                <svg width="14" height="14" viewBox="0 0 16 16" fill="none" aria-hidden="true">
+                    <path d="M4 6L8 10L12 6" stroke="#767676" stroke-width="1.5" stroke-linecap="round" stroke-linejoin="round" />
+                </svg>
+            </button>
+            <button
+                type="button"
+                class={button_class}
+                style={button_style}
+                aria-label="Close find bar"
+                onclick={let on_close = props.on_close.clone(); Callback::from(move |_| on_close.emit(()))}
+            >
+                <svg width="14" height="14" viewBox="0 0 16 16" fill="none" aria-hidden="true">
+                    <path d="M4 4L12 12M12 4L4 12" stroke="#767676" stroke-width="1.5" stroke-linecap="round" />
+                </svg>
+            </button>
+        </div>
+    }
+}
+
+/// Tracks the width in pixels of the element behind `node_ref` with a
+/// `ResizeObserver` while `enabled` is true.
+#[hook]
+pub fn use_element_width(node_ref: NodeRef, enabled: bool) -> Option<f64> {
+    let width = use_state(|| None::<f64>);
+
+    {
+        let width = width.clone();
+        use_effect_with(enabled, move |enabled| {
+            let observer = node_ref
+                .cast::<Element>()
+                .filter(|_| *enabled)
+                .and_then(|element| {
+                    let observed = element.clone();
+                    let callback = Closure::<dyn Fn()>::new(move || {
+                        width.set(Some(observed.client_width() as f64));
+                    });
+                    let observer = ResizeObserver::new(callback.as_ref().unchecked_ref()).ok()?;
+                    observer.observe(&element);
+                    Some((observer, callback))
+                });
+            move || {
+                if let Some((observer, _callback)) = observer {
+                    observer.disconnect();
                 }
-            </div>
-        </header>
+            }
+        });
+    }
+
+    *width
+}
+
+/// Returns `id`, or an id starting with `prefix` that is generated once for
+/// the component instance when `id` is empty.
+#[hook]
+pub fn use_element_id(id: &str, prefix: &'static str) -> String {
+    (*use_memo(id.to_string(), move |id| element_id(id, prefix))).clone()
+}
+
+/// Whether the page is shown in a forced colors mode such as Windows High
+/// Contrast, updated while the component is mounted.
+#[hook]
+pub fn use_forced_colors() -> bool {
+    let forced_colors = use_state(|| false);
+
+    {
+        let forced_colors = forced_colors.clone();
+        use_effect_with((), move |_| {
+            let listener = dom::MediaListener::new(style::FORCED_COLORS_QUERY, move |matches| {
+                forced_colors.set(matches)
+            });
+            move || drop(listener)
+        });
+    }
+
+    *forced_colors
+}
+
+/// Handlers of a WAI-ARIA toolbar with a roving tabindex, returned by
+/// [`use_roving_tabindex`].
+pub struct RovingTabindex {
+    pub node_ref: NodeRef,
+    pub onkeydown: Callback<KeyboardEvent>,
+    pub onfocusin: Callback<FocusEvent>,
+}
+
+/// Makes the element behind the returned `node_ref` a single tab stop whose
+/// buttons are reached with the arrow keys, `Home` and `End`. Attach the
+/// returned handlers to the same element.
+#[hook]
+pub fn use_roving_tabindex(on_error: Callback<DomError>) -> RovingTabindex {
+    let node_ref = use_node_ref();
+
+    {
+        let node_ref = node_ref.clone();
+        let on_error = on_error.clone();
+        use_effect(move || {
+            if let Some(toolbar) = node_ref.cast::<Element>() {
+                if let Err(error) = dom::sync_roving_tabindex(&toolbar, None) {
+                    on_error.emit(error);
+                }
+            }
+        });
+    }
+
+    let onkeydown = {
+        let node_ref = node_ref.clone();
+        let on_error = on_error.clone();
+        Callback::from(move |e: KeyboardEvent| {
+            if let Some(toolbar) = node_ref.cast::<Element>() {
+                if let Err(error) = dom::rove_toolbar(&e, &toolbar) {
+                    on_error.emit(error);
+                }
+            }
+        })
+    };
+
+    let onfocusin = {
+        let node_ref = node_ref.clone();
+        Callback::from(move |e: FocusEvent| {
+            if let Some(toolbar) = node_ref.cast::<Element>() {
+                if let Err(error) = dom::sync_roving_tabindex(&toolbar, e.target().as_ref()) {
+                    on_error.emit(error);
+                }
+            }
+        })
+    };
+
+    RovingTabindex {
+        node_ref,
+        onkeydown,
+        onfocusin,
+    }
+}
+
+/// Reads messages to screen readers through live regions shared by every
+/// frame on the page.
+///
+/// Get one with [`use_announcer`]. The texts of [`Announcement`]s come from
+/// an `AnnouncerMessages` context when one is provided, e.g.
+/// `<ContextProvider<AnnouncerMessages> context={messages}>`.
+#[derive(Clone, PartialEq, Default)]
+pub struct Announcer {
+    messages: AnnouncerMessages,
+}
+
+impl Announcer {
+    /// Reads `message` on the `politeness` channel.
+    pub fn say(&self, message: &str, politeness: Politeness) -> Result<(), DomError> {
+        dom::announce(message, politeness)
+    }
+
+    /// Reads the message for `announcement`.
+    pub fn announce(&self, announcement: &Announcement) -> Result<(), DomError> {
+        self.say(
+            &self.messages.message(announcement),
+            announcement.politeness(),
+        )
     }
 }
 
+#[hook]
+pub fn use_announcer() -> Announcer {
+    let messages = use_context::<AnnouncerMessages>().unwrap_or_default();
+    Announcer { messages }
+}
+
+/// Keys handled by [`use_keyboard`]. `Escape` only counts when it is pressed
+/// inside the container the returned ref is put on, or anywhere while the ref
+/// is not attached.
 #[derive(Clone, PartialEq)]
 pub struct KeyboardNavigationOptions {
     pub on_escape: Option<Callback<()>>,
     pub on_enter: Option<Callback<()>>,
     pub trap_focus: bool,
+    pub on_error: Option<Callback<DomError>>,
+}
+
+fn report_error(on_error: &Option<Callback<DomError>>, error: DomError) {
+    if let Some(on_error) = on_error {
+        on_error.emit(error);
+    }
 }
 
 #[hook]
@@ -908,22 +2987,26 @@ pub fn use_keyboard(options: KeyboardNavigationOptions) -> NodeRef {
 
     {
         let options = options.clone();
+        let on_error = options.on_error.clone();
         let container_ref = container_ref.clone();
 
         use_effect(move || {
-            let closure = Closure::<dyn Fn(KeyboardEvent)>::wrap(Box::new(
-                move |event: KeyboardEvent| {
+            let closure =
+                Closure::<dyn Fn(KeyboardEvent)>::wrap(Box::new(move |event: KeyboardEvent| {
                     let key = event.key();
-                    let target = event.target();
+                    let target = dom::origin(&event);
+                    let inside = container_ref
+                        .cast::<Element>()
+                        .is_none_or(|container| dom::started_inside(&event, &container));
 
-                    match key.as_str() {
-                        "Escape" => {
+                    match navigation_command(&KeyInput::new(&key), options.trap_focus, inside) {
+                        Some(NavigationCommand::Escape) => {
                             if let Some(callback) = &options.on_escape {
                                 event.prevent_default();
                                 callback.emit(());
                             }
                         }
-                        "Enter" => {
+                        Some(NavigationCommand::Enter) => {
                             if let Some(callback) = &options.on_enter {
                                 if let Some(target_elem) =
                                     target.and_then(|t| t.dyn_into::<Element>().ok())
@@ -935,60 +3018,36 @@ pub fn use_keyboard(options: KeyboardNavigationOptions) -> NodeRef {
                                 }
                             }
                         }
-                        "Tab" if options.trap_focus => {
+                        Some(NavigationCommand::TrapFocus) => {
                             if let Some(container) = container_ref.cast::<Element>() {
-                                let selector = "button, [href], input, select, textarea, [tabindex]:not([tabindex=\"-1\"])";
-                                let focusables = container.query_selector_all(selector).unwrap();
-
-                                let length = focusables.length();
-                                if length == 0 {
-                                    return;
-                                }
-
-                                let first = focusables
-                                    .item(0)
-                                    .and_then(|e| e.dyn_into::<web_sys::HtmlElement>().ok());
-                                let last = focusables
-                                    .item(length - 1)
-                                    .and_then(|e| e.dyn_into::<web_sys::HtmlElement>().ok());
-
-                                let document = web_sys::window().unwrap().document().unwrap();
-                                let active = document.active_element();
-
-                                if event.shift_key() {
-                                    if active == first.as_ref().map(|e| e.clone().into()) {
-                                        event.prevent_default();
-                                        if let Some(elem) = last {
-                                            elem.focus().ok();
-                                        }
-                                    }
-                                } else if active == last.as_ref().map(|e| e.clone().into()) {
-                                    event.prevent_default();
-                                    if let Some(elem) = first {
-                                        elem.focus().ok();
-                                    }
+                                if let Err(error) = dom::trap_focus(&event, &container) {
+                                    report_error(&options.on_error, error);
                                 }
                             }
                         }
-                        _ => {}
+                        None => {}
                     }
-                },
-            )
-                as Box<dyn Fn(KeyboardEvent)>);
-
-            web_sys::window()
-                .unwrap()
-                .add_event_listener_with_callback("keydown", closure.as_ref().unchecked_ref())
-                .unwrap();
+                })
+                    as Box<dyn Fn(KeyboardEvent)>);
+
+            let listening = dom::window().and_then(|window| {
+                dom::add_event_listener(&window, "keydown", closure.as_ref().unchecked_ref())
+                    .map(|_| window)
+            });
+            if let Err(error) = &listening {
+                report_error(&on_error, error.clone());
+            }
 
             move || {
-                web_sys::window()
-                    .unwrap()
-                    .remove_event_listener_with_callback(
+                if let Ok(window) = listening {
+                    if let Err(error) = dom::remove_event_listener(
+                        &window,
                         "keydown",
                         closure.as_ref().unchecked_ref(),
-                    )
-                    .unwrap();
+                    ) {
+                        report_error(&on_error, error);
+                    }
+                }
                 drop(closure);
             }
         });
@@ -1019,6 +3078,77 @@ pub struct BrowserFrameProps {
     #[prop_or_default]
     pub on_url_change: Option<Callback<InputEvent>>,
 
+    /// Source of the autocomplete suggestions shown under the address bar.
+    ///
+    /// Defaults to `SuggestionProvider::None`, which disables the dropdown.
+    #[prop_or_default]
+    pub suggestions: SuggestionProvider,
+
+    /// Callback when a suggestion is picked from the dropdown.
+    #[prop_or_default]
+    pub on_suggestion_select: Callback<Suggestion>,
+
+    /// Whether the unfocused address bar shows a simplified URL (no scheme or
+    /// `www.`, emphasized domain, greyed-out path).
+    ///
+    /// Defaults to `true`.
+    #[prop_or(true)]
+    pub smart_display: bool,
+
+    /// Title of the displayed page, shown in the title bar (`Variant::Default`)
+    /// or in the active tab (`Variant::Tabs`).
+    ///
+    /// When the content contains a same-origin `iframe`, the title of the loaded
+    /// document replaces this value.
+    #[prop_or_default]
+    pub title: String,
+
+    /// URL of the page icon shown next to the title. A globe icon is shown when
+    /// empty or when the image fails to load.
+    #[prop_or_default]
+    pub favicon: String,
+
+    /// Saved links shown in a bookmarks bar under the header. The bar is hidden
+    /// when empty.
+    #[prop_or_default]
+    pub bookmarks: Vec<Bookmark>,
+
+    /// Callback invoked with the URL the frame navigates to from a bookmark.
+    ///
+    /// The address bar shows the new URL and an `iframe` inside the content is
+    /// pointed at it; other content should react through this callback.
+    #[prop_or_default]
+    pub on_navigate: Callback<String>,
+
+    /// Callback invoked with the text submitted from the address bar with
+    /// `Enter` or by picking a suggestion.
+    ///
+    /// The frame does not navigate on its own, as the text may be a search
+    /// term; update `url` to go to the address it stands for.
+    #[prop_or_default]
+    pub on_submit: Callback<String>,
+
+    /// Enables the find-in-page bar, opened with `Cmd/Ctrl+F` while focus is
+    /// inside the frame. It searches the rendered text of the content.
+    #[prop_or(true)]
+    pub find_in_page: bool,
+
+    /// Zoom factor of the content, e.g. `1.25` for 125%. The content is scaled
+    /// with its layout preserved, and the level is shown in the address bar when
+    /// it is not 100%.
+    #[prop_or(1.0)]
+    pub zoom: f64,
+
+    /// Callback invoked with the new zoom factor when it changes through the
+    /// `Cmd/Ctrl` `+`, `-` and `0` shortcuts or the address bar badge.
+    #[prop_or_default]
+    pub on_zoom_change: Callback<f64>,
+
+    /// Callback invoked when a DOM operation fails, e.g. when announcing to
+    /// screen readers or reloading the page. Failures never panic.
+    #[prop_or_default]
+    pub on_error: Callback<DomError>,
+
     /// Callback when the close button is clicked.
     #[prop_or_default]
     pub on_close: Callback<()>,
@@ -1037,6 +3167,13 @@ pub struct BrowserFrameProps {
     #[prop_or(true)]
     pub show_controls: bool,
 
+    /// Whether the window is inactive, which draws the window controls grey
+    /// until the pointer or focus enters them.
+    ///
+    /// Defaults to `false`.
+    #[prop_or_default]
+    pub inactive: bool,
+
     /// Whether to show the address bar.
     ///
     /// Defaults to `true`.
@@ -1057,18 +3194,61 @@ pub struct BrowserFrameProps {
     #[prop_or_default]
     pub variant: Variant,
 
-    /// Custom buttons to render in the browser header.
+    /// How the frame chrome is styled. `StyleMode::Stylesheet` replaces the
+    /// inline default styles with stable `browser-frame__*` classes styled by
+    /// `browser_rs::common::css::STYLESHEET`.
     #[prop_or_default]
-    pub custom_buttons: Vec<Html>,
+    pub style_mode: StyleMode,
 
-    /// CSS classes for styling the outer container of the browser frame.
+    /// Transitions played when the frame opens, closes, minimizes or
+    /// maximizes. Skipped while the user prefers reduced motion.
+    #[prop_or_default]
+    pub animation: Animation,
+
+    /// Callback when a window transition finishes, or right away when the
+    /// phase doesn't animate. Unmount the frame here after
+    /// `AnimationPhase::Close`.
+    #[prop_or_default]
+    pub on_animation_end: Callback<AnimationPhase>,
+
+    /// Whether the host shows the frame minimized. Setting it plays the
+    /// minimize transition, and clearing it restores a minimized frame with
+    /// `AnimationPhase::Restore`.
+    #[prop_or_default]
+    pub minimized: bool,
+
+    /// Buttons added to the header toolbar, placed left or right of the
+    /// address bar or inside the "More options" menu.
+    ///
+    /// Inline items that do not fit in the header collapse into the menu.
+    #[prop_or_default]
+    pub toolbar_items: Vec<ToolbarItem>,
+
+    /// Width in pixels taken by each inline toolbar item when deciding how
+    /// many fit in the header.
     ///
-    /// Defaults to: `"rounded-lg border shadow-lg overflow-hidden bg-white dark:bg-gray-800 border-gray-200 dark:border-gray-700"`.
-    #[prop_or(
-        "rounded-lg border shadow-lg overflow-hidden bg-white dark:bg-gray-800 border-gray-200 dark:border-gray-700"
-    )]
+    /// Defaults to `32.0`.
+    #[prop_or(32.0)]
+    pub toolbar_item_width: f64,
+
+    /// Header width in pixels kept free for the window controls, address bar
+    /// and built-in buttons before toolbar items start to collapse.
+    ///
+    /// Defaults to `420.0`.
+    #[prop_or(420.0)]
+    pub toolbar_reserved_width: f64,
+
+    /// CSS classes for styling the outer container of the browser frame.
+    /// `browser_rs::common::tailwind::FRAME_CLASS` restores the Tailwind look.
+    #[prop_or_default]
     pub class: &'static str,
 
+    /// Default look of the outer container: background, border, radius and
+    /// shadow, following the system color scheme. Set to `""` when styling
+    /// the container through `class`.
+    #[prop_or(style::FRAME_BASE_STYLE)]
+    pub base_style: &'static str,
+
     /// CSS classes for styling the browser frame.
     #[prop_or_default]
     pub frame_class: &'static str,
@@ -1096,41 +3276,52 @@ pub struct BrowserFrameProps {
     pub container_class: &'static str,
 
     /// CSS classes for the address bar input element.
-    #[prop_or("text-black dark:text-white")]
+    #[prop_or_default]
     pub input_class: &'static str,
 
     /// Inline styles for the refresh button.
     ///
-    /// Defaults to: `"position: absolute; top: 50%; right: 8px; transform: translateY(-50%); padding: 4px; background: none; border: none; box-shadow: none; outline: none; cursor: pointer;"`.
-    #[prop_or(
-        "position: absolute; top: 50%; right: 8px; transform: translateY(-50%); padding: 4px; background: none; border: none; box-shadow: none; outline: none; cursor: pointer;"
-    )]
+    /// Defaults to: `"position: absolute; top: 50%; right: 8px; transform: translateY(-50%); padding: 4px; background: none; border: none; box-shadow: none; cursor: pointer;"`.
+    #[prop_or(style::REFRESH_BUTTON_STYLE)]
     pub refresh_button_style: &'static str,
 
     /// ARIA label for the refresh button.
     ///
     /// Defaults to `"Refresh"`.
-    #[prop_or("Refresh")]
+    #[prop_or(REFRESH_LABEL)]
     pub refresh_button_aria_label: &'static str,
 
+    /// Loading state of the page, shown as a progress bar along the bottom of
+    /// the header. While loading, the refresh button turns into a stop button.
+    #[prop_or_default]
+    pub load_state: LoadState,
+
+    /// Callback when the refresh button is clicked.
+    ///
+    /// When `None`, the refresh button reloads the whole window.
+    #[prop_or_default]
+    pub on_refresh: Option<Callback<()>>,
+
+    /// Callback when the stop button is clicked while loading.
+    #[prop_or_default]
+    pub on_stop: Callback<()>,
+
     /// Inline styles for icon buttons (close, minimize, maximize).
     ///
-    /// Defaults to: `"padding: 4px; cursor: pointer; background: none; border: none; box-shadow: none; outline: none;"`.
-    #[prop_or(
-        "padding: 4px; cursor: pointer; background: none; border: none; box-shadow: none; outline: none;"
-    )]
+    /// Defaults to: `"padding: 4px; cursor: pointer; background: none; border: none; box-shadow: none;"`.
+    #[prop_or(style::ICON_BUTTON_STYLE)]
     pub icon_button_style: &'static str,
 
     /// Inline styles for the address bar wrapper.
     ///
     /// Defaults to: `"flex: 1; display: flex; justify-content: center; padding-right: 8px;"`.
-    #[prop_or("flex: 1; display: flex; justify-content: center; padding-right: 8px;")]
+    #[prop_or(style::ADDRESS_WRAPPER_BASE_STYLE)]
     pub address_wrapper_base_style: &'static str,
 
     /// Inline styles for the header container.
     ///
     /// Defaults to: `"display: flex; align-items: center; position: relative;"`.
-    #[prop_or("display: flex; align-items: center; position: relative;")]
+    #[prop_or(style::HEADER_BASE_STYLE)]
     pub header_base_style: &'static str,
 
     /// Callbacks and styles for the close button and related elements.
@@ -1208,6 +3399,17 @@ pub struct BrowserFrameProps {
     /// Style and callbacks for the share button.
     #[prop_or_default]
     pub share_button_style: &'static str,
+
+    /// Opens the built-in share menu: the native share sheet through
+    /// `navigator.share` when available, otherwise a popover with "Copy link",
+    /// "Email" and a QR code for the current URL.
+    #[prop_or(true)]
+    pub share_menu: bool,
+
+    /// Callback invoked with the target the page was shared to.
+    #[prop_or_default]
+    pub on_share: Callback<ShareTarget>,
+
     #[prop_or_default]
     pub share_onclick: Callback<()>,
     #[prop_or_default]
@@ -1240,6 +3442,16 @@ pub struct BrowserFrameProps {
     /// Style and callbacks for the more button.
     #[prop_or_default]
     pub more_button_style: &'static str,
+
+    /// Items of the menu opened by the "More options" button. The button only
+    /// emits `more_onclick` when this is empty.
+    #[prop_or_default]
+    pub menu_items: Vec<MenuItem>,
+
+    /// Callback invoked with the `id` of the selected menu item.
+    #[prop_or_default]
+    pub on_menu_select: Callback<String>,
+
     #[prop_or_default]
     pub more_onclick: Callback<()>,
     #[prop_or_default]
@@ -1288,21 +3500,21 @@ pub struct BrowserFrameProps {
 /// }
 /// ```
 ///
-/// ## With Custom Buttons
+/// ## With Toolbar Items
 /// ```rust
 /// use yew::prelude::*;
-/// use browser_rs::yew::BrowserFrame;
+/// use browser_rs::yew::{BrowserFrame, ToolbarItem};
 ///
 /// #[function_component(App)]
 /// pub fn app() -> Html {
-///     let custom_button = html! {
-///         <button>{ "Custom Button" }</button>
-///     };
+///     let bookmark = ToolbarItem::new("bookmark", "Bookmark")
+///         .with_icon(html! { <span>{ "★" }</span> })
+///         .with_on_click(Callback::from(|_| log::info!("Bookmarked")));
 ///
 ///     html! {
 ///         <BrowserFrame
 ///             url={"https://opensass.org".to_string()}
-///             custom_buttons={vec![custom_button]}
+///             toolbar_items={vec![bookmark]}
 ///         >
 ///             <p>{ "Custom button in the header!" }</p>
 ///         </BrowserFrame>
@@ -1343,45 +3555,342 @@ pub struct BrowserFrameProps {
 /// - Accessibility attributes (`aria-*`) are provided.
 #[function_component(BrowserFrame)]
 pub fn browser_frame(props: &BrowserFrameProps) -> Html {
-    let on_close = props.on_close.clone();
+    let announcer = use_announcer();
+    let announce = {
+        let announcer = announcer.clone();
+        let on_error = props.on_error.clone();
+        Callback::from(move |announcement: Announcement| {
+            if let Err(error) = announcer.announce(&announcement) {
+                on_error.emit(error);
+            }
+        })
+    };
+
+    let animation = props.animation;
+    let animation_state =
+        use_state(|| AnimationState::start(animation, AnimationPhase::Open, false));
+
+    let start_animation = {
+        let animation_state = animation_state.clone();
+        let on_animation_end = props.on_animation_end.clone();
+        Callback::from(move |phase: AnimationPhase| {
+            let state = AnimationState::start(animation, phase, dom::prefers_reduced_motion());
+            animation_state.set(state);
+            if state == AnimationState::Done(phase) {
+                on_animation_end.emit(phase);
+            }
+        })
+    };
+
+    let on_close = {
+        let on_close = props.on_close.clone();
+        let start_animation = start_animation.clone();
+        let announce = announce.clone();
+        Callback::from(move |_| {
+            on_close.emit(());
+            announce.emit(Announcement::Closed);
+            start_animation.emit(AnimationPhase::Close);
+        })
+    };
+    let on_minimize = {
+        let on_minimize = props.on_minimize.clone();
+        let start_animation = start_animation.clone();
+        let announce = announce.clone();
+        Callback::from(move |_| {
+            on_minimize.emit(());
+            announce.emit(Announcement::Minimized);
+            start_animation.emit(AnimationPhase::Minimize);
+        })
+    };
+    let on_maximize = {
+        let on_maximize = props.on_maximize.clone();
+        let start_animation = start_animation.clone();
+        let announce = announce.clone();
+        Callback::from(move |_| {
+            on_maximize.emit(());
+            announce.emit(Announcement::Maximized);
+            start_animation.emit(AnimationPhase::Maximize);
+        })
+    };
+
     let container_ref = use_keyboard(KeyboardNavigationOptions {
-        on_escape: Some(Callback::from(move |_| on_close.emit(()))),
+        on_escape: Some(on_close.clone()),
         on_enter: None,
         trap_focus: false,
+        on_error: Some(props.on_error.clone()),
     });
 
-    let size_style = props.size.to_style();
-    let combined_style = format!("{} {}", size_style, props.style);
+    {
+        let animation_state = animation_state.clone();
+        let on_animation_end = props.on_animation_end.clone();
+        use_effect_with((), move |_| {
+            if let AnimationState::Done(phase) = *animation_state {
+                on_animation_end.emit(phase);
+            }
+        });
+    }
+    {
+        let animation_state = animation_state.clone();
+        let container_ref = container_ref.clone();
+        let on_animation_end = props.on_animation_end.clone();
+        use_effect_with(*animation_state, move |state| match *state {
+            AnimationState::Pending(phase) if !dom::prefers_reduced_motion() => {
+                // Lay out the start style first so the switch transitions.
+                if let Some(container) = container_ref.cast::<HtmlElement>() {
+                    let _ = container.offset_width();
+                }
+                animation_state.set(AnimationState::Running(phase));
+            }
+            AnimationState::Pending(phase) => {
+                animation_state.set(AnimationState::Done(phase));
+                on_animation_end.emit(phase);
+            }
+            _ => {}
+        });
+    }
+    {
+        let animation_state = animation_state.clone();
+        use_effect_with(props.minimized, move |&minimized| {
+            if let Some(phase) = animation_state.follow_minimized(minimized) {
+                start_animation.emit(phase);
+            }
+        });
+    }
+
+    let on_transition_end = {
+        let animation_state = animation_state.clone();
+        let on_animation_end = props.on_animation_end.clone();
+        Callback::from(move |e: TransitionEvent| {
+            if e.target() != e.current_target() {
+                return;
+            }
+            if let Some(phase) = animation_state.finished(&e.property_name()) {
+                animation_state.set(AnimationState::Done(phase));
+                on_animation_end.emit(phase);
+            }
+        })
+    };
+
+    let current_url = use_state(|| props.url.clone());
+    let page_title = use_state(|| props.title.clone());
+    let page_favicon = use_state(|| props.favicon.clone());
+
+    {
+        let current_url = current_url.clone();
+        use_effect_with(props.url.clone(), move |url| {
+            current_url.set(url.clone());
+        });
+    }
+
+    {
+        let page_title = page_title.clone();
+        use_effect_with(props.title.clone(), move |title| {
+            page_title.set(title.clone());
+        });
+    }
+
+    {
+        let page_favicon = page_favicon.clone();
+        use_effect_with(props.favicon.clone(), move |favicon| {
+            page_favicon.set(favicon.clone());
+        });
+    }
+
+    {
+        let title = (*page_title).clone();
+        let was_loading = use_mut_ref(|| false);
+        use_effect_with(props.load_state.clone(), move |load_state| {
+            let was_loading = was_loading.replace(load_state.is_loading());
+            if let Some(announcement) = Announcement::for_load(was_loading, load_state, &title) {
+                announce.emit(announcement);
+            }
+        });
+    }
+
+    {
+        let page_title = page_title.clone();
+        let page_favicon = page_favicon.clone();
+        let container_ref = container_ref.clone();
+        use_effect_with((), move |_| {
+            // `load` does not bubble, so listen in the capture phase to catch
+            // iframes anywhere inside the frame.
+            let closure = Closure::<dyn Fn(Event)>::wrap(Box::new(move |event: Event| {
+                if let Some((title, favicon)) = page::read_page_info(&event) {
+                    if !title.is_empty() {
+                        page_title.set(title);
+                    }
+                    page_favicon.set(favicon.unwrap_or_default());
+                }
+            }) as Box<dyn Fn(Event)>);
+
+            let container = container_ref.cast::<Element>();
+            if let Some(container) = &container {
+                let _ = container.add_event_listener_with_callback_and_bool(
+                    "load",
+                    closure.as_ref().unchecked_ref(),
+                    true,
+                );
+            }
+
+            move || {
+                if let Some(container) = container {
+                    let _ = container.remove_event_listener_with_callback_and_bool(
+                        "load",
+                        closure.as_ref().unchecked_ref(),
+                        true,
+                    );
+                }
+                drop(closure);
+            }
+        });
+    }
+
+    let navigate = {
+        let current_url = current_url.clone();
+        let container_ref = container_ref.clone();
+        let on_navigate = props.on_navigate.clone();
+        Callback::from(move |url: String| {
+            current_url.set(url.clone());
+            if let Some(iframe) = container_ref
+                .cast::<Element>()
+                .and_then(|container| container.query_selector("iframe").ok().flatten())
+                .and_then(|iframe| iframe.dyn_into::<HtmlIFrameElement>().ok())
+            {
+                iframe.set_src(&url);
+            }
+            on_navigate.emit(url);
+        })
+    };
+
+    let find_open = use_state(|| false);
+    let content_ref = use_node_ref();
+
+    let zoom = use_state(|| props.zoom);
+    {
+        let zoom = zoom.clone();
+        use_effect_with(props.zoom, move |level| {
+            zoom.set(*level);
+            || ()
+        });
+    }
+
+    let set_zoom = {
+        let zoom = zoom.clone();
+        let on_zoom_change = props.on_zoom_change.clone();
+        Callback::from(move |level: f64| {
+            if (level - *zoom).abs() > f64::EPSILON {
+                zoom.set(level);
+                on_zoom_change.emit(level);
+            }
+        })
+    };
+
+    let on_key_down = {
+        let find_open = find_open.clone();
+        let container_ref = container_ref.clone();
+        let find_in_page = props.find_in_page;
+        let zoom = *zoom;
+        let set_zoom = set_zoom.clone();
+        Callback::from(move |e: KeyboardEvent| {
+            let key = e.key();
+            let input = KeyInput::new(&key)
+                .with_ctrl(e.ctrl_key())
+                .with_meta(e.meta_key());
+            let Some(command) = frame_command(&input, find_in_page) else {
+                return;
+            };
+            e.prevent_default();
+            match command {
+                FrameCommand::Find => {
+                    if !*find_open {
+                        find_open.set(true);
+                    } else if let Some(input) = container_ref
+                        .cast::<Element>()
+                        .and_then(|frame| frame.query_selector("input[type='search']").ok())
+                        .flatten()
+                        .and_then(|input| input.dyn_into::<HtmlInputElement>().ok())
+                    {
+                        input.focus().ok();
+                        input.select();
+                    }
+                }
+                FrameCommand::ZoomIn => set_zoom.emit(zoom_step(zoom, true)),
+                FrameCommand::ZoomOut => set_zoom.emit(zoom_step(zoom, false)),
+                FrameCommand::ZoomReset => set_zoom.emit(1.0),
+            }
+        })
+    };
+
+    let on_find_close = {
+        let find_open = find_open.clone();
+        let content_ref = content_ref.clone();
+        Callback::from(move |_| {
+            find_open.set(false);
+            if let Some(content) = content_ref.cast::<web_sys::HtmlElement>() {
+                content.focus().ok();
+            }
+        })
+    };
+
+    let mode = props.style_mode;
+    let mut combined_style = match mode {
+        StyleMode::Inline => frame_style(props.base_style, &props.size, props.style),
+        StyleMode::Stylesheet => props.style.to_string(),
+    };
+    let motion_style = animation_state.style(animation);
+    if !motion_style.is_empty() {
+        combined_style = format!("{combined_style} {motion_style}");
+    }
 
     html! {
         <article
             ref={container_ref}
             id={props.id}
-            class={props.class}
+            class={mode.class(&frame_class(&props.variant, &props.size), props.class)}
             style={combined_style}
             role="application"
             aria-label={props.aria_label}
             aria-describedby={props.aria_describedby}
+            aria-busy={props.load_state.is_loading().then_some("true")}
             tabindex={Some("-1")}
+            onkeydown={on_key_down}
+            ontransitionend={on_transition_end}
         >
             <BrowserHeader
-                url={props.url.clone()}
+                id_prefix={props.id}
+                url={(*current_url).clone()}
                 placeholder={props.placeholder}
                 on_url_change={props.on_url_change.clone()}
-                on_close={props.on_close.clone()}
-                on_minimize={props.on_minimize.clone()}
-                on_maximize={props.on_maximize.clone()}
+                suggestions={props.suggestions.clone()}
+                on_suggestion_select={props.on_suggestion_select.clone()}
+                smart_display={props.smart_display}
+                title={(*page_title).clone()}
+                favicon={(*page_favicon).clone()}
+                on_close={on_close}
+                on_minimize={on_minimize}
+                on_maximize={on_maximize}
                 show_controls={props.show_controls}
+                inactive={props.inactive}
                 show_address_bar={props.show_address_bar}
                 read_only={props.read_only}
                 variant={props.variant.clone()}
                 size={props.size.clone()}
-                custom_buttons={props.custom_buttons.clone()}
+                style_mode={mode}
+                toolbar_items={props.toolbar_items.clone()}
+                toolbar_item_width={props.toolbar_item_width}
+                toolbar_reserved_width={props.toolbar_reserved_width}
                 class={props.frame_class}
                 container_class={props.container_class}
                 input_class={props.input_class}
                 refresh_button_style={props.refresh_button_style}
                 refresh_button_aria_label={props.refresh_button_aria_label}
+                load_state={props.load_state.clone()}
+                on_refresh={props.on_refresh.clone()}
+                on_stop={props.on_stop.clone()}
+                zoom={*zoom}
+                on_zoom_reset={Callback::from(move |_| set_zoom.emit(1.0))}
+                on_submit={props.on_submit.clone()}
+                on_error={props.on_error.clone()}
                 icon_button_style={props.icon_button_style}
                 address_wrapper_base_style={props.address_wrapper_base_style}
                 header_base_style={props.header_base_style}
@@ -1419,6 +3928,8 @@ pub fn browser_frame(props: &BrowserFrameProps) -> Html {
                 maximize_title={props.maximize_title}
                 maximize_tabindex={props.maximize_tabindex}
                 share_button_style={props.share_button_style}
+                share_menu={props.share_menu}
+                on_share={props.on_share.clone()}
                 share_onclick={props.share_onclick.clone()}
                 share_onmouseover={props.share_onmouseover.clone()}
                 share_onmouseout={props.share_onmouseout.clone()}
@@ -1433,6 +3944,8 @@ pub fn browser_frame(props: &BrowserFrameProps) -> Html {
                 tabs_onblur={props.tabs_onblur.clone()}
                 tabs_tabindex={props.tabs_tabindex}
                 more_button_style={props.more_button_style}
+                menu_items={props.menu_items.clone()}
+                on_menu_select={props.on_menu_select.clone()}
                 more_onclick={props.more_onclick.clone()}
                 more_onmouseover={props.more_onmouseover.clone()}
                 more_onmouseout={props.more_onmouseout.clone()}
@@ -1440,7 +3953,29 @@ pub fn browser_frame(props: &BrowserFrameProps) -> Html {
                 more_onblur={props.more_onblur.clone()}
                 more_tabindex={props.more_tabindex}
             />
-            <BrowserContent aria_describedby={props.aria_describedby}>
+            if !props.bookmarks.is_empty() {
+                <BookmarksBar
+                    id={part_id(props.id, "bookmarks")}
+                    bookmarks={props.bookmarks.clone()}
+                    on_select={navigate}
+                    style_mode={mode}
+                />
+            }
+            if *find_open {
+                <FindBar
+                    id={part_id(props.id, "find")}
+                    target={content_ref.clone()}
+                    on_close={on_find_close}
+                    on_error={props.on_error.clone()}
+                    style_mode={mode}
+                />
+            }
+            <BrowserContent
+                node_ref={content_ref}
+                zoom={*zoom}
+                aria_describedby={props.aria_describedby}
+                style_mode={mode}
+            >
                 { for props.children.iter() }
             </BrowserContent>
         </article>
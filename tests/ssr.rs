@@ -0,0 +1,575 @@
+//! Renders the components to HTML strings on the host target, where no
+//! browser APIs exist, so anything touching `web_sys` during render fails here.
+
+/// Asserts that every window control in `html` is a tab stop until the roving
+/// tabindex takes over in the browser.
+#[cfg(any(feature = "yew", feature = "dio", feature = "sycamore"))]
+fn assert_controls_reachable(html: &str) {
+    for label in ["Close window", "Minimize window", "Maximize window"] {
+        let at = html.find(&format!("aria-label=\"{label}\"")).unwrap();
+        let start = html[..at].rfind("<button").unwrap();
+        let end = at + html[at..].find('>').unwrap();
+        let tag = &html[start..end];
+        assert!(
+            tag.contains("tabindex=\"0\"") || tag.contains("tabindex=0"),
+            "{label} is not reachable"
+        );
+    }
+}
+
+/// Asserts that `html` carries no inline style, only empty `style`
+/// attributes left by the ignored `*_style` props.
+#[cfg(any(feature = "yew", feature = "dio", feature = "sycamore"))]
+fn assert_no_inline_styles(html: &str) {
+    let rest = html.replace(" style=\"\"", "");
+    assert!(!rest.contains("style="), "{rest}");
+}
+
+#[cfg(feature = "yew")]
+mod yew_ssr {
+    use browser_rs::yew::{BrowserFrame, FindBar, MenuList, ToolbarItem};
+    use browser_rs::{
+        Bookmark, LoadState, MenuItem, StyleMode, Suggestion, SuggestionProvider, Variant,
+    };
+    use yew::prelude::*;
+
+    #[derive(Properties, PartialEq)]
+    struct FrameProps {
+        variant: Variant,
+    }
+
+    #[function_component(Frame)]
+    fn frame(props: &FrameProps) -> Html {
+        html! {
+            <BrowserFrame
+                url={"https://opensass.org/docs".to_string()}
+                title={"OpenSASS".to_string()}
+                variant={props.variant.clone()}
+                load_state={LoadState::Loading(40)}
+                zoom={1.25}
+                suggestions={SuggestionProvider::Static(vec![Suggestion::new("https://opensass.org")])}
+                menu_items={vec![MenuItem::new("print", "Print").with_shortcut("Ctrl+P")]}
+                toolbar_items={vec![ToolbarItem::new("bookmark", "Bookmark")]}
+                bookmarks={vec![
+                    Bookmark::new("Home", "https://opensass.org"),
+                    Bookmark::folder("Rust", vec![Bookmark::new("Yew", "https://yew.rs")]),
+                ]}
+            >
+                <p>{ "Server rendered content" }</p>
+            </BrowserFrame>
+        }
+    }
+
+    #[function_component(Widgets)]
+    fn widgets() -> Html {
+        let target = use_node_ref();
+        let items = vec![
+            MenuItem::new("reload", "Reload").with_checked(true),
+            MenuItem::separator(),
+            MenuItem::new("zoom", "Zoom").with_submenu(vec![MenuItem::new("zoom-in", "Zoom In")]),
+        ];
+
+        html! {
+            <>
+                <FindBar target={target.clone()} />
+                <MenuList items={items} id={"menu".to_string()} label={"More".to_string()} />
+                <div ref={target}>{ "Searchable text" }</div>
+            </>
+        }
+    }
+
+    #[function_component(NamedFrame)]
+    fn named_frame() -> Html {
+        html! {
+            <BrowserFrame
+                id="docs"
+                url={"https://opensass.org/docs".to_string()}
+                bookmarks={vec![Bookmark::folder("Rust", vec![Bookmark::new("Yew", "https://yew.rs")])]}
+            >
+                <p>{ "Server rendered content" }</p>
+            </BrowserFrame>
+        }
+    }
+
+    #[function_component(StyledFrame)]
+    fn styled_frame() -> Html {
+        html! {
+            <BrowserFrame
+                url={"https://opensass.org/docs".to_string()}
+                style_mode={StyleMode::Stylesheet}
+                inactive=true
+                bookmarks={vec![
+                    Bookmark::new("Home", "https://opensass.org"),
+                    Bookmark::folder("Rust", vec![Bookmark::new("Yew", "https://yew.rs")]),
+                ]}
+            >
+                <p>{ "Server rendered content" }</p>
+            </BrowserFrame>
+        }
+    }
+
+    #[function_component(StyledWidgets)]
+    fn styled_widgets() -> Html {
+        let target = use_node_ref();
+        let items = vec![
+            MenuItem::new("reload", "Reload").with_checked(true),
+            MenuItem::separator(),
+            MenuItem::new("zoom", "Zoom").with_submenu(vec![MenuItem::new("zoom-in", "Zoom In")]),
+        ];
+
+        html! {
+            <>
+                <FindBar target={target.clone()} style_mode={StyleMode::Stylesheet} />
+                <MenuList
+                    items={items}
+                    id={"menu".to_string()}
+                    style_mode={StyleMode::Stylesheet}
+                />
+                <div ref={target}>{ "Searchable text" }</div>
+            </>
+        }
+    }
+
+    async fn render(variant: Variant) -> String {
+        yew::ServerRenderer::<Frame>::with_props(move || FrameProps { variant })
+            .render()
+            .await
+    }
+
+    #[tokio::test]
+    async fn renders_frame_to_string() {
+        let html = render(Variant::Default).await;
+
+        assert!(html.contains("Server rendered content"));
+        assert!(html.contains("opensass.org"));
+        assert!(html.contains("125%"));
+        assert!(html.contains("Bookmarks"));
+        assert!(html.contains("aria-busy=\"true\""));
+        super::assert_controls_reachable(&html);
+    }
+
+    #[tokio::test]
+    async fn derives_part_ids_from_the_frame_id() {
+        let html = yew::ServerRenderer::<NamedFrame>::new().render().await;
+
+        assert!(html.contains("id=\"docs-address\""));
+        assert!(html.contains("aria-controls=\"docs-address-listbox\""));
+        assert!(html.contains("id=\"docs-address-description\""));
+        assert!(html.contains("id=\"docs-bookmarks-folder-0\""));
+        // Server and client render the same ids, so hydration lines up.
+        assert_eq!(
+            html,
+            yew::ServerRenderer::<NamedFrame>::new().render().await
+        );
+    }
+
+    #[tokio::test]
+    async fn renders_every_variant() {
+        for variant in [Variant::Default, Variant::Tabs, Variant::Ios] {
+            let html = render(variant).await;
+            assert!(html.contains("Server rendered content"));
+        }
+    }
+
+    #[tokio::test]
+    async fn renders_popups_to_string() {
+        let html = yew::ServerRenderer::<Widgets>::new().render().await;
+
+        assert!(html.contains("Find in page"));
+        assert!(html.contains("menuitemcheckbox"));
+        assert!(html.contains("Zoom"));
+    }
+
+    #[tokio::test]
+    async fn renders_stylesheet_classes() {
+        let html = yew::ServerRenderer::<StyledFrame>::new().render().await;
+
+        assert!(html.contains("browser-frame browser-frame--default browser-frame--medium"));
+        assert!(html.contains("browser-frame__header"));
+        assert!(html.contains("browser-frame__control--close"));
+        assert!(html.contains("browser-frame__controls--inactive"));
+        assert!(html.contains("browser-frame__bookmarks"));
+        assert!(html.contains("browser-frame__bookmark-list"));
+        assert!(html.contains("browser-frame__zoom-layer"));
+        super::assert_no_inline_styles(&html);
+
+        let html = yew::ServerRenderer::<StyledWidgets>::new().render().await;
+        assert!(html.contains("browser-frame__find-input"));
+        assert!(html.contains("browser-frame__menu-item browser-frame__menu-item--active"));
+        assert!(html.contains("browser-frame__menu-separator"));
+        assert!(html.contains("browser-frame__menu-check"));
+        assert!(html.contains("browser-frame__menu-arrow"));
+        super::assert_no_inline_styles(&html);
+    }
+}
+
+#[cfg(feature = "dio")]
+mod dioxus_ssr_tests {
+    use browser_rs::dioxus::{BrowserFrame, FindBar, MenuList, ToolbarItem};
+    use browser_rs::{
+        Bookmark, LoadState, MenuItem, StyleMode, Suggestion, SuggestionProvider, Variant,
+    };
+    use dioxus::prelude::*;
+
+    #[component]
+    fn Frame(variant: Variant) -> Element {
+        rsx! {
+            BrowserFrame {
+                url: "https://opensass.org/docs",
+                title: "OpenSASS",
+                variant: variant,
+                load_state: LoadState::Loading(40),
+                zoom: 1.25,
+                suggestions: SuggestionProvider::Static(vec![Suggestion::new("https://opensass.org")]),
+                menu_items: vec![MenuItem::new("print", "Print").with_shortcut("Ctrl+P")],
+                toolbar_items: vec![ToolbarItem::new("bookmark", "Bookmark")],
+                bookmarks: vec![
+                    Bookmark::new("Home", "https://opensass.org"),
+                    Bookmark::folder("Rust", vec![Bookmark::new("Dioxus", "https://dioxuslabs.com")]),
+                ],
+                children: rsx! {
+                    p { "Server rendered content" }
+                }
+            }
+        }
+    }
+
+    #[component]
+    fn Widgets() -> Element {
+        let target = use_signal(|| None);
+        let items = vec![
+            MenuItem::new("reload", "Reload").with_checked(true),
+            MenuItem::separator(),
+            MenuItem::new("zoom", "Zoom").with_submenu(vec![MenuItem::new("zoom-in", "Zoom In")]),
+        ];
+
+        rsx! {
+            FindBar { target: target }
+            MenuList { items: items, id: "menu", label: "More" }
+            div { "Searchable text" }
+        }
+    }
+
+    #[component]
+    fn StyledWidgets() -> Element {
+        let target = use_signal(|| None);
+        let items = vec![
+            MenuItem::new("reload", "Reload").with_checked(true),
+            MenuItem::separator(),
+            MenuItem::new("zoom", "Zoom").with_submenu(vec![MenuItem::new("zoom-in", "Zoom In")]),
+        ];
+
+        rsx! {
+            FindBar { target: target, style_mode: StyleMode::Stylesheet }
+            MenuList { items: items, id: "menu", style_mode: StyleMode::Stylesheet }
+            div { "Searchable text" }
+        }
+    }
+
+    #[component]
+    fn NamedFrame() -> Element {
+        rsx! {
+            BrowserFrame {
+                id: "docs",
+                url: "https://opensass.org/docs",
+                bookmarks: vec![Bookmark::folder("Rust", vec![Bookmark::new("Dioxus", "https://dioxuslabs.com")])],
+                children: rsx! {
+                    p { "Server rendered content" }
+                }
+            }
+        }
+    }
+
+    #[component]
+    fn StyledFrame() -> Element {
+        rsx! {
+            BrowserFrame {
+                url: "https://opensass.org/docs",
+                style_mode: StyleMode::Stylesheet,
+                inactive: true,
+                bookmarks: vec![
+                    Bookmark::new("Home", "https://opensass.org"),
+                    Bookmark::folder("Rust", vec![Bookmark::new("Dioxus", "https://dioxuslabs.com")]),
+                ],
+                children: rsx! {
+                    p { "Server rendered content" }
+                }
+            }
+        }
+    }
+
+    fn render(variant: Variant) -> String {
+        let mut dom = VirtualDom::new_with_props(Frame, FrameProps { variant });
+        dom.rebuild_in_place();
+        dioxus_ssr::render(&dom)
+    }
+
+    #[test]
+    fn renders_frame_to_string() {
+        let html = render(Variant::Default);
+
+        assert!(html.contains("Server rendered content"));
+        assert!(html.contains("opensass.org"));
+        assert!(html.contains("125%"));
+        assert!(html.contains("Bookmarks"));
+        assert!(html.contains("aria-busy=true") || html.contains("aria-busy=\"true\""));
+        super::assert_controls_reachable(&html);
+    }
+
+    #[test]
+    fn renders_every_variant() {
+        for variant in [Variant::Default, Variant::Tabs, Variant::Ios] {
+            let html = render(variant);
+            assert!(html.contains("Server rendered content"));
+        }
+    }
+
+    #[test]
+    fn derives_part_ids_from_the_frame_id() {
+        let render_named = || {
+            let mut dom = VirtualDom::new(NamedFrame);
+            dom.rebuild_in_place();
+            dioxus_ssr::render(&dom)
+        };
+        let html = render_named();
+
+        assert!(html.contains("id=\"docs-address\""));
+        assert!(html.contains("aria-controls=\"docs-address-listbox\""));
+        assert!(html.contains("id=\"docs-address-description\""));
+        assert!(html.contains("id=\"docs-bookmarks-folder-0\""));
+        // Server and client render the same ids, so hydration lines up.
+        assert_eq!(html, render_named());
+    }
+
+    #[test]
+    fn renders_popups_to_string() {
+        let mut dom = VirtualDom::new(Widgets);
+        dom.rebuild_in_place();
+        let html = dioxus_ssr::render(&dom);
+
+        assert!(html.contains("Find in page"));
+        assert!(html.contains("menuitemcheckbox"));
+        assert!(html.contains("Zoom"));
+    }
+
+    #[test]
+    fn renders_stylesheet_classes() {
+        let mut dom = VirtualDom::new(StyledFrame);
+        dom.rebuild_in_place();
+        let html = dioxus_ssr::render(&dom);
+
+        assert!(html.contains("browser-frame__controls--inactive"));
+        assert!(html.contains("browser-frame__bookmark-list"));
+        assert!(html.contains("browser-frame__zoom-layer"));
+        super::assert_no_inline_styles(&html);
+
+        let mut dom = VirtualDom::new(StyledWidgets);
+        dom.rebuild_in_place();
+        let html = dioxus_ssr::render(&dom);
+
+        assert!(html.contains("browser-frame__find-input"));
+        assert!(html.contains("browser-frame__menu-item browser-frame__menu-item--active"));
+        assert!(html.contains("browser-frame__menu-separator"));
+        assert!(html.contains("browser-frame__menu-check"));
+        assert!(html.contains("browser-frame__menu-arrow"));
+        super::assert_no_inline_styles(&html);
+    }
+}
+
+#[cfg(feature = "sycamore")]
+mod sycamore_ssr {
+    use browser_rs::common::{ADDRESS_BAR_LABEL, REFRESH_LABEL};
+    use browser_rs::sycamore::{BrowserFrame, BrowserHeader, FindBar, MenuList, ToolbarItem};
+    use browser_rs::{
+        Animation, Bookmark, LoadState, MenuItem, StyleMode, Suggestion, SuggestionProvider,
+        Variant,
+    };
+    use sycamore::prelude::*;
+
+    fn bookmarks() -> Vec<Bookmark> {
+        vec![
+            Bookmark::new("Home", "https://opensass.org"),
+            Bookmark::folder(
+                "Rust",
+                vec![Bookmark::new("Sycamore", "https://sycamore.dev")],
+            ),
+        ]
+    }
+
+    fn menu_items() -> Vec<MenuItem> {
+        vec![
+            MenuItem::new("reload", "Reload").with_checked(true),
+            MenuItem::separator(),
+            MenuItem::new("zoom", "Zoom").with_submenu(vec![MenuItem::new("zoom-in", "Zoom In")]),
+        ]
+    }
+
+    fn render(variant: Variant) -> String {
+        sycamore::render_to_string(move || {
+            view! {
+                BrowserFrame(
+                    url="https://opensass.org/docs",
+                    title="OpenSASS",
+                    variant=variant,
+                    load_state=LoadState::Loading(40),
+                    zoom=1.25,
+                    suggestions=SuggestionProvider::Static(vec![Suggestion::new("https://opensass.org")]),
+                    menu_items=vec![MenuItem::new("print", "Print").with_shortcut("Ctrl+P")],
+                    toolbar_items=vec![ToolbarItem::new("bookmark", "Bookmark")],
+                    bookmarks=bookmarks(),
+                ) {
+                    p { "Server rendered content" }
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn renders_frame_to_string() {
+        let html = render(Variant::Default);
+
+        assert!(html.contains("Server rendered content"));
+        assert!(html.contains("opensass.org"));
+        assert!(html.contains("Close window"));
+        assert!(html.contains("progressbar"));
+        assert!(html.contains("aria-busy=\"true\""));
+        assert!(html.contains("aria-label=\"Page actions\""));
+        assert!(html.contains("OpenSASS"));
+        assert!(html.contains("125%"));
+        assert!(html.contains("Bookmarks"));
+        assert!(html.contains("aria-label=\"Bookmark\""));
+        super::assert_controls_reachable(&html);
+    }
+
+    #[test]
+    fn renders_every_variant() {
+        for variant in [Variant::Default, Variant::Tabs, Variant::Ios] {
+            let html = render(variant);
+            assert!(html.contains("Server rendered content"));
+        }
+    }
+
+    #[test]
+    fn renders_header_with_yew_defaults() {
+        let html = sycamore::render_to_string(|| {
+            view! {
+                BrowserHeader(url="https://opensass.org/docs")
+            }
+        });
+
+        assert!(html.contains("Close window"));
+        assert!(html.contains(ADDRESS_BAR_LABEL));
+        assert!(html.contains(&format!("aria-label=\"{REFRESH_LABEL}\"")));
+    }
+
+    #[test]
+    fn renders_popups_to_string() {
+        let html = sycamore::render_to_string(|| {
+            let target = create_node_ref();
+            view! {
+                FindBar(target=target)
+                MenuList(items=menu_items(), id="menu", label="More")
+                div(r#ref=target) { "Searchable text" }
+            }
+        });
+
+        assert!(html.contains("Find in page"));
+        assert!(html.contains("menuitemcheckbox"));
+        assert!(html.contains("Zoom"));
+    }
+
+    #[test]
+    fn renders_stylesheet_classes() {
+        let html = sycamore::render_to_string(|| {
+            view! {
+                BrowserFrame(
+                    url="https://opensass.org/docs",
+                    style_mode=StyleMode::Stylesheet,
+                    inactive=true,
+                    bookmarks=bookmarks(),
+                ) {
+                    p { "Server rendered content" }
+                }
+            }
+        });
+
+        assert!(html.contains("browser-frame__header"));
+        assert!(html.contains("browser-frame__address-bar"));
+        assert!(html.contains("browser-frame__controls--inactive"));
+        assert!(html.contains("browser-frame__visually-hidden"));
+        assert!(html.contains("browser-frame__bookmarks"));
+        assert!(html.contains("browser-frame__bookmark-list"));
+        assert!(html.contains("browser-frame__zoom-layer"));
+        super::assert_no_inline_styles(&html);
+
+        let html = sycamore::render_to_string(|| {
+            let target = create_node_ref();
+            view! {
+                FindBar(target=target, style_mode=StyleMode::Stylesheet)
+                MenuList(items=menu_items(), id="menu", style_mode=StyleMode::Stylesheet)
+                div(r#ref=target) { "Searchable text" }
+            }
+        });
+        assert!(html.contains("browser-frame__find-input"));
+        assert!(html.contains("browser-frame__menu-item browser-frame__menu-item--active"));
+        assert!(html.contains("browser-frame__menu-separator"));
+        assert!(html.contains("browser-frame__menu-check"));
+        assert!(html.contains("browser-frame__menu-arrow"));
+        super::assert_no_inline_styles(&html);
+    }
+
+    #[test]
+    fn renders_open_animation_start() {
+        let html = sycamore::render_to_string(|| {
+            view! {
+                BrowserFrame(url="https://opensass.org/docs", animation=Animation::Fade) {
+                    p { "Server rendered content" }
+                }
+            }
+        });
+
+        assert!(html.contains("opacity: 0;"));
+        assert!(!render(Variant::Default).contains("opacity: 0;"));
+    }
+
+    #[test]
+    fn derives_part_ids_from_the_frame_id() {
+        let render_named = || {
+            sycamore::render_to_string(|| {
+                view! {
+                    BrowserFrame(id="docs", url="https://opensass.org/docs", bookmarks=bookmarks()) {}
+                }
+            })
+        };
+        let html = render_named();
+
+        assert!(html.contains("id=\"docs-address\""));
+        assert!(html.contains("aria-controls=\"docs-address-listbox\""));
+        assert!(html.contains("id=\"docs-address-description\""));
+        assert!(html.contains("id=\"docs-bookmarks-folder-1\""));
+        // Server and client render the same ids, so hydration lines up.
+        assert_eq!(html, render_named());
+    }
+
+    #[test]
+    fn renders_unique_ids() {
+        let html = sycamore::render_to_string(|| {
+            view! {
+                BrowserFrame(url="https://opensass.org") {}
+                BrowserFrame(url="https://opensass.org/docs") {}
+            }
+        });
+        let descriptions: Vec<&str> = html
+            .split("aria-describedby=\"")
+            .skip(1)
+            .filter_map(|rest| rest.split('"').next())
+            .filter(|id| id.ends_with("-description"))
+            .collect();
+
+        assert_eq!(descriptions.len(), 2);
+        assert_ne!(descriptions[0], descriptions[1]);
+        for id in descriptions {
+            assert!(html.contains(&format!("id=\"{id}\"")));
+        }
+    }
+}